@@ -55,6 +55,12 @@ pub trait PathItemExt {
     /// Convert this path item type to a [`axum::routing::MethodFilter`].
     ///
     /// Method filter is used with handler registration on [`axum::routing::MethodRouter`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the method is [`HttpMethod::Query`] or [`HttpMethod::Custom`] since axum
+    /// [`MethodFilter`] cannot represent them. Handlers registered with [`routes`] macro are
+    /// checked at compile time instead.
     fn to_method_filter(&self) -> MethodFilter;
 }
 
@@ -69,10 +75,36 @@ impl PathItemExt for HttpMethod {
             HttpMethod::Trace => MethodFilter::TRACE,
             HttpMethod::Delete => MethodFilter::DELETE,
             HttpMethod::Options => MethodFilter::OPTIONS,
+            HttpMethod::Query => panic!("axum MethodFilter does not support HTTP method: QUERY"),
+            HttpMethod::Custom(method) => match HttpMethod::from(method.as_str()) {
+                HttpMethod::Custom(method) => {
+                    panic!("axum MethodFilter does not support HTTP method: {method}")
+                }
+                predefined => predefined.to_method_filter(),
+            },
+            _ => panic!("axum MethodFilter does not support the HTTP method"),
         }
     }
 }
 
+/// Check whether all the given method names of path operation can be represented with axum
+/// [`MethodFilter`]. Used by [`routes`] macro to reject handlers at compile time.
+#[doc(hidden)]
+pub const fn is_method_filter_supported(method_names: &[&str]) -> bool {
+    let mut index = 0;
+    while index < method_names.len() {
+        if !matches!(
+            method_names[index].as_bytes(),
+            b"get" | b"post" | b"put" | b"delete" | b"options" | b"head" | b"patch" | b"trace"
+        ) {
+            return false;
+        }
+        index += 1;
+    }
+
+    true
+}
+
 /// re-export paste so users do not need to add the dependency.
 #[doc(hidden)]
 pub use pastey::paste;
@@ -107,6 +139,20 @@ pub use pastey::paste;
 /// handler having request method type `CONNECT` `#[utoipa::path(connect, path = "")]` will panic at
 /// runtime.
 ///
+/// # Compile errors
+///
+/// Handlers with `QUERY` or custom HTTP methods such as `#[utoipa::path(method(query), ...)]`
+/// cannot be registered with this macro since axum [`MethodFilter`] cannot represent them.
+///
+/// ```rust,compile_fail
+/// # use utoipa_axum::{routes, router::UtoipaMethodRouter};
+/// # use utoipa::path;
+///  #[utoipa::path(method(query), path = "/search")]
+///  async fn search_user() {}
+///
+///  let _: UtoipaMethodRouter = routes!(search_user);
+/// ```
+///
 /// # Examples
 ///
 /// _**Create new `OpenApiRouter` with `get_user` and `post_user` paths.**_
@@ -126,6 +172,7 @@ macro_rules! routes {
     ( $handler:path $(, $tail:path)* $(,)? ) => {
         {
             use $crate::PathItemExt;
+            $crate::routes!(@assert_methods $handler);
             let mut paths = utoipa::openapi::path::Paths::new();
             let mut schemas = Vec::<(String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>)>::new();
             let (path, item, types) = $crate::routes!(@resolve_types $handler : schemas);
@@ -140,6 +187,7 @@ macro_rules! routes {
     };
     ( $schemas:tt: $router:ident: $paths:ident: $handler:path $(, $tail:tt)* ) => {
         {
+            $crate::routes!(@assert_methods $handler);
             let (path, item, types) = $crate::routes!(@resolve_types $handler : $schemas);
             let router = types.iter().by_ref().fold($router, |router, path_type| {
                 router.on(path_type.to_method_filter(), $handler)
@@ -148,6 +196,18 @@ macro_rules! routes {
             router
         }
     };
+    ( @assert_methods $handler:path ) => {
+        $crate::paste! {
+            const {
+                assert!(
+                    $crate::is_method_filter_supported(
+                        $crate::routes!( @path [METHOD_NAMES] of $handler )
+                    ),
+                    "axum MethodFilter does not support HTTP method QUERY or custom HTTP methods"
+                )
+            }
+        }
+    };
     ( @resolve_types $handler:path : $schemas:tt ) => {
         {
             $crate::paste! {
@@ -182,7 +242,7 @@ macro_rules! routes {
     ( @rev [$op:ident $( $args:tt )* ] : $handler:tt [] $($tt:tt)* ) => {
         {
             #[allow(unused_imports)]
-            use utoipa::{Path, __dev::{Tags, SchemaReferences}};
+            use utoipa::{Path, __dev::{Tags, SchemaReferences, MethodNames}};
             $crate::paste! {
                 $( $tt :: )* [<__path_ $handler>]::$op $( $args )*
             }
@@ -9,6 +9,8 @@
 //! * Define schema collect mode for `utoipa` with `.schema_collect(...)` method.
 //!   * [`SchemaCollect::All`] will collect all schemas from usages including inlined with `inline(T)`
 //!   * [`SchemaCollect::NonInlined`] will only collect non inlined schemas from usages.
+//! * Define request and response schema variants for all `ToSchema` types with
+//!   `.request_response(...)` method. See [`RequestResponse`] for more details.
 //!
 //! <div class="warning">
 //!
@@ -69,6 +71,70 @@ pub struct Config<'c> {
     pub aliases: HashMap<Cow<'c, str>, Cow<'c, str>>,
     /// Schema collect mode for `utoipa`. By default only non inlined schemas are collected.
     pub schema_collect: SchemaCollect,
+    /// Request and response schema variants of structs with named fields. By default variants
+    /// are not created.
+    #[serde(default)]
+    pub request_response: Option<RequestResponse<'c>>,
+}
+
+/// Configures request and response schema variants of `ToSchema` types.
+///
+/// When set `utoipa` will create two additional schemas for each struct with named fields
+/// deriving `ToSchema`. Request variant leaves out read only properties and response variant
+/// leaves out write only properties. Names of the variants are name of the schema with
+/// the configured suffix, by default _`FooRequest`_ and _`FooResponse`_. Types used in
+/// `request_body` and `responses` of `#[utoipa::path(...)]` will reference the variant
+/// schemas.
+///
+/// # Examples
+///
+/// _**Create `FooInput` and `FooOutput` schema variants.**_
+/// ```rust
+/// use utoipa_config::{Config, RequestResponse};
+///
+/// let _ = Config::new().request_response(
+///     RequestResponse::new()
+///         .request_suffix("Input")
+///         .response_suffix("Output"),
+/// );
+/// ```
+#[derive(Serialize, Deserialize, Clone)]
+#[non_exhaustive]
+pub struct RequestResponse<'r> {
+    /// Suffix of the request schema variant name. Defaults to _`Request`_.
+    pub request_suffix: Cow<'r, str>,
+    /// Suffix of the response schema variant name. Defaults to _`Response`_.
+    pub response_suffix: Cow<'r, str>,
+}
+
+impl Default for RequestResponse<'_> {
+    fn default() -> Self {
+        Self {
+            request_suffix: Cow::Borrowed("Request"),
+            response_suffix: Cow::Borrowed("Response"),
+        }
+    }
+}
+
+impl<'r> RequestResponse<'r> {
+    /// Construct a new [`RequestResponse`] with default _`Request`_ and _`Response`_ suffixes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set suffix of the request schema variant name.
+    pub fn request_suffix(mut self, request_suffix: &'r str) -> Self {
+        self.request_suffix = Cow::Borrowed(request_suffix);
+
+        self
+    }
+
+    /// Set suffix of the response schema variant name.
+    pub fn response_suffix(mut self, response_suffix: &'r str) -> Self {
+        self.response_suffix = Cow::Borrowed(response_suffix);
+
+        self
+    }
 }
 
 /// Configures schema collect mode. By default only non explicitly inlined schemas are collected.
@@ -190,6 +256,17 @@ impl<'c> Config<'c> {
         self
     }
 
+    /// Define request and response schema variants for `utoipa`.
+    ///
+    /// Method accepts one argument [`RequestResponse`] which defines suffixes of the request and
+    /// response schema variants. If none is defined the variants are only created for types
+    /// having `#[schema(request_response)]` attribute.
+    pub fn request_response(mut self, request_response: RequestResponse<'c>) -> Self {
+        self.request_response = Some(request_response);
+
+        self
+    }
+
     fn get_out_dir() -> Option<String> {
         std::env::var("OUT_DIR").ok()
     }
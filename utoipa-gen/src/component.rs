@@ -6,7 +6,7 @@ use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::token::Comma;
 use syn::{
-    AngleBracketedGenericArguments, Attribute, GenericArgument, GenericParam, Generics, Path,
+    AngleBracketedGenericArguments, Attribute, Expr, GenericArgument, GenericParam, Generics, Path,
     PathArguments, PathSegment, Type, TypePath,
 };
 
@@ -19,7 +19,7 @@ use crate::{
     Array, AttributesExt, Diagnostics, GenericsExt, OptionExt,
 };
 
-use self::features::attributes::{Description, Nullable};
+use self::features::attributes::{Contains, Description, Nullable, PatternProperties};
 use self::features::validation::Minimum;
 use self::features::{
     pop_feature, Feature, FeaturesExt, IntoInner, IsInline, ToTokensExt, Validatable,
@@ -31,6 +31,7 @@ pub mod into_params;
 pub mod features;
 pub mod schema;
 pub mod serde;
+pub mod serde_as;
 
 /// Check whether either serde `container_rule` or `field_rule` has _`default`_ attribute set.
 #[inline]
@@ -64,8 +65,8 @@ enum TypeTreeValue<'t> {
     TypePath(&'t TypePath),
     Path(&'t Path),
     /// Slice and array types need to be manually defined, since they cannot be recognized from
-    /// generic arguments.
-    Array(Vec<TypeTreeValue<'t>>, Span),
+    /// generic arguments. Arrays have length, slices do not.
+    Array(Vec<TypeTreeValue<'t>>, Span, Option<&'t Expr>),
     UnitType,
     Tuple(Vec<TypeTreeValue<'t>>, Span),
 }
@@ -75,7 +76,7 @@ impl PartialEq for TypeTreeValue<'_> {
         match self {
             Self::Path(_) => self == other,
             Self::TypePath(_) => self == other,
-            Self::Array(array, _) => matches!(other, Self::Array(other, _) if other == array),
+            Self::Array(array, ..) => matches!(other, Self::Array(other, ..) if other == array),
             Self::Tuple(tuple, _) => matches!(other, Self::Tuple(other, _) if other == tuple),
             Self::UnitType => self == other,
         }
@@ -93,6 +94,8 @@ pub struct TypeTree<'t> {
     pub value_type: ValueType,
     pub generic_type: Option<GenericType>,
     pub children: Option<Vec<TypeTree<'t>>>,
+    /// Length of fixed size array `[T; N]`.
+    pub array_len: Option<&'t Expr>,
 }
 
 pub trait SynPathExt {
@@ -231,10 +234,12 @@ impl TypeTree<'_> {
             Type::Slice(slice) => Ok(TypeTreeValue::Array(
                 vec![Self::get_type_tree_value(&slice.elem)?],
                 slice.bracket_token.span.join(),
+                None,
             )),
             Type::Array(array) => Ok(TypeTreeValue::Array(
                 vec![Self::get_type_tree_value(&array.elem)?],
                 array.bracket_token.span.join(),
+                Some(&array.len),
             )),
             Type::TraitObject(trait_object) => trait_object
                 .bounds
@@ -268,7 +273,7 @@ impl TypeTree<'_> {
                 let path = match value {
                     TypeTreeValue::TypePath(type_path) => &type_path.path,
                     TypeTreeValue::Path(path) => path,
-                    TypeTreeValue::Array(value, span) => {
+                    TypeTreeValue::Array(value, span, array_len) => {
                         let array: Path = Ident::new("Array", span).into();
                         return Ok(TypeTree {
                             path: Some(Cow::Owned(array)),
@@ -279,6 +284,7 @@ impl TypeTree<'_> {
                                 Ok(converted_values) => converted_values.collect(),
                                 Err(diagnostics) => return Err(diagnostics),
                             }),
+                            array_len,
                         });
                     }
                     TypeTreeValue::Tuple(tuple, span) => {
@@ -291,6 +297,7 @@ impl TypeTree<'_> {
                             }),
                             generic_type: None,
                             value_type: ValueType::Tuple,
+                            array_len: None,
                         })
                     }
                     TypeTreeValue::UnitType => {
@@ -300,6 +307,7 @@ impl TypeTree<'_> {
                             value_type: ValueType::Tuple,
                             generic_type: None,
                             children: None,
+                            array_len: None,
                         })
                     }
                 };
@@ -403,6 +411,7 @@ impl TypeTree<'_> {
             },
             generic_type,
             children: None,
+            array_len: None,
         }
     }
 
@@ -419,6 +428,8 @@ impl TypeTree<'_> {
             "Vec" => Some(GenericType::Vec),
             "BTreeSet" | "HashSet" => Some(GenericType::Set),
             "LinkedList" => Some(GenericType::LinkedList),
+            "VecDeque" => Some(GenericType::VecDeque),
+            "BinaryHeap" => Some(GenericType::BinaryHeap),
             #[cfg(feature = "smallvec")]
             "SmallVec" => Some(GenericType::SmallVec),
             "Option" => Some(GenericType::Option),
@@ -429,6 +440,11 @@ impl TypeTree<'_> {
             #[cfg(feature = "rc_schema")]
             "Rc" => Some(GenericType::Rc),
             "RefCell" => Some(GenericType::RefCell),
+            "Cell" => Some(GenericType::Cell),
+            "Mutex" => Some(GenericType::Mutex),
+            "RwLock" => Some(GenericType::RwLock),
+            "Wrapping" => Some(GenericType::Wrapping),
+            "PhantomData" => Some(GenericType::PhantomData),
             _ => None,
         }
     }
@@ -597,6 +613,8 @@ pub enum ValueType {
 pub enum GenericType {
     Vec,
     LinkedList,
+    VecDeque,
+    BinaryHeap,
     Set,
     #[cfg(feature = "smallvec")]
     SmallVec,
@@ -605,6 +623,11 @@ pub enum GenericType {
     Cow,
     Box,
     RefCell,
+    Cell,
+    Mutex,
+    RwLock,
+    Wrapping,
+    PhantomData,
     #[cfg(feature = "rc_schema")]
     Arc,
     #[cfg(feature = "rc_schema")]
@@ -713,6 +736,10 @@ impl ToTokens for ComponentDescription<'_> {
 #[derive(Default)]
 pub struct SchemaReference {
     pub name: TokenStream,
+    /// Name of the request schema variant, empty for composed generic schemas.
+    pub request_name: TokenStream,
+    /// Name of the response schema variant, empty for composed generic schemas.
+    pub response_name: TokenStream,
     pub tokens: TokenStream,
     pub references: TokenStream,
     pub is_inline: bool,
@@ -725,6 +752,19 @@ impl SchemaReference {
     fn is_partial(&self) -> bool {
         self.tokens.is_empty()
     }
+
+    /// Get `(name, variant_name)` tokens of the request or the response schema variant of the
+    /// referenced schema. Returns `None` if the reference has no schema variants.
+    pub fn variant_names(&self, response: bool) -> Option<TokenStream> {
+        let name = &self.name;
+        let variant_name = if response {
+            &self.response_name
+        } else {
+            &self.request_name
+        };
+
+        (!variant_name.is_empty()).then(|| quote! { (#name, #variant_name) })
+    }
 }
 
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -778,16 +818,20 @@ impl ComponentSchema {
                 type_tree,
                 description,
             )?,
-            Some(GenericType::Vec | GenericType::LinkedList | GenericType::Set) => {
-                ComponentSchema::vec_to_tokens(
-                    &mut tokens,
-                    &mut schema_references,
-                    container,
-                    features,
-                    type_tree,
-                    description,
-                )?
-            }
+            Some(
+                GenericType::Vec
+                | GenericType::LinkedList
+                | GenericType::VecDeque
+                | GenericType::BinaryHeap
+                | GenericType::Set,
+            ) => ComponentSchema::vec_to_tokens(
+                &mut tokens,
+                &mut schema_references,
+                container,
+                features,
+                type_tree,
+                description,
+            )?,
             #[cfg(feature = "smallvec")]
             Some(GenericType::SmallVec) => ComponentSchema::vec_to_tokens(
                 &mut tokens,
@@ -819,7 +863,15 @@ impl ComponentSchema {
 
                 schema_references.extend(schema.schema_references);
             }
-            Some(GenericType::Cow | GenericType::Box | GenericType::RefCell) => {
+            Some(
+                GenericType::Cow
+                | GenericType::Box
+                | GenericType::RefCell
+                | GenericType::Cell
+                | GenericType::Mutex
+                | GenericType::RwLock
+                | GenericType::Wrapping,
+            ) => {
                 let child = type_tree
                     .children
                     .as_ref()
@@ -864,6 +916,11 @@ impl ComponentSchema {
 
                 schema_references.extend(schema.schema_references);
             }
+            // `PhantomData` is serialized as unit regardless of its type argument
+            Some(GenericType::PhantomData) => tokens.extend(quote! {
+                utoipa::openapi::ObjectBuilder::new()
+                    .schema_type(utoipa::openapi::schema::Type::Null)
+            }),
             None => ComponentSchema::non_generic_to_tokens(
                 &mut tokens,
                 &mut name_tokens,
@@ -916,6 +973,16 @@ impl ComponentSchema {
     ) -> Result<(), Diagnostics> {
         let example = features.pop_by(|feature| matches!(feature, Feature::Example(_)));
         let additional_properties = pop_feature!(features => Feature::AdditionalProperties(_));
+        let pattern_properties =
+            pop_feature!(features => Feature::PatternProperties(_) as Option<PatternProperties>);
+        if let (Some(_), Some(additional_properties)) =
+            (&pattern_properties, &additional_properties)
+        {
+            return Err(Diagnostics::new(format!(
+                "`{additional_properties}` cannot be used together with `pattern_properties`"
+            ))
+            .help("`pattern_properties` defines the map values and disallows other properties by setting `additionalProperties: false`"));
+        }
         let nullable: Option<Nullable> =
             pop_feature!(features => Feature::Nullable(_)).into_inner();
         let default = pop_feature!(features => Feature::Default(_));
@@ -969,9 +1036,17 @@ impl ComponentSchema {
 
                 schema_references.extend(schema_property.schema_references);
 
+                let values_tokens = match &pattern_properties {
+                    Some(pattern_properties) => quote! {
+                        .pattern_property(#pattern_properties, #schema_tokens)
+                        .additional_properties(Some(utoipa::openapi::schema::AdditionalProperties::FreeForm(false)))
+                    },
+                    None => quote! { .additional_properties(Some(#schema_tokens)) },
+                };
+
                 Result::<Option<TokenStream>, Diagnostics>::Ok(Some(quote! {
                     .property_names(Some(#property_name_tokens))
-                    .additional_properties(Some(#schema_tokens))
+                    #values_tokens
                 }))
             })?;
 
@@ -1002,6 +1077,10 @@ impl ComponentSchema {
         let xml = features.extract_vec_xml_feature(type_tree)?;
         let max_items = pop_feature!(features => Feature::MaxItems(_));
         let min_items = pop_feature!(features => Feature::MinItems(_));
+        let contains = pop_feature!(features => Feature::Contains(_) as Option<Contains>);
+        let max_contains = pop_feature!(features => Feature::MaxContains(_));
+        let min_contains = pop_feature!(features => Feature::MinContains(_));
+        let unevaluated_items = pop_feature!(features => Feature::UnevaluatedItems(_));
         let nullable: Option<Nullable> =
             pop_feature!(features => Feature::Nullable(_)).into_inner();
         let default = pop_feature!(features => Feature::Default(_));
@@ -1080,13 +1159,42 @@ impl ComponentSchema {
         if let Some(max_items) = max_items {
             validate(&max_items);
             tokens.extend(max_items.to_token_stream())
+        } else if let Some(array_len) = type_tree.array_len {
+            tokens.extend(quote! { .max_items(Some(#array_len)) })
         }
 
         if let Some(min_items) = min_items {
             validate(&min_items);
             tokens.extend(min_items.to_token_stream())
+        } else if let Some(array_len) = type_tree.array_len {
+            tokens.extend(quote! { .min_items(Some(#array_len)) })
+        }
+
+        if let Some(contains) = contains {
+            let contains_type_tree = contains.as_type_tree()?;
+            let contains_schema = ComponentSchema::new(ComponentSchemaProps {
+                container,
+                type_tree: &contains_type_tree,
+                features: Vec::new(),
+                description: None,
+            })?;
+            let contains_tokens = contains_schema.to_token_stream();
+            schema_references.extend(contains_schema.schema_references);
+
+            tokens.extend(quote! { .contains(Some(#contains_tokens)) })
+        }
+
+        if let Some(max_contains) = max_contains {
+            validate(&max_contains);
+            tokens.extend(max_contains.to_token_stream())
+        }
+
+        if let Some(min_contains) = min_contains {
+            validate(&min_contains);
+            tokens.extend(min_contains.to_token_stream())
         }
 
+        unevaluated_items.to_tokens(tokens)?;
         content_encoding.to_tokens(tokens)?;
         content_media_type.to_tokens(tokens)?;
         default.to_tokens(tokens)?;
@@ -1204,6 +1312,8 @@ impl ComponentSchema {
                         name_tokens.extend(
                             quote! { format!("{}", < #rewritten_path as utoipa::ToSchema >::name()) },
                         );
+                        object_schema_reference.request_name = quote! { String::from(< #rewritten_path as utoipa::ToSchema >::request_name()) };
+                        object_schema_reference.response_name = quote! { String::from(< #rewritten_path as utoipa::ToSchema >::response_name()) };
                     }
 
                     object_schema_reference.name = quote! { String::from(#name_tokens) };
@@ -1524,6 +1634,8 @@ impl ComponentSchema {
 
                 Ok(ChildRefIter::Once(std::iter::once(SchemaReference {
                     name: quote! { String::from(< #rewritten_path as utoipa::ToSchema >::name().as_ref()) },
+                    request_name: quote! { String::from(< #rewritten_path as utoipa::ToSchema >::request_name()) },
+                    response_name: quote! { String::from(< #rewritten_path as utoipa::ToSchema >::response_name()) },
                     tokens: quote! { <#rewritten_path as utoipa::PartialSchema>::schema() },
                     references: quote !{ <#rewritten_path as utoipa::ToSchema>::schemas(schemas) },
                     is_inline: false,
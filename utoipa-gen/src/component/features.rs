@@ -15,6 +15,8 @@ use self::validators::{AboveZeroF64, AboveZeroUsize, IsNumber, IsString, IsVec,
 use super::TypeTree;
 
 pub mod attributes;
+#[cfg(any(feature = "validator", feature = "garde"))]
+pub mod external;
 pub mod validation;
 pub mod validators;
 
@@ -106,6 +108,13 @@ pub enum Feature {
     Bound(attributes::Bound),
     Ignore(attributes::Ignore),
     NoRecursion(attributes::NoRecursion),
+    RequestResponse(attributes::RequestResponse),
+    Const(attributes::Const),
+    Not(attributes::Not),
+    PatternProperties(attributes::PatternProperties),
+    UnevaluatedProperties(attributes::UnevaluatedProperties),
+    UnevaluatedItems(attributes::UnevaluatedItems),
+    Contains(attributes::Contains),
     MultipleOf(validation::MultipleOf),
     Maximum(validation::Maximum),
     Minimum(validation::Minimum),
@@ -116,6 +125,8 @@ pub enum Feature {
     Pattern(validation::Pattern),
     MaxItems(validation::MaxItems),
     MinItems(validation::MinItems),
+    MaxContains(validation::MaxContains),
+    MinContains(validation::MinContains),
     MaxProperties(validation::MaxProperties),
     MinProperties(validation::MinProperties),
     Extensions(attributes::Extensions),
@@ -148,8 +159,10 @@ impl Feature {
             Feature::MinItems(min_items) => min_items.validate(
                 ValidatorChain::new(&AboveZeroUsize(&min_items.0)).next(&IsVec(type_tree)),
             ),
+            Feature::MaxContains(max_contains) => max_contains.validate(IsVec(type_tree)),
+            Feature::MinContains(min_contains) => min_contains.validate(IsVec(type_tree)),
             unsupported => {
-                const SUPPORTED_VARIANTS: [&str; 10] = [
+                const SUPPORTED_VARIANTS: [&str; 12] = [
                     "multiple_of",
                     "maximum",
                     "minimum",
@@ -160,6 +173,8 @@ impl Feature {
                     "pattern",
                     "max_items",
                     "min_items",
+                    "max_contains",
+                    "min_contains",
                 ];
                 panic!(
                     "Unsupported variant: `{unsupported}` for Validate::validate, expected one of: {variants}",
@@ -203,6 +218,8 @@ impl ToTokensDiagnostics for Feature {
             Feature::Pattern(pattern) => quote! { .pattern(Some(#pattern)) },
             Feature::MaxItems(max_items) => quote! { .max_items(Some(#max_items)) },
             Feature::MinItems(min_items) => quote! { .min_items(Some(#min_items)) },
+            Feature::MaxContains(max_contains) => quote! { .max_contains(Some(#max_contains)) },
+            Feature::MinContains(min_contains) => quote! { .min_contains(Some(#min_contains)) },
             Feature::MaxProperties(max_properties) => {
                 quote! { .max_properties(Some(#max_properties)) }
             }
@@ -234,6 +251,7 @@ impl ToTokensDiagnostics for Feature {
                 TokenStream::new()
             }
             Feature::NoRecursion(_) => return Err(Diagnostics::new("NoRecursion does not support `ToTokens`")),
+            Feature::RequestResponse(_) => return Err(Diagnostics::new("RequestResponse does not support `ToTokens`")),
             Feature::IntoParamsNames(_) => {
                 return Err(Diagnostics::new("Names feature does not support `ToTokens`")
                     .help("Names is only used with IntoParams to artificially give names for unnamed struct type `IntoParams`."))
@@ -247,6 +265,25 @@ impl ToTokensDiagnostics for Feature {
             }
             Feature::Ignore(_) => return Err(Diagnostics::new("Ignore does not support `ToTokens`")),
             Feature::Extensions(extensions) => quote! { .extensions(Some(#extensions)) },
+            Feature::Const(const_value) => quote! { .const_value(Some(#const_value)) },
+            Feature::Not(_) => {
+                return Err(Diagnostics::new("Not does not support `ToTokens`")
+                    .help("Not is handled by the container of the field as it describes the relation between fields."))
+            }
+            Feature::PatternProperties(_) => {
+                return Err(Diagnostics::new("PatternProperties does not support `ToTokens`")
+                    .help("PatternProperties can only be used with map types such as `HashMap` or `BTreeMap`."))
+            }
+            Feature::UnevaluatedProperties(unevaluated_properties) => {
+                quote! { .unevaluated_properties(Some(#unevaluated_properties)) }
+            }
+            Feature::UnevaluatedItems(unevaluated_items) => {
+                quote! { .unevaluated_items(Some(#unevaluated_items)) }
+            }
+            Feature::Contains(_) => {
+                return Err(Diagnostics::new("Contains does not support `ToTokens`")
+                    .help("Contains can only be used with `Vec`, `array` or `slice` types."))
+            }
         };
 
         tokens.extend(feature);
@@ -296,6 +333,8 @@ impl Display for Feature {
             Feature::Pattern(pattern) => pattern.fmt(f),
             Feature::MaxItems(max_items) => max_items.fmt(f),
             Feature::MinItems(min_items) => min_items.fmt(f),
+            Feature::MaxContains(max_contains) => max_contains.fmt(f),
+            Feature::MinContains(min_contains) => min_contains.fmt(f),
             Feature::MaxProperties(max_properties) => max_properties.fmt(f),
             Feature::MinProperties(min_properties) => min_properties.fmt(f),
             Feature::SchemaWith(schema_with) => schema_with.fmt(f),
@@ -310,7 +349,14 @@ impl Display for Feature {
             Feature::Bound(bound) => bound.fmt(f),
             Feature::Ignore(ignore) => ignore.fmt(f),
             Feature::NoRecursion(no_recursion) => no_recursion.fmt(f),
+            Feature::RequestResponse(request_response) => request_response.fmt(f),
             Feature::Extensions(extensions) => extensions.fmt(f),
+            Feature::Const(const_value) => const_value.fmt(f),
+            Feature::Not(not) => not.fmt(f),
+            Feature::PatternProperties(pattern_properties) => pattern_properties.fmt(f),
+            Feature::UnevaluatedProperties(unevaluated_properties) => unevaluated_properties.fmt(f),
+            Feature::UnevaluatedItems(unevaluated_items) => unevaluated_items.fmt(f),
+            Feature::Contains(contains) => contains.fmt(f),
         }
     }
 }
@@ -346,6 +392,8 @@ impl Validatable for Feature {
             Feature::Pattern(pattern) => pattern.is_validatable(),
             Feature::MaxItems(max_items) => max_items.is_validatable(),
             Feature::MinItems(min_items) => min_items.is_validatable(),
+            Feature::MaxContains(max_contains) => max_contains.is_validatable(),
+            Feature::MinContains(min_contains) => min_contains.is_validatable(),
             Feature::MaxProperties(max_properties) => max_properties.is_validatable(),
             Feature::MinProperties(min_properties) => min_properties.is_validatable(),
             Feature::SchemaWith(schema_with) => schema_with.is_validatable(),
@@ -362,7 +410,16 @@ impl Validatable for Feature {
             Feature::Bound(bound) => bound.is_validatable(),
             Feature::Ignore(ignore) => ignore.is_validatable(),
             Feature::NoRecursion(no_recursion) => no_recursion.is_validatable(),
+            Feature::RequestResponse(request_response) => request_response.is_validatable(),
             Feature::Extensions(extensions) => extensions.is_validatable(),
+            Feature::Const(const_value) => const_value.is_validatable(),
+            Feature::Not(not) => not.is_validatable(),
+            Feature::PatternProperties(pattern_properties) => pattern_properties.is_validatable(),
+            Feature::UnevaluatedProperties(unevaluated_properties) => {
+                unevaluated_properties.is_validatable()
+            }
+            Feature::UnevaluatedItems(unevaluated_items) => unevaluated_items.is_validatable(),
+            Feature::Contains(contains) => contains.is_validatable(),
         }
     }
 }
@@ -412,6 +469,13 @@ is_validatable! {
     attributes::Bound,
     attributes::Ignore,
     attributes::NoRecursion,
+    attributes::RequestResponse,
+    attributes::Const,
+    attributes::Not,
+    attributes::PatternProperties,
+    attributes::UnevaluatedProperties,
+    attributes::UnevaluatedItems,
+    attributes::Contains,
     validation::MultipleOf = true,
     validation::Maximum = true,
     validation::Minimum = true,
@@ -422,6 +486,8 @@ is_validatable! {
     validation::Pattern = true,
     validation::MaxItems = true,
     validation::MinItems = true,
+    validation::MaxContains = true,
+    validation::MinContains = true,
     validation::MaxProperties,
     validation::MinProperties,
     attributes::Extensions
@@ -438,6 +504,8 @@ macro_rules! parse_features {
                 while !input.is_empty() {
                     let ident = input.parse::<syn::Ident>().or_else(|_| {
                         input.parse::<syn::Token![as]>().map(|as_| syn::Ident::new("as", as_.span))
+                    }).or_else(|_| {
+                        input.parse::<syn::Token![const]>().map(|const_| syn::Ident::new("const", const_.span))
                     }).map_err(|error| {
                         syn::Error::new(
                             error.span(),
@@ -643,6 +711,13 @@ impl_feature_into_inner! {
     attributes::Bound,
     attributes::Ignore,
     attributes::NoRecursion,
+    attributes::RequestResponse,
+    attributes::Const,
+    attributes::Not,
+    attributes::PatternProperties,
+    attributes::UnevaluatedProperties,
+    attributes::UnevaluatedItems,
+    attributes::Contains,
     validation::MultipleOf,
     validation::Maximum,
     validation::Minimum,
@@ -653,6 +728,8 @@ impl_feature_into_inner! {
     validation::Pattern,
     validation::MaxItems,
     validation::MinItems,
+    validation::MaxContains,
+    validation::MinContains,
     validation::MaxProperties,
     validation::MinProperties,
 }
@@ -201,6 +201,12 @@ impl ToTokens for Format {
     }
 }
 
+impl From<KnownFormat> for Format {
+    fn from(value: KnownFormat) -> Self {
+        Self(value)
+    }
+}
+
 impl From<Format> for Feature {
     fn from(value: Format) -> Self {
         Feature::Format(value)
@@ -225,6 +231,12 @@ impl ToTokens for WriteOnly {
     }
 }
 
+impl From<bool> for WriteOnly {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
 impl From<WriteOnly> for Feature {
     fn from(value: WriteOnly) -> Self {
         Feature::WriteOnly(value)
@@ -249,6 +261,12 @@ impl ToTokens for ReadOnly {
     }
 }
 
+impl From<bool> for ReadOnly {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
 impl From<ReadOnly> for Feature {
     fn from(value: ReadOnly) -> Self {
         Feature::ReadOnly(value)
@@ -1047,3 +1065,273 @@ impl From<NoRecursion> for Feature {
         Self::NoRecursion(value)
     }
 }
+
+impl_feature! {
+    #[derive(Clone)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct Const(AnyValue);
+}
+
+impl Parse for Const {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self> {
+        parse_utils::parse_next(input, || AnyValue::parse_any(input)).map(Self)
+    }
+}
+
+impl ToTokens for Const {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        tokens.extend(self.0.to_token_stream())
+    }
+}
+
+impl From<Const> for Feature {
+    fn from(value: Const) -> Self {
+        Feature::Const(value)
+    }
+}
+
+impl_feature! {
+    #[derive(Clone)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct Not(Punctuated<Ident, Token![,]>);
+}
+
+impl Not {
+    /// Get the names of the fields which must not be present together with the annotated field.
+    pub fn fields(&self) -> impl Iterator<Item = &Ident> {
+        self.0.iter()
+    }
+}
+
+impl Parse for Not {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        let fields;
+        syn::parenthesized!(fields in input);
+
+        let span = fields.span();
+        let fields = Punctuated::<Ident, Token![,]>::parse_terminated(&fields)?;
+        if fields.is_empty() {
+            return Err(Error::new(
+                span,
+                "expected at least one field name, e.g. `not(other_field)`",
+            ));
+        }
+
+        Ok(Self(fields))
+    }
+}
+
+impl From<Not> for Feature {
+    fn from(value: Not) -> Self {
+        Feature::Not(value)
+    }
+}
+
+impl_feature! {
+    #[derive(Clone)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct PatternProperties(LitStr);
+}
+
+impl Parse for PatternProperties {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        parse_utils::parse_next(input, || input.parse::<LitStr>()).map(Self)
+    }
+}
+
+impl ToTokens for PatternProperties {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens)
+    }
+}
+
+impl From<PatternProperties> for Feature {
+    fn from(value: PatternProperties) -> Self {
+        Self::PatternProperties(value)
+    }
+}
+
+impl_feature! {
+    #[derive(Clone)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct UnevaluatedProperties(bool);
+}
+
+impl Parse for UnevaluatedProperties {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        parse_utils::parse_bool_or_true(input).map(Self)
+    }
+}
+
+impl ToTokens for UnevaluatedProperties {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let unevaluated_properties = &self.0;
+        tokens.extend(quote!(
+            utoipa::openapi::schema::AdditionalProperties::FreeForm(
+                #unevaluated_properties
+            )
+        ))
+    }
+}
+
+impl From<UnevaluatedProperties> for Feature {
+    fn from(value: UnevaluatedProperties) -> Self {
+        Self::UnevaluatedProperties(value)
+    }
+}
+
+impl_feature! {
+    #[derive(Clone)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct UnevaluatedItems(bool);
+}
+
+impl Parse for UnevaluatedItems {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        parse_utils::parse_bool_or_true(input).map(Self)
+    }
+}
+
+impl ToTokens for UnevaluatedItems {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if self.0 {
+            tokens.extend(quote!(utoipa::openapi::schema::Schema::Bool(true)))
+        } else {
+            tokens.extend(quote!(utoipa::openapi::schema::ArrayItems::False))
+        }
+    }
+}
+
+impl From<UnevaluatedItems> for Feature {
+    fn from(value: UnevaluatedItems) -> Self {
+        Self::UnevaluatedItems(value)
+    }
+}
+
+impl_feature! {
+    #[derive(Clone)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct Contains(syn::Type);
+}
+
+impl Contains {
+    /// Create [`TypeTree`] from current [`syn::Type`].
+    pub fn as_type_tree(&self) -> Result<TypeTree<'_>, Diagnostics> {
+        TypeTree::from_type(&self.0)
+    }
+}
+
+impl Parse for Contains {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self> {
+        parse_utils::parse_next(input, || input.parse::<syn::Type>()).map(Self)
+    }
+}
+
+impl From<Contains> for Feature {
+    fn from(value: Contains) -> Self {
+        Self::Contains(value)
+    }
+}
+
+impl_feature! {
+    #[derive(Clone)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct RequestResponse {
+        enabled: bool,
+        request_suffix: Option<String>,
+        response_suffix: Option<String>,
+    }
+}
+
+impl RequestResponse {
+    /// Get request and response suffixes of enabled [`RequestResponse`] using _`Request`_ and
+    /// _`Response`_ for the suffixes not defined.
+    pub fn suffixes(&self) -> Option<(String, String)> {
+        self.enabled.then(|| {
+            (
+                self.request_suffix
+                    .clone()
+                    .unwrap_or_else(|| "Request".to_string()),
+                self.response_suffix
+                    .clone()
+                    .unwrap_or_else(|| "Response".to_string()),
+            )
+        })
+    }
+}
+
+impl Parse for RequestResponse {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self> {
+        let mut request_response = Self {
+            enabled: true,
+            request_suffix: None,
+            response_suffix: None,
+        };
+
+        if input.peek(Paren) {
+            let request_response_stream;
+            syn::parenthesized!(request_response_stream in input);
+
+            while !request_response_stream.is_empty() {
+                let attribute = request_response_stream.parse::<Ident>()?;
+                match &*attribute.to_string() {
+                    "request_suffix" => {
+                        request_response.request_suffix = Some(
+                            parse_utils::parse_next_literal_str(&request_response_stream)?,
+                        )
+                    }
+                    "response_suffix" => {
+                        request_response.response_suffix = Some(
+                            parse_utils::parse_next_literal_str(&request_response_stream)?,
+                        )
+                    }
+                    unexpected => {
+                        return Err(Error::new(
+                            attribute.span(),
+                            format!(
+                                "unexpected identifier {unexpected}, expected any of: request_suffix, response_suffix",
+                            ),
+                        ))
+                    }
+                }
+
+                if !request_response_stream.is_empty() {
+                    request_response_stream.parse::<Token![,]>()?;
+                }
+            }
+        } else {
+            request_response.enabled = parse_utils::parse_bool_or_true(input)?;
+        }
+
+        Ok(request_response)
+    }
+}
+
+impl From<RequestResponse> for Feature {
+    fn from(value: RequestResponse) -> Self {
+        Self::RequestResponse(value)
+    }
+}
+
+#[cfg(feature = "config")]
+impl From<&utoipa_config::RequestResponse<'_>> for RequestResponse {
+    fn from(value: &utoipa_config::RequestResponse<'_>) -> Self {
+        Self {
+            enabled: true,
+            request_suffix: Some(value.request_suffix.to_string()),
+            response_suffix: Some(value.response_suffix.to_string()),
+        }
+    }
+}
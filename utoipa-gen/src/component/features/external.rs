@@ -0,0 +1,450 @@
+//! Mapping of validation attributes of external validation crates to schema validation
+//! [`Feature`]s.
+//!
+//! With `validator` feature `#[validate(...)]` attributes of the
+//! [validator](https://docs.rs/validator) crate are recognized and with `garde` feature
+//! `#[garde(...)]` attributes of the [garde](https://docs.rs/garde) crate are recognized.
+//! Only rules that have an equivalent JSON Schema keyword are mapped, other rules are ignored.
+
+use proc_macro2::{Group, Ident, TokenStream};
+use quote::quote;
+use syn::{
+    meta::ParseNestedMeta, parse::ParseStream, Attribute, Expr, ExprLit, ExprUnary, Lit, Token,
+    UnOp,
+};
+
+use crate::component::{GenericType, TypeTree};
+use crate::Diagnostics;
+
+use super::{attributes, validation, Feature, Parse};
+
+/// Kind of the length constraint based on the type of the field.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LengthKind {
+    String,
+    Items,
+    Properties,
+}
+
+impl LengthKind {
+    fn from_type_tree(type_tree: &TypeTree) -> Self {
+        match type_tree.generic_type {
+            Some(
+                GenericType::Vec
+                | GenericType::LinkedList
+                | GenericType::VecDeque
+                | GenericType::BinaryHeap
+                | GenericType::Set,
+            ) => Self::Items,
+            #[cfg(feature = "smallvec")]
+            Some(GenericType::SmallVec) => Self::Items,
+            Some(GenericType::Map) => Self::Properties,
+            Some(_) => type_tree
+                .children
+                .as_ref()
+                .and_then(|children| children.first())
+                .map(Self::from_type_tree)
+                .unwrap_or(Self::String),
+            None => Self::String,
+        }
+    }
+}
+
+/// Arguments of `length(...)` rule.
+struct Length {
+    /// Optional length mode e.g. `chars` of _`garde`_ length rule.
+    #[cfg_attr(not(feature = "garde"), allow(unused))]
+    mode: Option<Ident>,
+    /// `min`, `max` and `equal` bounds with numeric values.
+    bounds: Vec<(Ident, Expr)>,
+}
+
+/// Collects validation [`Feature`]s from `validate` and `garde` attributes of a field.
+pub struct ExternalValidation {
+    length_kind: LengthKind,
+    features: Vec<Feature>,
+}
+
+impl ExternalValidation {
+    /// Parse external validation attributes of the field with given [`TypeTree`] and push
+    /// resolved [`Feature`]s to the `features`. Features already present in `features` take
+    /// precedence over the ones resolved from external attributes.
+    pub fn push_features(
+        attributes: &[Attribute],
+        type_tree: &TypeTree,
+        features: &mut Vec<Feature>,
+    ) -> Result<(), Diagnostics> {
+        let mut validation = Self {
+            length_kind: LengthKind::from_type_tree(type_tree),
+            features: Vec::new(),
+        };
+
+        for attribute in attributes {
+            #[cfg(feature = "validator")]
+            if attribute.path().is_ident("validate") {
+                attribute.parse_nested_meta(|meta| validation.parse_validator(meta))?;
+            }
+            #[cfg(feature = "garde")]
+            if attribute.path().is_ident("garde") {
+                attribute.parse_nested_meta(|meta| validation.parse_garde(meta))?;
+            }
+        }
+
+        for feature in validation.features {
+            let exists = features.iter().any(|existing| {
+                std::mem::discriminant(existing) == std::mem::discriminant(&feature)
+            });
+            if !exists {
+                features.push(feature);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "validator")]
+    fn parse_validator(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("length") {
+            // validator counts characters of strings
+            let Length { bounds, .. } = Self::parse_length(meta)?;
+            self.push_length(bounds)
+        } else if meta.path.is_ident("range") {
+            meta.parse_nested_meta(|range| self.parse_range(range))
+        } else if meta.path.is_ident("email") {
+            self.push_format(quote!(Email))?;
+            skip_arguments(meta)
+        } else if meta.path.is_ident("url") {
+            self.push_format(quote!("uri"))?;
+            skip_arguments(meta)
+        } else {
+            skip_arguments(meta)
+        }
+    }
+
+    #[cfg(feature = "garde")]
+    fn parse_garde(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("length") {
+            // garde counts bytes of strings by default, only the `chars` mode maps to JSON
+            // Schema length while the default mode counts items of arrays and maps
+            let Length { mode, bounds } = Self::parse_length(meta)?;
+            let is_mapped = match (self.length_kind, mode) {
+                (LengthKind::String, Some(mode)) => mode == "chars",
+                (LengthKind::String, None) => false,
+                (_, mode) => mode.is_none_or(|mode| mode == "simple"),
+            };
+            if is_mapped {
+                self.push_length(bounds)?;
+            }
+            Ok(())
+        } else if meta.path.is_ident("range") {
+            meta.parse_nested_meta(|range| self.parse_range(range))
+        } else if meta.path.is_ident("email") {
+            self.push_format(quote!(Email))
+        } else if meta.path.is_ident("url") {
+            self.push_format(quote!("uri"))
+        } else if meta.path.is_ident("ipv4") {
+            self.push_format(quote!(Ipv4))
+        } else if meta.path.is_ident("ipv6") {
+            self.push_format(quote!(Ipv6))
+        } else if meta.path.is_ident("pattern") && meta.input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in meta.input);
+            if content.peek(syn::LitStr) {
+                let pattern = content.parse::<syn::LitStr>()?;
+                self.push::<validation::Pattern>("pattern", quote!(= #pattern))?;
+            }
+            content.parse::<TokenStream>().map(|_| ())
+        } else {
+            skip_arguments(meta)
+        }
+    }
+
+    /// Parse arguments of `length(...)` rule. The length mode may be given in any position so
+    /// all arguments are parsed before the bounds are used.
+    fn parse_length(meta: ParseNestedMeta) -> syn::Result<Length> {
+        let mut mode = None;
+        let mut bounds = Vec::new();
+
+        meta.parse_nested_meta(|length| {
+            let Some(ident) = length.path.get_ident().cloned() else {
+                return skip_arguments(length);
+            };
+            if matches!(&*ident.to_string(), "min" | "max" | "equal") {
+                if let Some(value) = parse_number(&length)? {
+                    bounds.push((ident, value));
+                }
+                Ok(())
+            } else if length.input.is_empty() || length.input.peek(Token![,]) {
+                mode = Some(ident);
+                Ok(())
+            } else {
+                skip_arguments(length)
+            }
+        })?;
+
+        Ok(Length { mode, bounds })
+    }
+
+    /// Push length `bounds` as `min_length` and `max_length` for strings, as `min_items` and
+    /// `max_items` for arrays and as `min_properties` and `max_properties` for maps.
+    fn push_length(&mut self, bounds: Vec<(Ident, Expr)>) -> syn::Result<()> {
+        for (bound, value) in bounds {
+            let is_min = bound != "max";
+            let is_max = bound != "min";
+            let value = quote!(= #value);
+            match self.length_kind {
+                LengthKind::String => {
+                    if is_min {
+                        self.push::<validation::MinLength>("min_length", value.clone())?;
+                    }
+                    if is_max {
+                        self.push::<validation::MaxLength>("max_length", value)?;
+                    }
+                }
+                LengthKind::Items => {
+                    if is_min {
+                        self.push::<validation::MinItems>("min_items", value.clone())?;
+                    }
+                    if is_max {
+                        self.push::<validation::MaxItems>("max_items", value)?;
+                    }
+                }
+                LengthKind::Properties => {
+                    if is_min {
+                        self.push::<validation::MinProperties>("min_properties", value.clone())?;
+                    }
+                    if is_max {
+                        self.push::<validation::MaxProperties>("max_properties", value)?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parse arguments of `range(...)` rule to `minimum`, `maximum`, `exclusive_minimum` and
+    /// `exclusive_maximum`.
+    fn parse_range(&mut self, range: ParseNestedMeta) -> syn::Result<()> {
+        let name = range
+            .path
+            .get_ident()
+            .map(ToString::to_string)
+            .unwrap_or_default();
+        if !matches!(
+            &*name,
+            "min" | "max" | "exclusive_min" | "exclusive_max" | "equal"
+        ) {
+            return skip_arguments(range);
+        }
+
+        let Some(value) = parse_number(&range)? else {
+            return Ok(());
+        };
+        let value = quote!(= #value);
+        match &*name {
+            "min" => self.push::<validation::Minimum>("minimum", value),
+            "max" => self.push::<validation::Maximum>("maximum", value),
+            "exclusive_min" => {
+                self.push::<validation::ExclusiveMinimum>("exclusive_minimum", value)
+            }
+            "exclusive_max" => {
+                self.push::<validation::ExclusiveMaximum>("exclusive_maximum", value)
+            }
+            _ => {
+                self.push::<validation::Minimum>("minimum", value.clone())?;
+                self.push::<validation::Maximum>("maximum", value)
+            }
+        }
+    }
+
+    fn push_format(&mut self, format: TokenStream) -> syn::Result<()> {
+        self.push::<attributes::Format>("format", quote!(= #format))
+    }
+
+    /// Parse the `value` as feature `T` in the same way as it would be parsed from
+    /// `#[schema(name = value)]`.
+    fn push<T: Parse + Into<Feature>>(
+        &mut self,
+        name: &str,
+        value: TokenStream,
+    ) -> syn::Result<()> {
+        let ident = Ident::new(name, proc_macro2::Span::call_site());
+        let feature =
+            syn::parse::Parser::parse2(|input: ParseStream| T::parse(input, ident), value)?;
+        self.features.push(feature.into());
+
+        Ok(())
+    }
+}
+
+/// Parse value of `name = value` argument. Only numeric literals are supported, for other
+/// expressions such as constants [`None`] is returned.
+fn parse_number(meta: &ParseNestedMeta) -> syn::Result<Option<Expr>> {
+    let expr = meta.value()?.parse::<Expr>()?;
+
+    let is_number = |lit: &Lit| matches!(lit, Lit::Int(_) | Lit::Float(_));
+    let is_number = match &expr {
+        Expr::Lit(ExprLit { lit, .. }) => is_number(lit),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => matches!(&**expr, Expr::Lit(ExprLit { lit, .. }) if is_number(lit)),
+        _ => false,
+    };
+
+    Ok(is_number.then_some(expr))
+}
+
+/// Skip arguments of unsupported rule which may be either `rule = value` or `rule(...)`.
+fn skip_arguments(meta: ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(Token![=]) {
+        meta.value()?.parse::<Expr>().map(|_| ())
+    } else if meta.input.peek(syn::token::Paren) {
+        meta.input.parse::<Group>().map(|_| ())
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::{parse_quote, Attribute, Type};
+
+    use super::ExternalValidation;
+    use crate::component::features::{Feature, ToTokensExt};
+    use crate::component::TypeTree;
+
+    fn push_features(attributes: &[Attribute], ty: Type, features: &mut Vec<Feature>) -> String {
+        let type_tree = TypeTree::from_type(&ty).expect("type tree should be created");
+        ExternalValidation::push_features(attributes, &type_tree, features)
+            .expect("attributes should be parsed");
+
+        features
+            .to_token_stream()
+            .expect("features should be tokenized")
+            .to_string()
+    }
+
+    #[test]
+    #[cfg(feature = "validator")]
+    fn validator_attributes_to_features() {
+        let attributes: Vec<Attribute> = vec![
+            parse_quote!(#[validate(length(min = 1, max = 64), email, custom(function = "check"))]),
+            parse_quote!(#[validate(regex(path = *RE), does_not_contain(pattern = "admin"))]),
+        ];
+        let tokens = push_features(&attributes, parse_quote!(Option<String>), &mut Vec::new());
+
+        assert_eq!(
+            tokens,
+            quote::quote! {
+                .min_length(Some(1)) .max_length(Some(64))
+                .format(Some(utoipa::openapi::schema::SchemaFormat::KnownFormat(utoipa::openapi::schema::KnownFormat::Email)))
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "validator")]
+    fn validator_url_to_custom_format() {
+        let attributes: Vec<Attribute> = vec![parse_quote!(#[validate(url(message = "invalid"))])];
+        let tokens = push_features(&attributes, parse_quote!(String), &mut Vec::new());
+
+        assert_eq!(
+            tokens,
+            quote::quote! {
+                .format(Some(utoipa::openapi::schema::SchemaFormat::Custom(String::from("uri"))))
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "validator")]
+    fn validator_length_and_range_by_type() {
+        let attributes: Vec<Attribute> = vec![parse_quote!(#[validate(length(equal = 2))])];
+        let tokens = push_features(&attributes, parse_quote!(Vec<i32>), &mut Vec::new());
+        assert_eq!(
+            tokens,
+            quote::quote!(.min_items(Some(2)) .max_items(Some(2))).to_string()
+        );
+
+        let attributes: Vec<Attribute> = vec![parse_quote!(
+            #[validate(range(min = -1.5, exclusive_max = 10, max = MAX))]
+        )];
+        let tokens = push_features(&attributes, parse_quote!(f64), &mut Vec::new());
+        assert_eq!(
+            tokens,
+            quote::quote!(.minimum(Some(-1.5)) .exclusive_maximum(Some(10))).to_string()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "validator")]
+    fn validator_attributes_do_not_override_schema_features() {
+        let mut features = Vec::new();
+        push_features(
+            &[parse_quote!(#[validate(length(max = 10))])],
+            parse_quote!(String),
+            &mut features,
+        );
+        let tokens = push_features(
+            &[parse_quote!(#[validate(length(min = 1, max = 64))])],
+            parse_quote!(String),
+            &mut features,
+        );
+
+        assert_eq!(
+            tokens,
+            quote::quote!(.max_length(Some(10)) .min_length(Some(1))).to_string()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "garde")]
+    fn garde_attributes_to_features() {
+        let attributes: Vec<Attribute> = vec![
+            parse_quote!(#[garde(length(min = 1, max = 64, chars), pattern(r"^[a-z]+$"))]),
+            parse_quote!(#[garde(ipv4, custom(is_valid))]),
+        ];
+        let tokens = push_features(&attributes, parse_quote!(String), &mut Vec::new());
+
+        assert_eq!(
+            tokens,
+            quote::quote! {
+                .min_length(Some(1)) .max_length(Some(64)) .pattern(Some("^[a-z]+$"))
+                .format(Some(utoipa::openapi::schema::SchemaFormat::KnownFormat(utoipa::openapi::schema::KnownFormat::Ipv4)))
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "garde")]
+    fn garde_length_modes_and_range() {
+        let attributes: Vec<Attribute> = vec![
+            parse_quote!(#[garde(length(bytes, max = 64), range(min = 0, max = 100))]),
+            parse_quote!(#[garde(length(min = 2), length(max = 8, graphemes))]),
+            parse_quote!(#[garde(inner(length(min = 1)))]),
+        ];
+        let tokens = push_features(&attributes, parse_quote!(String), &mut Vec::new());
+        assert_eq!(
+            tokens,
+            quote::quote!(.minimum(Some(0)) .maximum(Some(100))).to_string()
+        );
+
+        let attributes: Vec<Attribute> = vec![parse_quote!(#[garde(length(max = 5))])];
+        let tokens = push_features(
+            &attributes,
+            parse_quote!(HashMap<String, String>),
+            &mut Vec::new(),
+        );
+        assert_eq!(tokens, quote::quote!(.max_properties(Some(5))).to_string());
+
+        let attributes: Vec<Attribute> = vec![parse_quote!(#[garde(length(chars, max = 5))])];
+        let tokens = push_features(&attributes, parse_quote!(Vec<String>), &mut Vec::new());
+        assert_eq!(tokens, quote::quote!().to_string());
+    }
+}
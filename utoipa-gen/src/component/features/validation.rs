@@ -465,6 +465,80 @@ impl From<MinItems> for Feature {
     }
 }
 
+impl_feature! {
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Clone)]
+    pub struct MaxContains(pub(super) NumberValue, Ident);
+}
+
+impl Validate for MaxContains {
+    fn validate(&self, validator: impl Validator) -> Option<Diagnostics> {
+        match validator.is_valid() {
+            Err(error) => Some(Diagnostics::with_span(self.1.span(), format!("`max_contains` error: {error}"))
+                .help("See more details: `http://json-schema.org/draft/2020-12/json-schema-validation.html#name-maxcontains")),
+            _ => None,
+        }
+    }
+}
+
+impl Parse for MaxContains {
+    fn parse(input: ParseStream, ident: Ident) -> syn::Result<Self>
+    where
+        Self: Sized,
+    {
+        parse_next_number_value(input).map(|number| Self(number, ident))
+    }
+}
+
+impl ToTokens for MaxContains {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens);
+    }
+}
+
+impl From<MaxContains> for Feature {
+    fn from(value: MaxContains) -> Self {
+        Feature::MaxContains(value)
+    }
+}
+
+impl_feature! {
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Clone)]
+    pub struct MinContains(pub(super) NumberValue, Ident);
+}
+
+impl Validate for MinContains {
+    fn validate(&self, validator: impl Validator) -> Option<Diagnostics> {
+        match validator.is_valid() {
+            Err(error) => Some(Diagnostics::with_span(self.1.span(), format!("`min_contains` error: {error}"))
+                .help("See more details: `http://json-schema.org/draft/2020-12/json-schema-validation.html#name-mincontains")),
+            _ => None,
+        }
+    }
+}
+
+impl Parse for MinContains {
+    fn parse(input: ParseStream, ident: Ident) -> syn::Result<Self>
+    where
+        Self: Sized,
+    {
+        parse_next_number_value(input).map(|number| Self(number, ident))
+    }
+}
+
+impl ToTokens for MinContains {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens);
+    }
+}
+
+impl From<MinContains> for Feature {
+    fn from(value: MinContains) -> Self {
+        Feature::MinContains(value)
+    }
+}
+
 impl_feature! {
     #[cfg_attr(feature = "debug", derive(Debug))]
     #[derive(Clone)]
@@ -353,7 +353,7 @@ impl Param {
         let rename = pop_feature!(param_features => Feature::Rename(_) as Option<Rename>)
             .map(|rename| rename.into_value());
         let rename_to = field_serde_params
-            .rename
+            .deserialize_rename
             .as_deref()
             .map(Cow::Borrowed)
             .or(rename.map(Cow::Owned));
@@ -400,6 +400,17 @@ impl Param {
             let alias_type_tree = alias_type.as_ref().map_try(TypeTree::from_type)?;
             let component = alias_type_tree.as_ref().unwrap_or(&component);
 
+            #[cfg(any(feature = "validator", feature = "garde"))]
+            let schema_features = {
+                let mut schema_features = schema_features;
+                features::external::ExternalValidation::push_features(
+                    &field.attrs,
+                    component,
+                    &mut schema_features,
+                )?;
+                schema_features
+            };
+
             let required: Option<features::attributes::Required> =
                 pop_feature!(param_features => Feature::Required(_)).into_inner();
             let component_required =
@@ -25,10 +25,11 @@ use self::{
 
 use super::{
     features::{
-        attributes::{self, As, Bound, Description, NoRecursion, RenameAll},
+        attributes::{self, As, Bound, Description, NoRecursion, Not, RenameAll, RequestResponse},
         parse_features, pop_feature, Feature, FeaturesExt, IntoInner, ToTokensExt,
     },
     serde::{self, SerdeContainer, SerdeValue},
+    serde_as::SerdeAs,
     ComponentDescription, ComponentSchema, FieldRename, FlattenedMapSchema, SchemaReference,
     TypeTree, VariantRename,
 };
@@ -144,6 +145,51 @@ impl ToTokensDiagnostics for Schema<'_> {
             ident.to_string()
         };
 
+        let request_response = variant.get_request_response().map(|(request_suffix, response_suffix)| {
+            let variant_names = |response: bool| {
+                variant
+                    .get_schema_references()
+                    .filter_map(|schema_reference| schema_reference.variant_names(response))
+                    .collect::<Array<_>>()
+            };
+            let request_names = variant_names(false);
+            let response_names = variant_names(true);
+            let (request_required, response_required) = variant.get_variant_required();
+
+            let names = quote! {
+                fn request_name() -> std::borrow::Cow<'static, str> {
+                    std::borrow::Cow::Owned(format!("{}{}", <Self as utoipa::ToSchema>::name(), #request_suffix))
+                }
+
+                fn response_name() -> std::borrow::Cow<'static, str> {
+                    std::borrow::Cow::Owned(format!("{}{}", <Self as utoipa::ToSchema>::name(), #response_suffix))
+                }
+            };
+            let schemas = quote! {
+                schemas.push((
+                    <Self as utoipa::ToSchema>::request_name().into_owned(),
+                    utoipa::__dev::schema_variant(
+                        <Self as utoipa::PartialSchema>::schema(),
+                        utoipa::__dev::SchemaVariant::Request,
+                        &#request_names,
+                        &[#( #request_required ),*],
+                    ),
+                ));
+                schemas.push((
+                    <Self as utoipa::ToSchema>::response_name().into_owned(),
+                    utoipa::__dev::schema_variant(
+                        <Self as utoipa::PartialSchema>::schema(),
+                        utoipa::__dev::SchemaVariant::Response,
+                        &#response_names,
+                        &[#( #response_required ),*],
+                    ),
+                ));
+            };
+
+            (names, schemas)
+        });
+        let (variant_names, variant_schemas) = request_response.unzip();
+
         // TODO refactor this to avoid clone
         if let Some(Bound(bound)) = variant.get_schema_bound() {
             where_clause.predicates.extend(bound.clone());
@@ -170,10 +216,13 @@ impl ToTokensDiagnostics for Schema<'_> {
                     std::borrow::Cow::Borrowed(#name)
                 }
 
+                #variant_names
+
                 fn schemas(schemas: &mut Vec<(String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>)>) {
                     schemas.extend(#schema_refs);
                     #references;
                     #generic_references
+                    #variant_schemas
                 }
             }
         });
@@ -192,6 +241,62 @@ enum SchemaVariant<'a> {
 impl<'a> SchemaVariant<'a> {
     pub fn new(data: &'a Data, root: &'a Root<'a>) -> Result<SchemaVariant<'a>, Diagnostics> {
         match data {
+            Data::Struct(content)
+                if !matches!(content.fields, Fields::Unit)
+                    && serde::parse_container(root.attributes)?.transparent =>
+            {
+                // `#[serde(transparent)]` types serialize exactly as their single non skipped
+                // field thus the type is treated as a newtype wrapping that field.
+                let mut transparent_fields = Punctuated::<Field, Comma>::new();
+                for field in &content.fields {
+                    if !serde::parse_value(&field.attrs)?.skip {
+                        transparent_fields.push(field.clone());
+                    }
+                }
+                if transparent_fields.len() != 1 {
+                    return Err(Diagnostics::with_span(
+                        content.fields.span(),
+                        "serde(transparent) type must have exactly one non skipped field",
+                    ));
+                }
+                let mut transparent_features = root
+                    .attributes
+                    .parse_features::<UnnamedFieldStructFeatures>()?
+                    .into_inner()
+                    .unwrap_or_default();
+
+                // schema features of named field are features of the field schema, the features
+                // only meaningful for object properties are ignored
+                if let Some(field) = transparent_fields
+                    .first_mut()
+                    .filter(|field| field.ident.is_some())
+                {
+                    let mut field_features = field
+                        .attrs
+                        .parse_features::<NamedFieldFeatures>()?
+                        .into_inner()
+                        .unwrap_or_default();
+                    field_features.retain(|feature| {
+                        !matches!(
+                            feature,
+                            Feature::Rename(_)
+                                | Feature::Required(_)
+                                | Feature::Ignore(_)
+                                | Feature::XmlAttr(_)
+                        )
+                    });
+                    transparent_features.extend(field_features);
+                    field
+                        .attrs
+                        .retain(|attribute| !attribute.path().is_ident("schema"));
+                }
+
+                Ok(Self::Unnamed(UnnamedStructSchema::new(
+                    root,
+                    &transparent_fields,
+                    transparent_features,
+                )?))
+            }
             Data::Struct(content) => match &content.fields {
                 Fields::Unnamed(fields) => {
                     let FieldsUnnamed { unnamed, .. } = fields;
@@ -209,12 +314,23 @@ impl<'a> SchemaVariant<'a> {
                 }
                 Fields::Named(fields) => {
                     let FieldsNamed { named, .. } = fields;
-                    let named_features = root
+                    #[allow(unused_mut)]
+                    let mut named_features = root
                         .attributes
                         .parse_features::<NamedFieldStructFeatures>()?
                         .into_inner()
                         .unwrap_or_default();
 
+                    #[cfg(feature = "config")]
+                    if let Some(request_response) = crate::CONFIG.request_response.as_ref() {
+                        if !named_features
+                            .iter()
+                            .any(|feature| matches!(feature, Feature::RequestResponse(_)))
+                        {
+                            named_features.push(Feature::RequestResponse(request_response.into()));
+                        }
+                    }
+
                     Ok(Self::Named(NamedStructSchema::new(
                         root,
                         named,
@@ -249,6 +365,20 @@ impl<'a> SchemaVariant<'a> {
         }
     }
 
+    fn get_request_response(&self) -> Option<&(String, String)> {
+        match self {
+            Self::Named(schema) => schema.request_response.as_ref(),
+            _ => None,
+        }
+    }
+
+    fn get_variant_required(&self) -> (&[String], &[String]) {
+        match self {
+            Self::Named(schema) => (&schema.variant_required.0, &schema.variant_required.1),
+            _ => (&[], &[]),
+        }
+    }
+
     fn get_schema_bound(&self) -> Option<&Bound> {
         match self {
             SchemaVariant::Named(schema) => schema.bound.as_ref(),
@@ -311,6 +441,10 @@ impl ToTokens for UnitStructVariant {
 pub struct NamedStructSchema {
     tokens: TokenStream,
     pub schema_as: Option<As>,
+    /// Suffixes of request and response schema variants if enabled.
+    request_response: Option<(String, String)>,
+    /// Names required only by the request and response schema variants respectively.
+    variant_required: (Vec<String>, Vec<String>),
     fields_references: Vec<SchemaReference>,
     bound: Option<Bound>,
     is_all_of: bool,
@@ -319,10 +453,14 @@ pub struct NamedStructSchema {
 #[cfg_attr(feature = "debug", derive(Debug))]
 struct NamedStructFieldOptions<'a> {
     property: Property,
+    /// Write only property used for deserialize only field names, present when field has
+    /// different serialize and deserialize names or aliases.
+    write_property: Option<Property>,
     renamed_field: Option<Cow<'a, str>>,
     required: Option<super::features::attributes::Required>,
     is_option: bool,
     ignore: Option<LitBoolOrExprPath>,
+    not: Option<Not>,
 }
 
 impl NamedStructSchema {
@@ -338,6 +476,9 @@ impl NamedStructSchema {
         let description: Option<Description> =
             pop_feature!(features => Feature::Description(_)).into_inner();
         let bound = pop_feature!(features => Feature::Bound(_) as Option<Bound>);
+        let request_response =
+            pop_feature!(features => Feature::RequestResponse(_) as Option<RequestResponse>)
+                .and_then(|request_response| request_response.suffixes());
 
         let container_rules = serde::parse_container(root.attributes)?;
 
@@ -351,10 +492,16 @@ impl NamedStructSchema {
                 }
 
                 let field_rules = serde::parse_value(&field.attrs);
-                let field_rules = match field_rules {
+                let mut field_rules = match field_rules {
                     Ok(field_rules) => field_rules,
                     Err(diagnostics) => return Some(Err(diagnostics)),
                 };
+                // fields skipped only in one direction are part of one of the schema variants
+                if request_response.is_some()
+                    && field_rules.skip_serializing != field_rules.skip_deserializing
+                {
+                    field_rules.skip = false;
+                }
                 let field_options = Self::get_named_struct_field_options(
                     root,
                     field,
@@ -395,16 +542,24 @@ impl NamedStructSchema {
             .collect::<Vec<_>>();
 
         let mut object_tokens_empty = true;
-        let object_tokens = fields_vec
+        let mut property_names = Vec::<(&str, Cow<'_, str>, Option<&Not>)>::new();
+        let mut request_required = Vec::<String>::new();
+        let mut response_required = Vec::<String>::new();
+        let mut object_tokens = fields_vec
             .iter()
             .filter(|(_, field_rules, ..)| !field_rules.skip && !field_rules.flatten)
             .map(|(property, field_rules, field_name, field)| {
+                let write_schema = match &property.write_property {
+                    Some(write_property) => Some(as_tokens_or_diagnostics!(write_property)),
+                    None => None,
+                };
                 Ok((
                     property,
                     field_rules,
                     field_name,
                     field,
                     as_tokens_or_diagnostics!(&property.property),
+                    write_schema,
                 ))
             })
             .collect::<Result<Vec<_>, Diagnostics>>()?
@@ -418,12 +573,14 @@ impl NamedStructSchema {
                         required,
                         is_option,
                         ignore,
+                        not,
                         ..
                     },
                     field_rules,
                     field_name,
                     _field,
                     field_schema,
+                    write_schema,
                 )| {
                     object_tokens_empty = false;
                     let rename_to = field_rules
@@ -438,6 +595,7 @@ impl NamedStructSchema {
                     let name =
                         super::rename::<FieldRename>(field_name.borrow(), rename_to, rename_all)
                             .unwrap_or(Cow::Borrowed(field_name.borrow()));
+                    property_names.push((field_name.borrow(), name.clone(), not.as_ref()));
 
                     let mut property_tokens = quote! {
                         object = object.property(#name, #field_schema)
@@ -449,12 +607,46 @@ impl NamedStructSchema {
                         (None, component_required) => component_required,
                     };
 
-                    if required {
+                    let has_split_rename = write_schema.is_some()
+                        && field_rules.rename != field_rules.deserialize_rename;
+                    if required && has_split_rename && request_response.is_some() {
+                        // each name is only required by the schema variant it belongs to
+                        response_required.push(name.to_string());
+                    } else if required {
                         property_tokens.extend(quote! {
                             .required(#name)
                         })
                     }
 
+                    if let Some(write_schema) = write_schema {
+                        if has_split_rename {
+                            let deserialize_rename_to = field_rules
+                                .deserialize_rename
+                                .as_deref()
+                                .map(Cow::Borrowed)
+                                .or(renamed_field.as_ref().cloned());
+                            let deserialize_name = super::rename::<FieldRename>(
+                                field_name.borrow(),
+                                deserialize_rename_to,
+                                rename_all,
+                            )
+                            .unwrap_or(Cow::Borrowed(field_name.borrow()));
+
+                            property_tokens.extend(quote! {
+                                .property(#deserialize_name, #write_schema)
+                            });
+                            if required && request_response.is_some() {
+                                request_required.push(deserialize_name.to_string());
+                            }
+                        }
+
+                        for alias in &field_rules.alias {
+                            property_tokens.extend(quote! {
+                                .property(#alias, #write_schema)
+                            });
+                        }
+                    }
+
                     object_tokens.extend(match ignore {
                         Some(LitBoolOrExprPath::LitBool(bool)) => quote_spanned! {
                             bool.span() => if !#bool {
@@ -477,6 +669,41 @@ impl NamedStructSchema {
                 },
             );
 
+        for (_, name, not) in &property_names {
+            let Some(not) = not else {
+                continue;
+            };
+            let required = not
+                .fields()
+                .map(|field| {
+                    let field_name = field.to_string();
+                    let field_name = field_name.trim_start_matches("r#");
+                    property_names
+                        .iter()
+                        .find(|(other, ..)| *other == field_name)
+                        .map(|(_, other_name, _)| {
+                            quote! { utoipa::openapi::ObjectBuilder::new().required(#other_name) }
+                        })
+                        .ok_or_else(|| {
+                            Diagnostics::with_span(
+                                field.span(),
+                                format!("No such field `{field_name}` in `{}`", root.ident),
+                            )
+                            .help("`not(...)` expects names of the other fields of the struct which must not be present together with this field")
+                        })
+                })
+                .collect::<Result<Vec<_>, Diagnostics>>()?;
+
+            let item = if required.len() == 1 {
+                quote! { #( #required )* }
+            } else {
+                quote! { utoipa::openapi::schema::AnyOfBuilder::new() #( .item(#required) )* }
+            };
+            object_tokens.extend(quote! {
+                object = object.dependent_schema(#name, utoipa::openapi::schema::NotBuilder::new().item(#item));
+            });
+        }
+
         let mut object_tokens = quote! {
             { #object_tokens; object }
         };
@@ -569,6 +796,8 @@ impl NamedStructSchema {
         Ok(Self {
             tokens,
             schema_as,
+            request_response,
+            variant_required: (request_required, response_required),
             fields_references,
             bound,
             is_all_of: all_of,
@@ -631,9 +860,18 @@ impl NamedStructSchema {
             });
 
         let value_type = pop_feature!(field_features => Feature::ValueType(_) as Option<ValueType>);
-        let override_type_tree = value_type
-            .as_ref()
-            .map_try(|value_type| value_type.as_type_tree())?;
+        let serde_as = match (&value_type, &field_rules.serde_as) {
+            (None, Some(adapter)) => SerdeAs::resolve(&field.ty, adapter),
+            _ => None,
+        };
+        let override_type_tree = match (&value_type, &serde_as) {
+            (Some(value_type), _) => Some(value_type.as_type_tree()?),
+            (None, Some(serde_as)) => Some(TypeTree::from_type(&serde_as.ty)?),
+            (None, None) => None,
+        };
+        if let Some(serde_as) = &serde_as {
+            serde_as.push_format(&mut field_features);
+        }
         let comments = CommentAttributes::from_attributes(&field.attrs);
         let description = &ComponentDescription::CommentAttributes(&comments);
 
@@ -647,34 +885,75 @@ impl NamedStructSchema {
 
         let is_option = type_tree.is_option();
 
+        #[cfg(any(feature = "validator", feature = "garde"))]
+        super::features::external::ExternalValidation::push_features(
+            &field.attrs,
+            type_tree,
+            &mut field_features,
+        )?;
+
         let ignore = match pop_feature!(field_features => Feature::Ignore(_)) {
             Some(Feature::Ignore(attributes::Ignore(bool_or_exp))) => Some(bool_or_exp),
             _ => None,
         };
+        let not = pop_feature!(field_features => Feature::Not(_) as Option<Not>);
 
-        Ok(Some(NamedStructFieldOptions {
-            property: if let Some(schema_with) = schema_with {
-                Property::SchemaWith(schema_with)
+        let mut has_access_feature = field_features
+            .iter()
+            .any(|feature| matches!(feature, Feature::ReadOnly(_) | Feature::WriteOnly(_)));
+        if !has_access_feature && field_rules.skip_serializing != field_rules.skip_deserializing {
+            field_features.push(if field_rules.skip_serializing {
+                Feature::WriteOnly(true.into())
+            } else {
+                Feature::ReadOnly(true.into())
+            });
+            has_access_feature = true;
+        }
+        let has_split_rename = field_rules.rename != field_rules.deserialize_rename;
+        let write_features =
+            if !field_rules.flatten && (has_split_rename || !field_rules.alias.is_empty()) {
+                let mut write_features = field_features.clone();
+                if !has_access_feature {
+                    write_features.push(Feature::WriteOnly(true.into()));
+                }
+                Some(write_features)
+            } else {
+                None
+            };
+        if has_split_rename && !field_rules.flatten && !has_access_feature {
+            field_features.push(Feature::ReadOnly(true.into()));
+        }
+
+        let new_property = |features: Vec<Feature>, schema_with: Option<Feature>| {
+            if let Some(schema_with) = schema_with {
+                Ok(Property::SchemaWith(schema_with))
             } else {
                 let props = super::ComponentSchemaProps {
                     type_tree,
-                    features: field_features,
+                    features,
                     description: Some(description),
                     container: &super::Container {
                         generics: root.generics,
                     },
                 };
                 if field_rules.flatten && type_tree.is_map() {
-                    Property::FlattenedMap(FlattenedMapSchema::new(props)?)
+                    Ok::<_, Diagnostics>(Property::FlattenedMap(FlattenedMapSchema::new(props)?))
                 } else {
-                    let schema = ComponentSchema::new(props)?;
-                    Property::Schema(schema)
+                    Ok(Property::Schema(ComponentSchema::new(props)?))
                 }
-            },
+            }
+        };
+
+        Ok(Some(NamedStructFieldOptions {
+            write_property: write_features
+                .map(|features| new_property(features, schema_with.clone()))
+                .transpose()?,
+            property: new_property(field_features, schema_with)?,
             renamed_field: rename_field,
             required,
             is_option,
             ignore,
+            not,
         }))
     }
 }
@@ -728,9 +1007,20 @@ impl UnnamedStructSchema {
         let mut schema_references = Vec::<SchemaReference>::new();
         if all_fields_are_same {
             let value_type = pop_feature!(features => Feature::ValueType(_) as Option<ValueType>);
-            let override_type_tree = value_type
-                .as_ref()
-                .map_try(|value_type| value_type.as_type_tree())?;
+            let serde_as = match (&value_type, fields_len) {
+                (None, 1) => serde::parse_value(&first_field.attrs)?
+                    .serde_as
+                    .and_then(|adapter| SerdeAs::resolve(&first_field.ty, &adapter)),
+                _ => None,
+            };
+            let override_type_tree = match (&value_type, &serde_as) {
+                (Some(value_type), _) => Some(value_type.as_type_tree()?),
+                (None, Some(serde_as)) => Some(TypeTree::from_type(&serde_as.ty)?),
+                (None, None) => None,
+            };
+            if let Some(serde_as) = &serde_as {
+                serde_as.push_format(&mut features);
+            }
 
             if fields_len == 1 {
                 let inline = features::parse_schema_features_with(&first_field.attrs, |input| {
@@ -745,10 +1035,14 @@ impl UnnamedStructSchema {
                 if pop_feature!(features => Feature::Default(crate::features::attributes::Default(None)))
                     .is_some()
                 {
-                    let index: syn::Index = 0.into();
+                    // field of `#[serde(transparent)]` type can be a named field
+                    let member = match &first_field.ident {
+                        Some(ident) => syn::Member::Named(ident.clone()),
+                        None => syn::Member::Unnamed(0.into()),
+                    };
                     // TODO refactor the clone away
                     features.push(Feature::Default(
-                        crate::features::attributes::Default::new_default_trait(root.ident.clone(), index.into()),
+                        crate::features::attributes::Default::new_default_trait(root.ident.clone(), member),
                     ));
                 }
             }
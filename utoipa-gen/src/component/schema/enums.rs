@@ -40,6 +40,8 @@ pub struct PlainEnum<'e> {
     serde_enum_repr: SerdeEnumRepr,
     features: Vec<Feature>,
     pub description: Option<Description>,
+    /// Whether one of the variants is a `#[serde(other)]` catch-all variant.
+    has_other: bool,
 }
 
 impl<'e> PlainEnum<'e> {
@@ -58,21 +60,24 @@ impl<'e> PlainEnum<'e> {
         let description = pop_feature!(features => Feature::Description(_) as Option<Description>);
 
         let container_rules = serde::parse_container(root.attributes)?;
-        let variants_iter = variants
+        let variants = variants
             .iter()
             .map(|variant| match serde::parse_value(&variant.attrs) {
                 Ok(variant_rules) => Ok((variant, variant_rules)),
                 Err(diagnostics) => Err(diagnostics),
             })
-            .collect::<Result<Vec<_>, Diagnostics>>()?
-            .into_iter()
-            .filter_map(|(variant, variant_rules)| {
-                if variant_rules.skip {
-                    None
-                } else {
-                    Some((variant, variant_rules))
-                }
-            });
+            .collect::<Result<Vec<_>, Diagnostics>>()?;
+        let has_other = repr_type_path.is_none()
+            && variants
+                .iter()
+                .any(|(_, variant_rules)| variant_rules.other && !variant_rules.skip);
+        let variants_iter = variants.into_iter().filter_map(|(variant, variant_rules)| {
+            if variant_rules.skip {
+                None
+            } else {
+                Some((variant, variant_rules))
+            }
+        });
 
         let enum_variant = match repr_type_path {
             Some(repr_type_path) => PlainEnumRepr::Repr(
@@ -103,17 +108,40 @@ impl<'e> PlainEnum<'e> {
                     })
                     .collect::<Result<Vec<_>, Diagnostics>>()?
                     .into_iter()
-                    .map(|(variant, variant_rules, mut variant_features)| {
+                    .flat_map(|(variant, variant_rules, mut variant_features)| {
                         let name = &*variant.ident.to_string();
-                        let renamed = super::rename_enum_variant(
+                        let mut names = vec![super::rename_enum_variant(
                             name,
                             &mut variant_features,
                             &variant_rules,
                             &container_rules,
                             rename_all.as_ref(),
-                        );
+                        )
+                        .map_or_else(|| name.to_string(), Cow::into_owned)];
+
+                        // names only accepted when deserializing are valid values as well
+                        let deserialize_name =
+                            (variant_rules.rename != variant_rules.deserialize_rename).then(|| {
+                                let deserialize_rules = serde::SerdeValue {
+                                    rename: variant_rules.deserialize_rename.clone(),
+                                    ..Default::default()
+                                };
+                                super::rename_enum_variant(
+                                    name,
+                                    &mut variant_features,
+                                    &deserialize_rules,
+                                    &container_rules,
+                                    rename_all.as_ref(),
+                                )
+                                .map_or_else(|| name.to_string(), Cow::into_owned)
+                            });
+                        for other in deserialize_name.into_iter().chain(variant_rules.alias) {
+                            if !names.contains(&other) {
+                                names.push(other);
+                            }
+                        }
 
-                        renamed.unwrap_or(Cow::Borrowed(name)).to_token_stream()
+                        names.into_iter().map(|name| name.to_token_stream())
                     })
                     .collect::<Array<TokenStream>>(),
             ),
@@ -125,6 +153,7 @@ impl<'e> PlainEnum<'e> {
             features,
             serde_enum_repr: container_rules.enum_repr,
             description,
+            has_other,
         })
     }
 
@@ -165,6 +194,18 @@ impl ToTokens for PlainEnum<'_> {
         };
 
         match &self.serde_enum_repr {
+            SerdeEnumRepr::ExternallyTagged if self.has_other => {
+                let other = other_schema(&variants);
+                let plain_enum =
+                    EnumSchema::<PlainSchema>::with_types(variants, schema_type, enum_type)
+                        .to_token_stream();
+
+                OneOf {
+                    items: &Array::Owned(vec![plain_enum, other]),
+                    discriminator: None,
+                }
+                .to_tokens(tokens)
+            }
             SerdeEnumRepr::ExternallyTagged => {
                 EnumSchema::<PlainSchema>::with_types(variants, schema_type, enum_type)
                     .to_tokens(tokens);
@@ -177,18 +218,24 @@ impl ToTokens for PlainEnum<'_> {
                 let schema_type = schema_type.as_ref();
                 let enum_type = enum_type.as_ref();
 
+                let mut items = items
+                    .iter()
+                    .map(|item| {
+                        EnumSchema::<PlainSchema>::with_types(
+                            Roo::Ref(item),
+                            Roo::Ref(schema_type),
+                            Roo::Ref(enum_type),
+                        )
+                        .tagged(tag)
+                        .to_token_stream()
+                    })
+                    .collect::<Vec<_>>();
+                if self.has_other {
+                    items.push(other_tagged_schema(tag, &variants));
+                }
+
                 OneOf {
-                    items: &items
-                        .iter()
-                        .map(|item| {
-                            EnumSchema::<PlainSchema>::with_types(
-                                Roo::Ref(item),
-                                Roo::Ref(schema_type),
-                                Roo::Ref(enum_type),
-                            )
-                            .tagged(tag)
-                        })
-                        .collect(),
+                    items: &Array::Owned(items),
                     discriminator: None,
                 }
                 .to_tokens(tokens)
@@ -207,21 +254,27 @@ impl ToTokens for PlainEnum<'_> {
                 let schema_type = schema_type.as_ref();
                 let enum_type = enum_type.as_ref();
 
+                let mut items = items
+                    .iter()
+                    .map(|item| {
+                        EnumSchema::<ObjectSchema>::adjacently_tagged(
+                            PlainSchema::new(
+                                item.deref(),
+                                Roo::Ref(schema_type),
+                                Roo::Ref(enum_type),
+                            ),
+                            content,
+                        )
+                        .tag(tag, PlainSchema::for_name(content))
+                        .to_token_stream()
+                    })
+                    .collect::<Vec<_>>();
+                if self.has_other {
+                    items.push(other_tagged_schema(tag, &variants));
+                }
+
                 OneOf {
-                    items: &items
-                        .iter()
-                        .map(|item| {
-                            EnumSchema::<ObjectSchema>::adjacently_tagged(
-                                PlainSchema::new(
-                                    item.deref(),
-                                    Roo::Ref(schema_type),
-                                    Roo::Ref(enum_type),
-                                ),
-                                content,
-                            )
-                            .tag(tag, PlainSchema::for_name(content))
-                        })
-                        .collect(),
+                    items: &Array::Owned(items),
                     discriminator: None,
                 }
                 .to_tokens(tokens)
@@ -334,6 +387,40 @@ impl<'p> MixedEnum<'p> {
             .note("Read more about discriminators from the specs <https://spec.openapis.org/oas/latest.html#discriminator-object>"));
         }
 
+        let other_tag = match &container_rules.enum_repr {
+            SerdeEnumRepr::InternallyTagged { tag }
+            | SerdeEnumRepr::AdjacentlyTagged { tag, .. }
+                if variants.iter().any(|(variant, variant_rules, _)| {
+                    variant_rules.other && matches!(variant.fields, Fields::Unit)
+                }) =>
+            {
+                Some(tag)
+            }
+            _ => None,
+        };
+        let other = other_tag.map(|tag| {
+            let names = variants
+                .iter()
+                .map(|(variant, variant_rules, variant_features)| {
+                    let name = variant.ident.to_string();
+                    super::rename_enum_variant(
+                        &name,
+                        &mut variant_features.clone(),
+                        variant_rules,
+                        &container_rules,
+                        rename_all.as_ref(),
+                    )
+                    .map(Cow::into_owned)
+                    .unwrap_or(name)
+                })
+                .collect::<Vec<_>>();
+
+            MixedEnumContent {
+                tokens: other_tagged_schema(tag, &names),
+                schema_references: Vec::new(),
+            }
+        });
+
         let mut items = variants
             .into_iter()
             .map(|(variant, variant_serde_rules, mut variant_features)| {
@@ -353,6 +440,7 @@ impl<'p> MixedEnum<'p> {
                 )
             })
             .collect::<Result<Vec<MixedEnumContent>, Diagnostics>>()?;
+        items.extend(other);
 
         let schema_references = items
             .iter_mut()
@@ -1026,6 +1114,31 @@ where
     }
 }
 
+/// Schema of `#[serde(other)]` catch-all variant which accepts any string except the given names
+/// of the known variants.
+fn other_schema<T: ToTokens>(names: &[T]) -> TokenStream {
+    quote! {
+        utoipa::openapi::schema::Object::builder()
+            .schema_type(utoipa::openapi::schema::Type::String)
+            .not_schema(Some(
+                utoipa::openapi::schema::Object::builder()
+                    .schema_type(utoipa::openapi::schema::Type::String)
+                    .enum_values(Some([#( #names ),*]))
+            ))
+    }
+}
+
+/// Schema of `#[serde(other)]` catch-all variant for tagged enums where the `tag` property may
+/// have any value except the given names of the known variants.
+fn other_tagged_schema<T: ToTokens>(tag: &str, names: &[T]) -> TokenStream {
+    let other = other_schema(names);
+    quote! {
+        utoipa::openapi::schema::Object::builder()
+            .property(#tag, #other)
+            .required(#tag)
+    }
+}
+
 /// `RefOrOwned` is simple `Cow` like type to wrap either `ref` or owned value. This allows passing
 /// either owned or referenced values as if they were owned like the `Cow` does but this works with
 /// non cloneable types. Thus values cannot be modified but they can be passed down as re-referenced
@@ -6,15 +6,16 @@ use syn::{
 use crate::{
     component::features::{
         attributes::{
-            AdditionalProperties, As, Bound, ContentEncoding, ContentMediaType, Deprecated,
-            Description, Discriminator, Example, Examples, Format, Ignore, Inline, NoRecursion,
-            Nullable, ReadOnly, Rename, RenameAll, Required, SchemaWith, Title, ValueType,
-            WriteOnly, XmlAttr,
+            AdditionalProperties, As, Bound, Const, Contains, ContentEncoding, ContentMediaType,
+            Deprecated, Description, Discriminator, Example, Examples, Format, Ignore, Inline,
+            NoRecursion, Not, Nullable, PatternProperties, ReadOnly, Rename, RenameAll,
+            RequestResponse, Required, SchemaWith, Title, UnevaluatedItems, UnevaluatedProperties,
+            ValueType, WriteOnly, XmlAttr,
         },
         impl_into_inner, impl_merge, parse_features,
         validation::{
-            ExclusiveMaximum, ExclusiveMinimum, MaxItems, MaxLength, MaxProperties, Maximum,
-            MinItems, MinLength, MinProperties, Minimum, MultipleOf, Pattern,
+            ExclusiveMaximum, ExclusiveMinimum, MaxContains, MaxItems, MaxLength, MaxProperties,
+            Maximum, MinContains, MinItems, MinLength, MinProperties, Minimum, MultipleOf, Pattern,
         },
         Feature, Merge,
     },
@@ -39,7 +40,9 @@ impl Parse for NamedFieldStructFeatures {
             Deprecated,
             Description,
             Bound,
-            NoRecursion
+            NoRecursion,
+            UnevaluatedProperties,
+            RequestResponse
         )))
     }
 }
@@ -65,6 +68,7 @@ impl Parse for UnnamedFieldStructFeatures {
             ContentMediaType,
             Bound,
             NoRecursion,
+            Const,
             MultipleOf,
             Maximum,
             Minimum,
@@ -74,7 +78,11 @@ impl Parse for UnnamedFieldStructFeatures {
             MaxLength,
             Pattern,
             MaxItems,
-            MinItems
+            MinItems,
+            Contains,
+            MaxContains,
+            MinContains,
+            UnevaluatedItems
         )))
     }
 }
@@ -155,7 +163,14 @@ impl Parse for NamedFieldFeatures {
             ContentEncoding,
             ContentMediaType,
             Ignore,
-            NoRecursion
+            NoRecursion,
+            Const,
+            Not,
+            PatternProperties,
+            Contains,
+            MaxContains,
+            MinContains,
+            UnevaluatedItems
         )))
     }
 }
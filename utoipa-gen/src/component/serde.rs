@@ -2,7 +2,7 @@
 
 use std::str::FromStr;
 
-use proc_macro2::{Ident, Span, TokenTree};
+use proc_macro2::{Delimiter, Ident, Span, TokenTree};
 use syn::{buffer::Cursor, Attribute, Error};
 
 use crate::Diagnostics;
@@ -13,7 +13,11 @@ fn parse_next_lit_str(next: Cursor) -> Option<(String, Span)> {
         Some((tt, next)) => match tt {
             TokenTree::Punct(punct) if punct.as_char() == '=' => parse_next_lit_str(next),
             TokenTree::Literal(literal) => {
-                Some((literal.to_string().replace('\"', ""), literal.span()))
+                let value = match syn::Lit::new(literal.clone()) {
+                    syn::Lit::Str(lit_str) => lit_str.value(),
+                    _ => literal.to_string().replace('\"', ""),
+                };
+                Some((value, literal.span()))
             }
             _ => None,
         },
@@ -21,20 +25,72 @@ fn parse_next_lit_str(next: Cursor) -> Option<(String, Span)> {
     }
 }
 
+/// Parse `serialize = "..."` and `deserialize = "..."` pairs from a group such as
+/// `rename(serialize = "...", deserialize = "...")`.
+fn parse_serialize_deserialize(group: Cursor) -> (Option<String>, Option<String>) {
+    let mut serialize = None;
+    let mut deserialize = None;
+
+    let mut rest = group;
+    while let Some((tt, next)) = rest.token_tree() {
+        match tt {
+            TokenTree::Ident(ident) if ident == "serialize" => {
+                serialize = parse_next_lit_str(next).map(|(literal, _)| literal)
+            }
+            TokenTree::Ident(ident) if ident == "deserialize" => {
+                deserialize = parse_next_lit_str(next).map(|(literal, _)| literal)
+            }
+            _ => (),
+        }
+        rest = next;
+    }
+
+    (serialize, deserialize)
+}
+
 #[derive(Default)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(test, derive(PartialEq, Eq))]
 pub struct SerdeValue {
+    /// Set by any of `skip`, `skip_serializing` or `skip_deserializing`.
     pub skip: bool,
+    pub skip_serializing: bool,
+    pub skip_deserializing: bool,
+    /// Name used when serializing.
     pub rename: Option<String>,
+    /// Name used when deserializing, differs from `rename` only with
+    /// `rename(serialize = "...", deserialize = "...")`.
+    pub deserialize_rename: Option<String>,
+    pub alias: Vec<String>,
+    pub other: bool,
     pub default: bool,
     pub flatten: bool,
     pub skip_serializing_if: bool,
     pub double_option: bool,
+    /// `serde_with` adapter type of `#[serde_as(as = "...")]` which is expanded to
+    /// `#[serde(with = "::serde_with::As::<...>")]` by the `#[serde_as]` macro.
+    pub serde_as: Option<syn::Type>,
 }
 
 impl SerdeValue {
     const SERDE_WITH_DOUBLE_OPTION: &'static str = "::serde_with::rust::double_option";
+
+    /// Get the adapter type `T` from `serde_with` path `::serde_with::As::<T>`. The path may also
+    /// be followed by `::serialize` in case of `serialize_with`.
+    fn parse_serde_as(with: &str) -> Option<syn::Type> {
+        let path = syn::parse_str::<syn::Path>(with).ok()?;
+        path.segments
+            .iter()
+            .find(|segment| segment.ident == "As")
+            .and_then(|segment| match &segment.arguments {
+                syn::PathArguments::AngleBracketed(arguments) => arguments.args.first(),
+                _ => None,
+            })
+            .and_then(|argument| match argument {
+                syn::GenericArgument::Type(ty) => Some(ty.clone()),
+                _ => None,
+            })
+    }
 }
 
 impl SerdeValue {
@@ -45,33 +101,46 @@ impl SerdeValue {
             let mut rest = *cursor;
             while let Some((tt, next)) = rest.token_tree() {
                 match tt {
-                    TokenTree::Ident(ident)
-                        if ident == "skip"
-                            || ident == "skip_serializing"
-                            || ident == "skip_deserializing" =>
-                    {
-                        value.skip = true
+                    TokenTree::Ident(ident) if ident == "skip" => value.skip = true,
+                    TokenTree::Ident(ident) if ident == "skip_serializing" => {
+                        value.skip = true;
+                        value.skip_serializing = true
+                    }
+                    TokenTree::Ident(ident) if ident == "skip_deserializing" => {
+                        value.skip = true;
+                        value.skip_deserializing = true
                     }
                     TokenTree::Ident(ident) if ident == "skip_serializing_if" => {
                         value.skip_serializing_if = true
                     }
                     TokenTree::Ident(ident) if ident == "with" => {
-                        value.double_option = parse_next_lit_str(next)
-                            .and_then(|(literal, _)| {
-                                if literal == SerdeValue::SERDE_WITH_DOUBLE_OPTION {
-                                    Some(true)
-                                } else {
-                                    None
-                                }
-                            })
-                            .unwrap_or(false);
+                        if let Some((literal, _)) = parse_next_lit_str(next) {
+                            value.double_option = literal == SerdeValue::SERDE_WITH_DOUBLE_OPTION;
+                            value.serde_as = SerdeValue::parse_serde_as(&literal);
+                        }
+                    }
+                    TokenTree::Ident(ident) if ident == "serialize_with" => {
+                        if let Some((literal, _)) = parse_next_lit_str(next) {
+                            value.serde_as = SerdeValue::parse_serde_as(&literal);
+                        }
                     }
                     TokenTree::Ident(ident) if ident == "flatten" => value.flatten = true,
                     TokenTree::Ident(ident) if ident == "rename" => {
+                        if let Some((group, _, _)) = next.group(Delimiter::Parenthesis) {
+                            let (serialize, deserialize) = parse_serialize_deserialize(group);
+                            value.rename = serialize;
+                            value.deserialize_rename = deserialize;
+                        } else if let Some((literal, _)) = parse_next_lit_str(next) {
+                            value.rename = Some(literal.clone());
+                            value.deserialize_rename = Some(literal);
+                        };
+                    }
+                    TokenTree::Ident(ident) if ident == "alias" => {
                         if let Some((literal, _)) = parse_next_lit_str(next) {
-                            value.rename = Some(literal)
+                            value.alias.push(literal)
                         };
                     }
+                    TokenTree::Ident(ident) if ident == "other" => value.other = true,
                     TokenTree::Ident(ident) if ident == "default" => value.default = true,
                     _ => (),
                 }
@@ -118,6 +187,7 @@ pub struct SerdeContainer {
     pub enum_repr: SerdeEnumRepr,
     pub default: bool,
     pub deny_unknown_fields: bool,
+    pub transparent: bool,
 }
 
 impl SerdeContainer {
@@ -128,6 +198,7 @@ impl SerdeContainer {
     ///     * `untagged = ...`
     ///     * `default = ...`
     ///     * `deny_unknown_fields`
+    ///     * `transparent`
     fn parse_attribute(&mut self, ident: Ident, next: Cursor) -> syn::Result<()> {
         match ident.to_string().as_str() {
             "rename_all" => {
@@ -192,6 +263,9 @@ impl SerdeContainer {
             "deny_unknown_fields" => {
                 self.deny_unknown_fields = true;
             }
+            "transparent" => {
+                self.transparent = true;
+            }
             _ => {}
         }
         Ok(())
@@ -232,12 +306,25 @@ pub fn parse_value(attributes: &[Attribute]) -> Result<SerdeValue, Diagnostics>
             if value.skip {
                 acc.skip = value.skip;
             }
+            if value.skip_serializing {
+                acc.skip_serializing = value.skip_serializing;
+            }
+            if value.skip_deserializing {
+                acc.skip_deserializing = value.skip_deserializing;
+            }
             if value.skip_serializing_if {
                 acc.skip_serializing_if = value.skip_serializing_if;
             }
             if value.rename.is_some() {
                 acc.rename = value.rename;
             }
+            if value.deserialize_rename.is_some() {
+                acc.deserialize_rename = value.deserialize_rename;
+            }
+            acc.alias.extend(value.alias);
+            if value.other {
+                acc.other = value.other;
+            }
             if value.flatten {
                 acc.flatten = value.flatten;
             }
@@ -247,6 +334,9 @@ pub fn parse_value(attributes: &[Attribute]) -> Result<SerdeValue, Diagnostics>
             if value.double_option {
                 acc.double_option = value.double_option;
             }
+            if value.serde_as.is_some() {
+                acc.serde_as = value.serde_as;
+            }
 
             acc
         }))
@@ -270,6 +360,9 @@ pub fn parse_container(attributes: &[Attribute]) -> Result<SerdeContainer, Diagn
             if value.deny_unknown_fields {
                 acc.deny_unknown_fields = value.deny_unknown_fields;
             }
+            if value.transparent {
+                acc.transparent = value.transparent;
+            }
             match value.enum_repr {
                 SerdeEnumRepr::ExternallyTagged => {}
                 SerdeEnumRepr::Untagged
@@ -403,7 +496,10 @@ impl FromStr for RenameRule {
 
 #[cfg(test)]
 mod tests {
-    use super::{parse_container, RenameRule, SerdeContainer, RENAME_RULE_NAME_MAPPING};
+    use super::{
+        parse_container, parse_value, RenameRule, SerdeContainer, SerdeValue,
+        RENAME_RULE_NAME_MAPPING,
+    };
     use syn::{parse_quote, Attribute};
 
     macro_rules! test_rename_rule {
@@ -507,4 +603,38 @@ mod tests {
         let result = parse_container(attributes).expect("parse success");
         assert_eq!(expected, result);
     }
+
+    #[test]
+    fn test_serde_parse_value() {
+        let rename_attribute: syn::Attribute = parse_quote! {
+            #[serde(rename(serialize = "ser_name", deserialize = "de_name"))]
+        };
+        let alias_attribute: syn::Attribute = parse_quote! {
+            #[serde(alias = "first", alias = "second")]
+        };
+        let other_attribute: syn::Attribute = parse_quote! {
+            #[serde(other)]
+        };
+        let with_attribute: syn::Attribute = parse_quote! {
+            #[serde(with = "::serde_with::As::<Vec<(::serde_with::Same, DisplayFromStr)>>")]
+        };
+        let attributes: &[Attribute] = &[
+            rename_attribute,
+            alias_attribute,
+            other_attribute,
+            with_attribute,
+        ];
+
+        let expected = SerdeValue {
+            rename: Some("ser_name".to_string()),
+            deserialize_rename: Some("de_name".to_string()),
+            alias: vec!["first".to_string(), "second".to_string()],
+            other: true,
+            serde_as: Some(parse_quote!(Vec<(::serde_with::Same, DisplayFromStr)>)),
+            ..Default::default()
+        };
+
+        let result = parse_value(attributes).expect("parse success");
+        assert_eq!(expected, result);
+    }
 }
@@ -0,0 +1,341 @@
+//! Resolve serialized types of fields using [serde_with](https://docs.rs/serde_with)
+//! `#[serde_as(as = "...")]` adapters.
+//!
+//! `#[serde_as]` macro expands `#[serde_as(as = "T")]` field attribute to
+//! `#[serde(with = "::serde_with::As::<T>")]` and replaces the `_` placeholders of `T` with
+//! `::serde_with::Same`. The adapter type `T` is resolved against the Rust type of the field to a
+//! type that describes the serialized format of the field.
+//!
+//! Only fields of named field structs and the field of unnamed field struct with single field are
+//! resolved. Unnamed field structs with multiple fields are rendered as array of the common type
+//! of the fields, which per field adapters cannot describe.
+
+use syn::{parse_quote, GenericArgument, PathArguments, Type, TypePath};
+
+use crate::component::features::Feature;
+use crate::schema_type::KnownFormat;
+
+/// Serialized type of a field resolved from `serde_with` adapter type.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct SerdeAs {
+    /// Type describing the serialized format of the field.
+    pub ty: Type,
+    /// Format of the serialized value, e.g. `byte` for `Base64` encoded values.
+    pub format: Option<KnownFormat>,
+}
+
+impl SerdeAs {
+    /// Resolve the serialized type of field of type `ty` serialized with `adapter`.
+    ///
+    /// Returns [`None`] if the `adapter` or some part of it is not recognized in which case the
+    /// field should be treated as its Rust type.
+    pub fn resolve(ty: &Type, adapter: &Type) -> Option<Self> {
+        Some(Self {
+            ty: resolve_type(Some(ty), adapter)?,
+            format: resolve_format(adapter),
+        })
+    }
+
+    /// Push `format` of the serialized value to `features` unless the format is already defined.
+    pub fn push_format(&self, features: &mut Vec<Feature>) {
+        if let Some(format) = &self.format {
+            if !features
+                .iter()
+                .any(|feature| matches!(feature, Feature::Format(_)))
+            {
+                features.push(Feature::Format(format.clone().into()));
+            }
+        }
+    }
+}
+
+fn resolve_type(ty: Option<&Type>, adapter: &Type) -> Option<Type> {
+    match adapter {
+        Type::Infer(_) => ty.cloned(),
+        Type::Group(group) => resolve_type(ty, &group.elem),
+        Type::Paren(paren) => resolve_type(ty, &paren.elem),
+        Type::Tuple(tuple) => {
+            let types = match ty {
+                Some(Type::Tuple(ty)) if ty.elems.len() == tuple.elems.len() => {
+                    ty.elems.iter().map(Some).collect::<Vec<_>>()
+                }
+                _ => vec![None; tuple.elems.len()],
+            };
+            let elems = tuple
+                .elems
+                .iter()
+                .zip(types)
+                .map(|(adapter, ty)| resolve_type(ty, adapter))
+                .collect::<Option<Vec<_>>>()?;
+
+            Some(parse_quote!( ( #( #elems, )* ) ))
+        }
+        Type::Array(array) => {
+            let elem = resolve_type(ty.and_then(sequence_item).as_ref(), &array.elem)?;
+            let len = &array.len;
+
+            Some(parse_quote!( [#elem; #len] ))
+        }
+        Type::Path(path) if path.qself.is_none() => resolve_path(ty, path),
+        _ => None,
+    }
+}
+
+fn resolve_path(ty: Option<&Type>, adapter: &TypePath) -> Option<Type> {
+    let segment = adapter.path.segments.last()?;
+    let arguments = generic_types(&segment.arguments);
+    let name = segment.ident.to_string();
+
+    match &*name {
+        "Same" => ty.cloned(),
+        "DisplayFromStr"
+        | "StringWithSeparator"
+        | "NoneAsEmptyString"
+        | "JsonString"
+        | "Base64"
+        | "Hex" => Some(parse_quote!(String)),
+        "BoolFromInt" => Some(parse_quote!(u8)),
+        "Bytes" => Some(parse_quote!(Vec<u8>)),
+        "FromInto" | "TryFromInto" | "FromIntoRef" | "TryFromIntoRef" => {
+            arguments.first().map(|ty| (*ty).clone())
+        }
+        "DefaultOnError" | "DefaultOnNull" | "IfIsHumanReadable" => match arguments.first() {
+            Some(adapter) => resolve_type(ty, adapter),
+            None => ty.cloned(),
+        },
+        "PickFirst" => match arguments.first() {
+            Some(Type::Tuple(tuple)) => resolve_type(ty, tuple.elems.first()?),
+            _ => None,
+        },
+        "Seq" => {
+            let item = resolve_type(ty.and_then(sequence_item).as_ref(), arguments.first()?)?;
+            Some(parse_quote!(Vec<#item>))
+        }
+        // `Map` is resolved to `BTreeMap` of the resolved key and value types, thus the key is
+        // rendered with its own schema even though it is serialized as JSON object key.
+        "Map" => {
+            let (key, value) = match ty.and_then(sequence_item) {
+                Some(Type::Tuple(tuple)) if tuple.elems.len() == 2 => {
+                    (tuple.elems.first().cloned(), tuple.elems.last().cloned())
+                }
+                _ => (None, None),
+            };
+            let key = resolve_type(key.as_ref(), arguments.first()?)?;
+            let value = resolve_type(value.as_ref(), arguments.get(1)?)?;
+
+            Some(parse_quote!(std::collections::BTreeMap<#key, #value>))
+        }
+        name if name.starts_with("Duration") || name.starts_with("Timestamp") => {
+            match arguments.first() {
+                Some(format) => Some((*format).clone()),
+                None if name.ends_with("WithFrac") => Some(parse_quote!(f64)),
+                None if name.starts_with("Timestamp") => Some(parse_quote!(i64)),
+                None => Some(parse_quote!(u64)),
+            }
+        }
+        _ if !arguments.is_empty() => resolve_container(ty, adapter, &arguments),
+        _ => None,
+    }
+}
+
+/// Resolve generic container adapter such as `Vec<DisplayFromStr>` or `Option<Base64>` by
+/// resolving its type arguments against the type arguments of the field type. Container of the
+/// adapter is used in the resolved type because that is the serialized format.
+fn resolve_container(ty: Option<&Type>, adapter: &TypePath, arguments: &[&Type]) -> Option<Type> {
+    let ty_arguments = match ty {
+        Some(Type::Path(path)) => path
+            .path
+            .segments
+            .last()
+            .map(|segment| generic_types(&segment.arguments))
+            .unwrap_or_default(),
+        _ => Vec::new(),
+    };
+
+    let resolved = if ty_arguments.len() == arguments.len() {
+        arguments
+            .iter()
+            .zip(ty_arguments)
+            .map(|(adapter, ty)| resolve_type(Some(ty), adapter))
+            .collect::<Option<Vec<_>>>()?
+    } else if arguments.len() == 1 {
+        vec![resolve_type(
+            ty.and_then(sequence_item).as_ref(),
+            arguments[0],
+        )?]
+    } else {
+        return None;
+    };
+
+    let mut path = adapter.clone();
+    let segment = path.path.segments.last_mut()?;
+    if let PathArguments::AngleBracketed(angle_bracketed) = &mut segment.arguments {
+        angle_bracketed
+            .args
+            .iter_mut()
+            .filter_map(|argument| match argument {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .zip(resolved)
+            .for_each(|(ty, resolved)| *ty = resolved);
+    }
+
+    Some(Type::Path(path))
+}
+
+/// Get the item type of a sequence type. Items of maps are `(key, value)` tuples.
+fn sequence_item(ty: &Type) -> Option<Type> {
+    match ty {
+        Type::Array(array) => Some((*array.elem).clone()),
+        Type::Slice(slice) => Some((*slice.elem).clone()),
+        Type::Reference(reference) => sequence_item(&reference.elem),
+        Type::Path(path) => {
+            let arguments = generic_types(&path.path.segments.last()?.arguments);
+            match arguments.as_slice() {
+                [item] => Some((*item).clone()),
+                [key, value] => Some(parse_quote!((#key, #value))),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn generic_types(arguments: &PathArguments) -> Vec<&Type> {
+    match arguments {
+        PathArguments::AngleBracketed(arguments) => arguments
+            .args
+            .iter()
+            .filter_map(|argument| match argument {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Resolve format of the serialized value. Format is only resolved for the value itself, not for
+/// items of collections.
+fn resolve_format(adapter: &Type) -> Option<KnownFormat> {
+    let Type::Path(path) = adapter else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    let arguments = generic_types(&segment.arguments);
+
+    match &*segment.ident.to_string() {
+        "Base64" => Some(KnownFormat::Byte),
+        "Option" | "Box" | "DefaultOnError" | "DefaultOnNull" | "IfIsHumanReadable" => {
+            resolve_format(arguments.first()?)
+        }
+        "PickFirst" => match arguments.first()? {
+            Type::Tuple(tuple) => resolve_format(tuple.elems.first()?),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::ToTokens;
+    use syn::{parse_quote, Type};
+
+    use super::SerdeAs;
+
+    fn resolve(ty: Type, adapter: Type) -> Option<String> {
+        SerdeAs::resolve(&ty, &adapter).map(|serde_as| serde_as.ty.to_token_stream().to_string())
+    }
+
+    fn tokens(ty: Type) -> Option<String> {
+        Some(ty.to_token_stream().to_string())
+    }
+
+    #[test]
+    fn resolve_serde_as_adapters() {
+        assert_eq!(
+            resolve(parse_quote!(u64), parse_quote!(DisplayFromStr)),
+            tokens(parse_quote!(String))
+        );
+        assert_eq!(
+            resolve(
+                parse_quote!(Vec<u64>),
+                parse_quote!(Vec<serde_with::DisplayFromStr>)
+            ),
+            tokens(parse_quote!(Vec<String>))
+        );
+        assert_eq!(
+            resolve(
+                parse_quote!(Duration),
+                parse_quote!(DurationSeconds<f64, Flexible>)
+            ),
+            tokens(parse_quote!(f64))
+        );
+        assert_eq!(
+            resolve(parse_quote!(Duration), parse_quote!(DurationMilliSeconds)),
+            tokens(parse_quote!(u64))
+        );
+        assert_eq!(
+            resolve(
+                parse_quote!(Option<Vec<u8>>),
+                parse_quote!(Option<serde_with::base64::Base64>)
+            ),
+            tokens(parse_quote!(Option<String>))
+        );
+        assert_eq!(
+            resolve(parse_quote!(Vec<Foo>), parse_quote!(Vec<Unknown>)),
+            None
+        );
+    }
+
+    #[test]
+    fn resolve_serde_as_maps_and_tuples() {
+        assert_eq!(
+            resolve(
+                parse_quote!(HashMap<u32, Foo>),
+                parse_quote!(Vec<(::serde_with::Same, ::serde_with::Same)>)
+            ),
+            tokens(parse_quote!(Vec<(u32, Foo,)>))
+        );
+        assert_eq!(
+            resolve(
+                parse_quote!(BTreeMap<u32, Foo>),
+                parse_quote!(Seq<(DisplayFromStr, _)>)
+            ),
+            tokens(parse_quote!(Vec<(String, Foo,)>))
+        );
+        assert_eq!(
+            resolve(
+                parse_quote!(Vec<(u32, Foo)>),
+                parse_quote!(Map<DisplayFromStr, _>)
+            ),
+            tokens(parse_quote!(std::collections::BTreeMap<String, Foo>))
+        );
+        assert_eq!(
+            resolve(
+                parse_quote!(HashMap<u32, bool>),
+                parse_quote!(HashMap<DisplayFromStr, BoolFromInt>)
+            ),
+            tokens(parse_quote!(HashMap<String, u8>))
+        );
+    }
+
+    #[test]
+    fn resolve_serde_as_format() {
+        let serde_as = SerdeAs::resolve(
+            &parse_quote!(Option<Vec<u8>>),
+            &parse_quote!(Option<Base64>),
+        )
+        .expect("adapter should be resolved");
+        assert!(matches!(
+            serde_as.format,
+            Some(crate::schema_type::KnownFormat::Byte)
+        ));
+
+        let serde_as = SerdeAs::resolve(&parse_quote!(Vec<Vec<u8>>), &parse_quote!(Vec<Base64>))
+            .expect("adapter should be resolved");
+        assert!(serde_as.format.is_none());
+    }
+}
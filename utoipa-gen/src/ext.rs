@@ -119,6 +119,7 @@ impl ExtSchema<'_> {
                     value_type: crate::component::ValueType::Object,
                     span: Some(path.span()),
                     path,
+                    array_len: None,
                 })
             } else {
                 Cow::Borrowed(actual_body)
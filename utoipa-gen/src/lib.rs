@@ -134,10 +134,24 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 ///   contain. Value must be a number.
 /// * `min_properties = ...` Can be used to define minimum number of properties this struct can
 ///   contain. Value must be a number.
+/// * `unevaluated_properties = ...` Can be used to allow or disallow properties which are not
+///   defined by the struct or its _`#[serde(flatten)]`_ fields. Supports formats
+///   _`unevaluated_properties`_ and _`unevaluated_properties = false`_. Use
+///   _`unevaluated_properties = false`_ to make flattened _`allOf`_ compositions strict.
 ///* `no_recursion` Is used to break from recursion in case of looping schema tree e.g. `Pet` ->
 ///  `Owner` -> `Pet`. _`no_recursion`_ attribute must be used within `Owner` type not to allow
 ///  recurring into `Pet`. Failing to do so will cause infinite loop and runtime **panic**. On
 ///  struct level the _`no_recursion`_ rule will be applied to all of its fields.
+/// * `request_response` Can be used to create request and response variants of the schema in
+///   addition to the schema itself. Request variant leaves out _`read_only`_ and
+///   _`#[serde(skip_deserializing)]`_ fields and response variant leaves out _`write_only`_ and
+///   _`#[serde(skip_serializing)]`_ fields. The variants are named with _`Request`_ and
+///   _`Response`_ suffixes e.g. _`PetRequest`_ and _`PetResponse`_. Types used in _`request_body`_
+///   and _`responses`_ of [`utoipa::path(...)`][path_macro] will reference the matching variant.
+///   Suffixes can be changed with
+///   _`request_response(request_suffix = "...", response_suffix = "...")`_ and the variants can
+///   be disabled with _`request_response = false`_. Variants can be enabled for all types with
+///   `utoipa-config`.
 ///
 /// ## Named Fields Optional Configuration Options for `#[schema(...)]`
 ///
@@ -185,6 +199,15 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 ///   be non-negative integer.
 /// * `min_items = ...` Can be used to define minimum items allowed for `array` fields. Value must
 ///   be non-negative integer.
+/// * `contains = ...` Can be used to define a type which at least one item of `array` fields must
+///   be valid against. E.g. _`contains = Admin`_.
+/// * `max_contains = ...` Can be used to define maximum number of items of `array` fields which
+///   may be valid against _`contains`_. Value must be non-negative integer.
+/// * `min_contains = ...` Can be used to define minimum number of items of `array` fields which
+///   must be valid against _`contains`_. Value must be non-negative integer.
+/// * `unevaluated_items = ...` Can be used to allow or disallow items of `array` fields which are
+///   not evaluated by the schema. Supports formats _`unevaluated_items`_ and
+///   _`unevaluated_items = false`_.
 /// * `schema_with = ...` Use _`schema`_ created by provided function reference instead of the
 ///   default derived _`schema`_. The function must match to `fn() -> Into<RefOr<Schema>>`. It does
 ///   not accept arguments and must return anything that can be converted into `RefOr<Schema>`.
@@ -192,6 +215,11 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 ///   [`HashMap`](std::collections::HashMap) and [`BTreeMap`](std::collections::BTreeMap).
 ///   Free form type enables use of arbitrary types within map values.
 ///   Supports formats _`additional_properties`_ and _`additional_properties = true`_.
+/// * `pattern_properties = ...` Can be used to define a regular expression in _ECMA-262_ dialect
+///   the keys of maps such as [`HashMap`](std::collections::HashMap) and
+///   [`BTreeMap`](std::collections::BTreeMap) must match. The map values are rendered to
+///   _`patternProperties`_ instead of _`additionalProperties`_ which is set to `false`.
+///   E.g. _`pattern_properties = "^[a-z_]+$"`_.
 /// * `deprecated` Can be used to mark the field as deprecated in the generated OpenAPI spec but
 ///   not in the code. If you'd like to mark the field as deprecated in the code as well use
 ///   Rust's own `#[deprecated]` attribute instead.
@@ -199,6 +227,12 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 ///   See [`Object::content_encoding`][schema_object_encoding]
 /// * `content_media_type = ...` Can be used to define MIME type of a string for underlying schema object.
 ///   See [`Object::content_media_type`][schema_object_media_type]
+/// * `const = ...` Can be any value e.g. literal, method reference or _`json!(...)`_. Restricts the
+///   value of the field to this single constant value. Useful e.g. for describing a tag field of
+///   a type. See [`Object::const_value`][schema_object_const]
+/// * `not(...)` Comma separated list of other fields of the struct which must **not** be present
+///   together with this field. E.g. `not(iban)`. This is rendered to the _`dependentSchemas`_ of the
+///   struct's schema. See [`Object::dependent_schemas`][schema_object_dependent_schemas]
 /// * `ignore` or `ignore = ...` Can be used to skip the field from being serialized to OpenAPI schema. (Currently it accepts either a literal `bool` value
 ///   or a path to a function that returns `bool` (`Fn() -> bool`). **Note!** support for function paths is **deprecated** and will be removed in a future version.).
 /// * `no_recursion` Is used to break from recursion in case of looping schema tree e.g. `Pet` ->
@@ -266,6 +300,17 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 ///   See [`Object::content_encoding`][schema_object_encoding]
 /// * `content_media_type = ...` Can be used to define MIME type of a string for underlying schema object.
 ///   See [`Object::content_media_type`][schema_object_media_type]
+/// * `const = ...` Can be any value e.g. literal, method reference or _`json!(...)`_. Restricts the
+///   value of the type to this single constant value.
+/// * `contains = ...` Can be used to define a type which at least one item of `array` fields must
+///   be valid against. E.g. _`contains = Admin`_.
+/// * `max_contains = ...` Can be used to define maximum number of items of `array` fields which
+///   may be valid against _`contains`_. Value must be non-negative integer.
+/// * `min_contains = ...` Can be used to define minimum number of items of `array` fields which
+///   must be valid against _`contains`_. Value must be non-negative integer.
+/// * `unevaluated_items = ...` Can be used to allow or disallow items of `array` fields which are
+///   not evaluated by the schema. Supports formats _`unevaluated_items`_ and
+///   _`unevaluated_items = false`_.
 ///* `no_recursion` Is used to break from recursion in case of looping schema tree e.g. `Pet` ->
 ///  `Owner` -> `Pet`. _`no_recursion`_ attribute must be used within `Owner` type not to allow
 ///  recurring into `Pet`. Failing to do so will cause infinite loop and runtime **panic**.
@@ -477,6 +522,20 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 ///
 /// * `rename_all = "..."` Supported at the container level.
 /// * `rename = "..."` Supported **only** at the field or variant level.
+/// * `rename(serialize = "...", deserialize = "...")` Supported **only** at the field or variant
+///   level. If field has different serialize and deserialize names the serialize name is
+///   documented as _`readOnly`_ property and the deserialize name as _`writeOnly`_ property.
+///   The serialize name of a required field is _`required`_, but with `request_response` the
+///   deserialize name is required in the request variant and the serialize name in the
+///   response variant instead. Unit variants of plain enums list both of the names.
+/// * `alias = "..."` Supported **only** at the field and plain enum unit variant level. Each
+///   alias is documented as additional non required _`writeOnly`_ property or as additional
+///   enum value.
+/// * `transparent` Supported at the container level. The type will be documented as the schema of
+///   its single non skipped field including the _`#[schema(...)]`_ attributes of the field.
+/// * `other` Supported **only** at the unit variant level of plain enums and internally or
+///   adjacently tagged enums. Adds a catch-all schema accepting any other string than the names
+///   of the known variants.
 /// * `skip = "..."` Supported  **only** at the field or variant level.
 /// * `skip_serializing = "..."` Supported  **only** at the field or variant level.
 /// * `skip_deserializing = "..."` Supported  **only** at the field or variant level.
@@ -498,8 +557,8 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 /// [enum representation docs](https://serde.rs/enum-representations.html).
 ///
 /// **Note!** `with` attribute is used in tandem with [serde_with](https://github.com/jonasbb/serde_with) to recognize
-/// _[`double_option`](https://docs.rs/serde_with/latest/serde_with/rust/double_option/index.html)_ from **field value**.
-/// _`double_option`_ is **only** supported attribute from _`serde_with`_ crate.
+/// _[`double_option`](https://docs.rs/serde_with/latest/serde_with/rust/double_option/index.html)_ from **field value**
+/// and _`#[serde_as(as = ...)]`_ adapters. See [`serde_with` `#[serde_as]` support](#serde_with-serde_as-support).
 ///
 /// ```rust
 /// # use serde::Serialize;
@@ -552,6 +611,87 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 ///  }
 /// ```
 ///
+/// # `serde_with` `#[serde_as]` support
+///
+/// Fields of named field structs and the field of unnamed field struct with single field using
+/// [serde_with](https://docs.rs/serde_with) `#[serde_as(as = "...")]` attribute are rendered
+/// according to their serialized format instead of their Rust type. The `#[serde_as]` attribute
+/// must be placed before `#[derive(...)]` as required by _`serde_with`_. Explicitly defined
+/// `value_type` takes precedence over the `serde_as` type.
+///
+/// * `DisplayFromStr`, `StringWithSeparator`, `NoneAsEmptyString`, `JsonString` and `Hex` are
+///   rendered as `string`.
+/// * `Base64` is rendered as `string` with `byte` format.
+/// * `DurationSeconds`, `DurationMilliSeconds`, `TimestampSeconds` and other duration and
+///   timestamp adapters are rendered as their format type, e.g. `DurationSeconds<f64>` is rendered
+///   as `number`. Without explicit format type durations are rendered as `u64`, timestamps as `i64`
+///   and the `WithFrac` variants as `f64`.
+/// * `BoolFromInt` is rendered as `integer` and `Bytes` as array of integers.
+/// * `FromInto<T>` and `TryFromInto<T>` are rendered as `T`.
+/// * `DefaultOnError<T>`, `DefaultOnNull<T>`, `IfIsHumanReadable<T>` and `PickFirst<(T, ...)>`
+///   are rendered as `T`.
+/// * `Seq<T>` is rendered as array of `T` and `Map<K, V>` as map of `K` and `V`. The map is
+///   rendered the same way as `BTreeMap<K, V>` thus `propertyNames` is rendered from `K` even
+///   though JSON object keys are always strings. Use e.g. `Map<DisplayFromStr, V>` to render
+///   non-string keys as `string`.
+/// * Generic containers such as `Vec<T>`, `Option<T>` or `HashMap<K, V>` are rendered as the
+///   container of the adapter with resolved type arguments. E.g. `Vec<(_, _)>` for `HashMap<K, V>`
+///   is rendered as array of `[K, V]` tuples.
+///
+/// Fields using adapters that are not recognized are rendered according to their Rust type and
+/// so are the fields of unnamed field structs with multiple fields since those are rendered as
+/// array of the common type of the fields.
+///
+/// ```rust
+/// # use std::{collections::HashMap, time::Duration};
+/// # use serde_with::{serde_as, DisplayFromStr, DurationSeconds};
+/// #[serde_as]
+/// #[derive(serde::Serialize, utoipa::ToSchema)]
+/// struct Settings {
+///     #[serde_as(as = "DisplayFromStr")]
+///     id: u64,
+///     #[serde_as(as = "DurationSeconds")]
+///     timeout: Duration,
+///     #[serde_as(as = "Vec<(_, _)>")]
+///     limits: HashMap<u32, u32>,
+/// }
+/// ```
+///
+/// # `validator` and `garde` attributes support
+///
+/// With `validator` feature enabled `#[validate(...)]` attributes of the
+/// [validator](https://crates.io/crates/validator) crate and with `garde` feature enabled
+/// `#[garde(...)]` attributes of the [garde](https://crates.io/crates/garde) crate are mapped to
+/// schema validation keywords of the field. Values must be numeric or string literals, rules using
+/// constants or functions are ignored. Explicitly defined `#[schema(...)]` attributes take
+/// precedence over the mapped ones.
+///
+/// * `length(min = ..., max = ..., equal = ...)` Maps to `min_length` and `max_length` for strings,
+///   `min_items` and `max_items` for arrays and `min_properties` and `max_properties` for maps.
+///   _`garde`_ string length is only mapped with the `chars` mode since the default mode counts
+///   bytes and `bytes`, `graphemes` and `utf16` modes do not have JSON Schema equivalent.
+/// * `range(min = ..., max = ..., equal = ...)` Maps to `minimum` and `maximum`. _`validator`_ also
+///   supports `exclusive_min` and `exclusive_max` which map to `exclusive_minimum` and
+///   `exclusive_maximum`.
+/// * `email` Maps to `format = Email`.
+/// * `url` Maps to `format = "uri"`.
+/// * `ipv4` and `ipv6` Map to `format = Ipv4` and `format = Ipv6`. Supported only by _`garde`_.
+/// * `pattern("...")` Maps to `pattern = "..."`. Supported only by _`garde`_.
+///
+/// Other rules will be validated by the respective crate but will not be reflected on the
+/// generated OpenAPI doc.
+///
+/// ```rust
+/// # use validator::Validate;
+/// #[derive(utoipa::ToSchema, Validate)]
+/// struct User {
+///     #[validate(length(min = 1, max = 64), email)]
+///     email: String,
+///     #[validate(range(min = 18, max = 150))]
+///     age: u16,
+/// }
+/// ```
+///
 /// # `#[repr(...)]` attribute support
 ///
 /// [Serde repr](https://github.com/dtolnay/serde-repr) allows field-less enums be represented by
@@ -916,6 +1056,21 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 /// }
 /// ```
 ///
+/// _**Use `const` and `not(...)` to describe a payment which has either a card number or an IBAN but
+/// never both.**_
+/// ```rust
+/// # use utoipa::ToSchema;
+/// #[derive(ToSchema)]
+/// struct Payment {
+///     #[schema(const = "payment")]
+///     kind: String,
+///     #[schema(not(iban))]
+///     card_number: Option<String>,
+///     #[schema(not(card_number))]
+///     iban: Option<String>,
+/// }
+/// ```
+///
 /// _**Use `no_recursion` attribute to break from looping schema tree e.g. `Pet` -> `Owner` ->
 /// `Pet`.**_
 ///
@@ -952,6 +1107,8 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 /// [to_schema_xml]: macro@ToSchema#xml-attribute-configuration-options
 /// [schema_object_encoding]: openapi/schema/struct.Object.html#structfield.content_encoding
 /// [schema_object_media_type]: openapi/schema/struct.Object.html#structfield.content_media_type
+/// [schema_object_const]: openapi/schema/struct.Object.html#structfield.const_value
+/// [schema_object_dependent_schemas]: openapi/schema/struct.Object.html#structfield.dependent_schemas
 /// [path_macro]: macro@path
 /// [const]: https://doc.rust-lang.org/std/keyword.const.html
 pub fn derive_to_schema(input: TokenStream) -> TokenStream {
@@ -996,13 +1153,21 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// # Path Attributes
 ///
 /// * `operation` _**Must be first parameter!**_ Accepted values are known HTTP operations such as
-///   _`get, post, put, delete, head, options, patch, trace`_.
+///   _`get, post, put, delete, head, options, patch, trace, query`_. The _`query`_ operation is
+///   supported from OpenAPI 3.2 onwards.
 ///
 /// * `method(get, head, ...)` Http methods for the operation. This allows defining multiple
 ///   HTTP methods at once for single operation. Either _`operation`_ or _`method(...)`_ _**must be
-///   provided.**_
+///   provided.**_ Methods not known to OpenAPI can be given as string literals e.g.
+///   _`method("COPY")`_ and they are added to _`additionalOperations`_ of the path item which is
+///   supported from OpenAPI 3.2 onwards. Predefined methods must be given as identifiers,
+///   thus e.g. _`method("POST")`_ is rejected in favor of _`method(post)`_.
 ///
 /// * `path = "..."` Must be OpenAPI format compatible str with arguments within curly braces. E.g _`{id}`_
+///   Either _`path`_ or _`webhook`_ _**must be provided.**_
+///
+/// * `webhook = "..."` Name of the webhook for operations which are used as `webhooks(...)` of
+///   [`#[derive(OpenApi)]`][openapi] instead of `paths(...)`. Webhook cannot have a _`path`_.
 ///
 /// * `impl_for = ...` Optional type to implement the [`Path`][path] trait. By default a new type
 ///   is used for the implementation.
@@ -1040,6 +1205,9 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///
 /// * `extensions(...)` List of extensions local to the path operation.
 ///
+/// * `callbacks(...)` Map of out-of-band callbacks the API may initiate in relation to the
+///   operation. See [callbacks syntax](#callbacks-attributes) for more details.
+///
 /// # Request Body Attributes
 ///
 /// ## Simple format definition by `request_body = ...`
@@ -1488,6 +1656,48 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// ("x-another-extension" = json!( "body" ) ),
 /// ```
 ///
+/// # Callbacks Attributes
+///
+/// Callbacks are defined as tuples of callback name and one or more handlers decorated with
+/// `#[utoipa::path(...)]`. The `path` of the callback handler is used as the [runtime
+/// expression][expression] of the callback request e.g. `{$request.body#/callbackUrl}`.
+/// Handlers sharing the same expression will be merged to same path item. Schemas of the callback
+/// handlers will be collected along with the schemas of the operation.
+///
+/// _**Note!**_ Callback handlers with `impl_for = ...` are not supported as the handler is
+/// resolved to the generated `__path_` type.
+///
+/// **Callbacks supported formats:**
+///
+/// ```text
+/// ("onEvent" = on_event),
+/// ("onEvent" = (on_event, events::on_event_cancelled)),
+/// ```
+///
+/// _**Define callback for subscribe operation.**_
+/// ```rust
+/// #[utoipa::path(
+///     post,
+///     path = "{$request.body#/callbackUrl}",
+///     responses(
+///         (status = 200, description = "Event received")
+///     )
+/// )]
+/// async fn on_event() {}
+///
+/// #[utoipa::path(
+///     post,
+///     path = "/subscribe",
+///     responses(
+///         (status = 201, description = "Subscription created")
+///     ),
+///     callbacks(
+///         ("onEvent" = on_event)
+///     )
+/// )]
+/// async fn subscribe() {}
+/// ```
+///
 /// # actix_extras feature support for actix-web
 ///
 /// **actix_extras** feature gives **utoipa** ability to parse path operation information from **actix-web** types and macros.
@@ -1982,9 +2192,14 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///
 /// # OpenApi `#[openapi(...)]` attributes
 ///
+/// * `openapi_version = "..."` OpenAPI version of the document. Accepted values are _`"3.1"`_
+///   which is the default and _`"3.2"`_.
 /// * `paths(...)`  List of method references having attribute [`#[utoipa::path]`][path] macro.
-/// * `components(schemas(...), responses(...))` Takes available _`component`_ configurations. Currently only
-///   _`schema`_ and _`response`_ components are supported.
+/// * `webhooks(...)` List of method references having attribute [`#[utoipa::path]`][path] macro
+///   with `webhook = "..."` defining the name of the webhook.
+///   Webhooks describe requests the API may initiate to the API consumers.
+/// * `components(schemas(...), responses(...), ...)` Takes available _`component`_ configurations.
+///   See [components attribute syntax][components_syntax].
 /// * `schemas(...)` List of [`ToSchema`][to_schema]s in OpenAPI schema.
 /// * `responses(...)` List of types that implement [`ToResponse`][to_response_trait].
 /// * `modifiers(...)` List of items implementing [`Modify`][modify] trait for runtime OpenApi modification.
@@ -2008,6 +2223,13 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///   implement [`OpenApi`][openapi] trait. Nesting allows defining one `OpenApi` per defined path.
 ///   If more instances is defined only latest one will be rentained.
 ///   See the _[nest(...) attribute syntax below]( #nest-attribute-syntax )_
+/// * `include = "..."` Path to a hand written OpenAPI document fragment which paths, components
+///   and other items will be merged to the derived _`OpenApi`_. Relative paths are resolved from
+///   the `CARGO_MANIFEST_DIR` of the crate. The fragment must be a complete OpenAPI document in JSON
+///   or in YAML format. YAML fragments _(`.yaml` or `.yml`)_ require `yaml` feature. The fragment
+///   is embedded and checked at compile time, thus a missing file, invalid syntax or missing
+///   required fields of the document fail the compilation. Items defined by the derive take
+///   precedence over the items of the fragment.
 ///
 ///
 /// OpenApi derive macro will also derive [`Info`][info] for OpenApi specification using Cargo
@@ -2048,6 +2270,10 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// * `external_docs(...)` Optional links to external documents.
 ///      * `url = ...` Mandatory URL for external documentation.
 ///      * `description = ...` Optional description for the _`url`_ link.
+/// * `parent = ...` Optional name of the parent tag to nest the tag under. Supported from
+///   OpenAPI 3.2 onwards.
+/// * `kind = ...` Optional machine readable category of the tag e.g. _`"nav"`_, _`"badge"`_ or
+///   _`"audience"`_. Supported from OpenAPI 3.2 onwards.
 ///
 /// # `servers(...)` attribute syntax
 ///
@@ -2068,6 +2294,38 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// ("port" = (enum_values("8080", "5000", "4545")))
 /// ```
 ///
+/// # `components(...)` attribute syntax
+///
+/// * `schemas(...)` List of [`ToSchema`][to_schema]s in OpenAPI schema.
+/// * `responses(...)` List of types that implement [`ToResponse`][to_response_trait].
+/// * `parameters(...)` List of types that implement [`IntoParams`][into_params]. Each parameter
+///   of the type is added to the reusable parameters by its name.
+/// * `request_bodies(...)` List of named request bodies. Request body can be defined either with
+///   `("name" = Type)` or `("name" = (content = Type, description = "..."))` using the same
+///   syntax as the [`#[utoipa::path(request_body...)]`][path_request_body].
+/// * `headers(...)` List of named headers using the same syntax as the response headers of
+///   [`#[utoipa::path(...)]`][path]. E.g. `("X-Request-Id" = String, description = "...")`.
+/// * `examples(...)` List of named examples using the same syntax as the examples of
+///   [`#[utoipa::path(...)]`][path]. E.g. `("Demo" = (summary = "...", value = json!(...)))`.
+///
+/// The parameters, headers and examples of the operations which have the same name and are
+/// equal to the reusable components defined here will be replaced with references to the
+/// reusable components. Request bodies do not have a name in the operations, thus they are
+/// replaced only when exactly one of the reusable request bodies is equal to them. **Note!** In order for the parameters to be equal they must be fully defined. Use
+/// e.g. `#[into_params(parameter_in = Query)]` for [`IntoParams`][into_params] types if the
+/// parameter location is resolved from framework extractors.
+///
+/// _**Example of components definition**_
+/// ```text
+/// components(
+///     schemas(Pet),
+///     parameters(Pagination),
+///     request_bodies(("NewPet" = Pet)),
+///     headers(("X-Request-Id" = String, description = "Id of the request")),
+///     examples(("Pet" = (summary = "Pet example", value = json!({"name": "Tom"}))))
+/// )
+/// ```
+///
 /// # `nest(...)` attribute syntax
 ///
 /// * `path = ...` Define mandatory path for nesting the [`OpenApi`][openapi_struct].
@@ -2219,6 +2477,29 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///  struct ApiDoc;
 /// ```
 ///
+/// _**Define `newPet` webhook for the api doc.**_
+/// ```rust
+/// # use utoipa::OpenApi;
+/// #[derive(utoipa::ToSchema)]
+/// struct Pet {
+///     name: String,
+/// }
+///
+/// #[utoipa::path(
+///     post,
+///     webhook = "newPet",
+///     request_body = Pet,
+///     responses(
+///         (status = 200, description = "Return 200 if the data was received successfully")
+///     )
+/// )]
+/// fn new_pet() {}
+///
+/// #[derive(OpenApi)]
+/// #[openapi(webhooks(new_pet))]
+/// struct ApiDoc;
+/// ```
+///
 /// [openapi]: trait.OpenApi.html
 /// [openapi_struct]: openapi/struct.OpenApi.html
 /// [to_schema]: derive.ToSchema.html
@@ -2234,6 +2515,9 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// [tags_syntax]: #tags-attribute-syntax
 /// [info_syntax]: #info-attribute-syntax
 /// [servers_syntax]: #servers-attribute-syntax
+/// [components_syntax]: #components-attribute-syntax
+/// [into_params]: trait.IntoParams.html
+/// [path_request_body]: attr.path.html#request-body-attributes
 /// [include_str]: https://doc.rust-lang.org/std/macro.include_str.html
 pub fn openapi(input: TokenStream) -> TokenStream {
     let DeriveInput { attrs, ident, .. } = syn::parse_macro_input!(input);
@@ -2396,6 +2680,8 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 ///
 /// * `rename_all = "..."` Supported at the container level.
 /// * `rename = "..."` Supported **only** at the field level.
+/// * `rename(deserialize = "...")` Supported **only** at the field level. Parameters use the
+///   deserialize name.
 /// * `default` Supported at the container level and field level according to [serde attributes].
 /// * `skip_serializing_if = "..."` Supported  **only** at the field level.
 /// * `with = ...` Supported **only** at field level.
@@ -2405,6 +2691,14 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 ///
 /// Other _`serde`_ attributes will impact the serialization but will not be reflected on the generated OpenAPI doc.
 ///
+/// # `validator` and `garde` attributes support
+///
+/// With `validator` or `garde` feature enabled `#[validate(...)]` and `#[garde(...)]` field
+/// attributes are mapped to parameter schema validation keywords the same way as with
+/// [`ToSchema` derive][to_schema_validator].
+///
+/// [to_schema_validator]: derive@ToSchema#validator-and-garde-attributes-support
+///
 /// # Examples
 ///
 /// _**Demonstrate [`IntoParams`][into_params] usage with resolving `Path` and `Query` parameters
@@ -7,12 +7,17 @@ use syn::{
     punctuated::Punctuated,
     spanned::Spanned,
     token::{And, Comma},
-    Attribute, Error, ExprPath, Token, TypePath,
+    Attribute, Error, ExprPath, LitStr, Token, TypePath,
 };
 
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, quote_spanned, ToTokens};
 
+use crate::path::{
+    example::Example,
+    request_body::RequestBodyAttr,
+    response::header::{self, Header},
+};
 use crate::{
     component::{features::Feature, ComponentSchema, Container, TypeTree},
     parse_utils,
@@ -30,8 +35,10 @@ mod info;
 #[derive(Default)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct OpenApiAttr<'o> {
+    openapi_version: Option<OpenApiVersion>,
     info: Option<Info<'o>>,
     paths: Punctuated<ExprPath, Comma>,
+    webhooks: Punctuated<ExprPath, Comma>,
     components: Components,
     modifiers: Punctuated<Modifier, Comma>,
     security: Option<Array<'static, SecurityRequirementsAttr>>,
@@ -39,22 +46,41 @@ pub struct OpenApiAttr<'o> {
     external_docs: Option<ExternalDocs>,
     servers: Punctuated<Server, Comma>,
     nested: Vec<NestOpenApi>,
+    include: Option<LitStr>,
 }
 
 impl<'o> OpenApiAttr<'o> {
     fn merge(mut self, other: OpenApiAttr<'o>) -> Self {
+        if other.openapi_version.is_some() {
+            self.openapi_version = other.openapi_version;
+        }
         if other.info.is_some() {
             self.info = other.info;
         }
         if !other.paths.is_empty() {
             self.paths = other.paths;
         }
+        if !other.webhooks.is_empty() {
+            self.webhooks = other.webhooks;
+        }
         if !other.components.schemas.is_empty() {
             self.components.schemas = other.components.schemas;
         }
         if !other.components.responses.is_empty() {
             self.components.responses = other.components.responses;
         }
+        if !other.components.parameters.is_empty() {
+            self.components.parameters = other.components.parameters;
+        }
+        if !other.components.request_bodies.is_empty() {
+            self.components.request_bodies = other.components.request_bodies;
+        }
+        if !other.components.headers.is_empty() {
+            self.components.headers = other.components.headers;
+        }
+        if !other.components.examples.is_empty() {
+            self.components.examples = other.components.examples;
+        }
         if other.security.is_some() {
             self.security = other.security;
         }
@@ -67,6 +93,9 @@ impl<'o> OpenApiAttr<'o> {
         if !other.servers.is_empty() {
             self.servers = other.servers;
         }
+        if other.include.is_some() {
+            self.include = other.include;
+        }
 
         self
     }
@@ -84,7 +113,7 @@ pub fn parse_openapi_attrs(attrs: &[Attribute]) -> Result<Option<OpenApiAttr<'_>
 impl Parse for OpenApiAttr<'_> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         const EXPECTED_ATTRIBUTE: &str =
-            "unexpected attribute, expected any of: handlers, components, modifiers, security, tags, external_docs, servers, nest";
+            "unexpected attribute, expected any of: openapi_version, handlers, webhooks, components, modifiers, security, tags, external_docs, servers, nest, include";
         let mut openapi = OpenApiAttr::default();
 
         while !input.is_empty() {
@@ -94,6 +123,11 @@ impl Parse for OpenApiAttr<'_> {
             let attribute = &*ident.to_string();
 
             match attribute {
+                "openapi_version" => {
+                    openapi.openapi_version = Some(parse_utils::parse_next(input, || {
+                        input.parse::<OpenApiVersion>()
+                    })?);
+                }
                 "info" => {
                     let info_stream;
                     parenthesized!(info_stream in input);
@@ -102,6 +136,10 @@ impl Parse for OpenApiAttr<'_> {
                 "paths" => {
                     openapi.paths = parse_utils::parse_comma_separated_within_parenthesis(input)?;
                 }
+                "webhooks" => {
+                    openapi.webhooks =
+                        parse_utils::parse_comma_separated_within_parenthesis(input)?;
+                }
                 "components" => {
                     openapi.components = input.parse()?;
                 }
@@ -132,6 +170,10 @@ impl Parse for OpenApiAttr<'_> {
                     parenthesized!(nest in input);
                     openapi.nested = parse_utils::parse_groups_collect(&nest)?;
                 }
+                "include" => {
+                    openapi.include =
+                        Some(parse_utils::parse_next(input, || input.parse::<LitStr>())?);
+                }
                 _ => {
                     return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE));
                 }
@@ -146,6 +188,31 @@ impl Parse for OpenApiAttr<'_> {
     }
 }
 
+/// OpenAPI version of the document e.g. `"3.2"` or `"3.2.0"`.
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct OpenApiVersion(TokenStream);
+
+impl Parse for OpenApiVersion {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let version = input.parse::<LitStr>()?;
+        match &*version.value() {
+            "3.1" | "3.1.0" => Ok(Self(quote! { utoipa::openapi::OpenApiVersion::Version31 })),
+            "3.2" | "3.2.0" => Ok(Self(quote! { utoipa::openapi::OpenApiVersion::Version32 })),
+            _ => Err(Error::new(
+                version.span(),
+                "unexpected OpenAPI version, expected one of: 3.1, 3.2",
+            )),
+        }
+    }
+}
+
+impl ToTokens for OpenApiVersion {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let version = &self.0;
+        tokens.extend(quote! { .openapi(#version) })
+    }
+}
+
 #[cfg_attr(feature = "debug", derive(Debug))]
 struct Schema(TypePath);
 
@@ -184,6 +251,21 @@ impl Parse for Response {
     }
 }
 
+/// ("name" = Type) or ("name" = (content = Type, description = "..."))
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct RequestBody(LitStr, RequestBodyAttr<'static>);
+
+impl Parse for RequestBody {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse::<LitStr>()?;
+        if input.peek(Token![=]) && input.peek2(syn::token::Paren) {
+            input.parse::<Token![=]>()?;
+        }
+
+        Ok(Self(name, input.parse()?))
+    }
+}
+
 #[cfg_attr(feature = "debug", derive(Debug))]
 struct Modifier {
     and: And,
@@ -215,12 +297,14 @@ struct Tag {
     name: parse_utils::LitStrOrExpr,
     description: Option<parse_utils::LitStrOrExpr>,
     external_docs: Option<ExternalDocs>,
+    parent: Option<parse_utils::LitStrOrExpr>,
+    kind: Option<parse_utils::LitStrOrExpr>,
 }
 
 impl Parse for Tag {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         const EXPECTED_ATTRIBUTE: &str =
-            "unexpected token, expected any of: name, description, external_docs";
+            "unexpected token, expected any of: name, description, external_docs, parent, kind";
 
         let mut tag = Tag::default();
 
@@ -240,6 +324,8 @@ impl Parse for Tag {
                     parenthesized!(content in input);
                     tag.external_docs = Some(content.parse::<ExternalDocs>()?);
                 }
+                "parent" => tag.parent = Some(parse_utils::parse_next_literal_str_or_expr(input)?),
+                "kind" => tag.kind = Some(parse_utils::parse_next_literal_str_or_expr(input)?),
                 _ => return Err(syn::Error::new(ident.span(), EXPECTED_ATTRIBUTE)),
             }
 
@@ -271,6 +357,18 @@ impl ToTokens for Tag {
             });
         }
 
+        if let Some(ref parent) = self.parent {
+            tokens.extend(quote! {
+                .parent(Some(#parent))
+            });
+        }
+
+        if let Some(ref kind) = self.kind {
+            tokens.extend(quote! {
+                .kind(Some(#kind))
+            });
+        }
+
         tokens.extend(quote! { .build() })
     }
 }
@@ -328,12 +426,106 @@ impl OpenApi<'_> {
             Some(nest_tokens)
         }
     }
+
+    /// Get tokens to merge the OpenAPI document fragment defined with `include = "..."` to the
+    /// derived [`OpenApi`]. Relative paths are resolved from the `CARGO_MANIFEST_DIR`.
+    fn include_tokens(&self) -> Result<Option<TokenStream>, Diagnostics> {
+        let Some(include) = self
+            .0
+            .as_ref()
+            .and_then(|attributes| attributes.include.as_ref())
+        else {
+            return Ok(None);
+        };
+
+        let path = include.value();
+        let file = if std::path::Path::new(&path).is_absolute() {
+            quote! { #include }
+        } else {
+            quote! { concat!(env!("CARGO_MANIFEST_DIR"), "/", #include) }
+        };
+
+        let is_yaml = path.ends_with(".yaml") || path.ends_with(".yml");
+        let from = if is_yaml {
+            if cfg!(not(feature = "yaml")) {
+                return Err(Diagnostics::with_span(
+                    include.span(),
+                    "including YAML OpenAPI document requires `yaml` feature",
+                )
+                .help("Enable `yaml` feature of `utoipa` or include JSON document instead"));
+            }
+            quote! { utoipa::openapi::OpenApi::from_yaml }
+        } else {
+            quote! { utoipa::openapi::OpenApi::from_json }
+        };
+        Self::validate_include(include, is_yaml)?;
+
+        Ok(Some(quote! {
+            openapi.merge(#from(include_str!(#file)).unwrap_or_else(|error| {
+                panic!("failed to parse OpenAPI document included from {}: {error}", #include)
+            }));
+        }))
+    }
+
+    /// Read and parse the included OpenAPI document fragment at compile time to report missing
+    /// files, syntax errors and missing required fields as compile errors instead of panicking
+    /// when the [`OpenApi`] is created.
+    fn validate_include(include: &LitStr, is_yaml: bool) -> Result<(), Diagnostics> {
+        let path = include.value();
+        let file = std::env::var("CARGO_MANIFEST_DIR")
+            .map(|manifest_dir| std::path::Path::new(&manifest_dir).join(&path))
+            .unwrap_or_else(|_| std::path::PathBuf::from(&path));
+        let error = |message: String| Diagnostics::with_span(include.span(), message);
+
+        let content = std::fs::read_to_string(&file).map_err(|err| {
+            error(format!(
+                "failed to read OpenAPI document included from `{path}`: {err}"
+            ))
+        })?;
+        let document = if is_yaml {
+            #[cfg(feature = "yaml")]
+            {
+                yaml_serde::from_str::<serde_json::Value>(&content).map_err(|err| err.to_string())
+            }
+            #[cfg(not(feature = "yaml"))]
+            {
+                Ok(serde_json::Value::Null)
+            }
+        } else {
+            serde_json::from_str::<serde_json::Value>(&content).map_err(|err| err.to_string())
+        }
+        .map_err(|err| {
+            error(format!(
+                "failed to parse OpenAPI document included from `{path}`: {err}"
+            ))
+        })?;
+
+        for (field, pointer) in [
+            ("openapi", "/openapi"),
+            ("info.title", "/info/title"),
+            ("info.version", "/info/version"),
+            ("paths", "/paths"),
+        ] {
+            if document.pointer(pointer).is_none() {
+                return Err(error(format!(
+                    "OpenAPI document included from `{path}` is missing required field `{field}`"
+                ))
+                .help("The included document must be a complete OpenAPI document"));
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl ToTokensDiagnostics for OpenApi<'_> {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) -> Result<(), Diagnostics> {
         let OpenApi(attributes, ident) = self;
 
+        let openapi_version = attributes
+            .as_ref()
+            .and_then(|attributes| attributes.openapi_version.as_ref());
+
         let info = Info::merge_with_env_args(
             attributes
                 .as_ref()
@@ -354,7 +546,18 @@ impl ToTokensDiagnostics for OpenApi<'_> {
         let Paths(path_items, handlers) =
             impl_paths(attributes.as_ref().map(|attributes| &attributes.paths));
 
-        let handler_schemas = handlers.iter().fold(
+        let Webhooks(webhooks, webhook_handlers) =
+            impl_webhooks(attributes.as_ref().map(|attributes| &attributes.webhooks));
+
+        let component_schemas = attributes
+            .as_ref()
+            .map_try(|attributes| attributes.components.schema_references())?;
+        let reference_components = attributes
+            .as_ref()
+            .filter(|attributes| attributes.components.has_referenceable_components())
+            .map(|_| quote! { utoipa::__dev::reference_components(&mut openapi); });
+
+        let handler_schemas = handlers.iter().chain(&webhook_handlers).fold(
             quote! {
                     let components = openapi.components.get_or_insert(utoipa::openapi::Components::new());
                     let mut schemas = Vec::<(String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>)>::new();
@@ -418,15 +621,18 @@ impl ToTokensDiagnostics for OpenApi<'_> {
         let nested_tokens = self
             .nested_tokens()
             .map(|tokens| quote! {openapi = openapi #tokens;});
+        let include_tokens = self.include_tokens()?;
         tokens.extend(quote! {
             impl utoipa::OpenApi for #ident {
                 fn openapi() -> utoipa::openapi::OpenApi {
                     use utoipa::{ToSchema, Path};
                     let mut openapi = utoipa::openapi::OpenApiBuilder::new()
+                        #openapi_version
                         .info(#info)
                         .paths({
                             #path_items
                         })
+                        #webhooks
                         #components
                         #securities
                         #tags
@@ -434,9 +640,16 @@ impl ToTokensDiagnostics for OpenApi<'_> {
                         #external_docs
                         .build();
                     #handler_schemas
+                    {
+                        let schemas = &mut schemas;
+                        #component_schemas
+                    }
                     components.schemas.extend(schemas);
+                    #include_tokens
                     #nested_tokens
 
+                    #reference_components
+
                     #modifiers_tokens
 
                     openapi
@@ -453,6 +666,34 @@ impl ToTokensDiagnostics for OpenApi<'_> {
 struct Components {
     schemas: Vec<Schema>,
     responses: Vec<Response>,
+    parameters: Vec<TypePath>,
+    request_bodies: Vec<RequestBody>,
+    headers: Vec<Header>,
+    examples: Vec<Example>,
+}
+
+impl Components {
+    /// Whether any of the reusable components which are referenced by equality from the
+    /// operations is defined.
+    fn has_referenceable_components(&self) -> bool {
+        !self.parameters.is_empty()
+            || !self.request_bodies.is_empty()
+            || !self.headers.is_empty()
+            || !self.examples.is_empty()
+    }
+
+    /// Get schema references of the request bodies to be collected to the schemas of the
+    /// [`OpenApi`].
+    fn schema_references(&self) -> Result<TokenStream, Diagnostics> {
+        Ok(self
+            .request_bodies
+            .iter()
+            .map(|request_body| request_body.1.get_component_schemas())
+            .collect::<Result<Vec<_>, Diagnostics>>()?
+            .into_iter()
+            .flatten()
+            .fold(TokenStream::new(), path::to_schema_references))
+    }
 }
 
 impl Parse for Components {
@@ -460,10 +701,14 @@ impl Parse for Components {
         let content;
         parenthesized!(content in input);
         const EXPECTED_ATTRIBUTE: &str =
-            "unexpected attribute. expected one of: schemas, responses";
+            "unexpected attribute. expected one of: schemas, responses, parameters, request_bodies, headers, examples";
 
         let mut schemas: Vec<Schema> = Vec::new();
         let mut responses: Vec<Response> = Vec::new();
+        let mut parameters: Vec<TypePath> = Vec::new();
+        let mut request_bodies: Vec<RequestBody> = Vec::new();
+        let mut headers: Vec<Header> = Vec::new();
+        let mut examples: Vec<Example> = Vec::new();
 
         while !content.is_empty() {
             let ident = content.parse::<Ident>().map_err(|error| {
@@ -482,6 +727,24 @@ impl Parse for Components {
                         .into_iter()
                         .collect(),
                 ),
+                "parameters" => parameters.append(
+                    &mut parse_utils::parse_comma_separated_within_parenthesis(&content)?
+                        .into_iter()
+                        .collect(),
+                ),
+                "request_bodies" => {
+                    let request_bodies_stream;
+                    parenthesized!(request_bodies_stream in content);
+                    request_bodies.append(&mut parse_utils::parse_groups_collect(
+                        &request_bodies_stream,
+                    )?)
+                }
+                "headers" => headers.append(&mut header::headers(&content)?),
+                "examples" => examples.append(
+                    &mut parse_utils::parse_comma_separated_within_parenthesis(&content)?
+                        .into_iter()
+                        .collect(),
+                ),
                 _ => return Err(syn::Error::new(ident.span(), EXPECTED_ATTRIBUTE)),
             }
 
@@ -490,13 +753,23 @@ impl Parse for Components {
             }
         }
 
-        Ok(Self { schemas, responses })
+        Ok(Self {
+            schemas,
+            responses,
+            parameters,
+            request_bodies,
+            headers,
+            examples,
+        })
     }
 }
 
 impl ToTokensDiagnostics for Components {
     fn to_tokens(&self, tokens: &mut TokenStream) -> Result<(), Diagnostics> {
-        if self.schemas.is_empty() && self.responses.is_empty() {
+        if self.schemas.is_empty()
+            && self.responses.is_empty()
+            && !self.has_referenceable_components()
+        {
             return Ok(());
         }
 
@@ -541,6 +814,32 @@ impl ToTokensDiagnostics for Components {
                     builder_tokens
                 });
 
+        let mut builder_tokens =
+            self.parameters
+                .iter()
+                .fold(builder_tokens, |mut builder_tokens, parameters| {
+                    builder_tokens.extend(quote_spanned! {parameters.span() =>
+                        .parameters_from::<#parameters>(|| None)
+                    });
+                    builder_tokens
+                });
+
+        for RequestBody(name, request_body) in &self.request_bodies {
+            let request_body = request_body.try_to_token_stream()?;
+            builder_tokens.extend(quote! { .request_body(#name, #request_body) });
+        }
+
+        for header in &self.headers {
+            let name = &header.name;
+            let header = header.try_to_token_stream()?;
+            builder_tokens.extend(quote! { .header(#name, #header) });
+        }
+
+        for example in &self.examples {
+            let name = &example.name;
+            builder_tokens.extend(quote! { .example(#name, #example) });
+        }
+
         tokens.extend(quote! { #builder_tokens.build() });
 
         Ok(())
@@ -549,10 +848,13 @@ impl ToTokensDiagnostics for Components {
 
 struct Paths(TokenStream, Vec<(ExprPath, String, Ident)>);
 
-fn impl_paths(handler_paths: Option<&Punctuated<ExprPath, Comma>>) -> Paths {
-    let handlers = handler_paths
+/// Resolve usage of the generated path struct, module tag and the config ident of each handler.
+fn handler_usages<'a, I: IntoIterator<Item = &'a ExprPath>>(
+    handler_paths: I,
+    config_suffix: &str,
+) -> Vec<(ExprPath, String, Ident)> {
+    handler_paths
         .into_iter()
-        .flatten()
         .map(|handler| {
             let segments = handler.path.segments.iter().collect::<Vec<_>>();
             let handler_config_name = segments
@@ -562,7 +864,7 @@ fn impl_paths(handler_paths: Option<&Punctuated<ExprPath, Comma>>) -> Paths {
                 .join("_");
             let handler_fn = &segments.last().unwrap().ident;
             let handler_ident = path::format_path_ident(Cow::Borrowed(handler_fn));
-            let handler_ident_config = format_ident!("{}_config", handler_config_name);
+            let handler_ident_config = format_ident!("{}_{}", handler_config_name, config_suffix);
 
             let tag = segments
                 .iter()
@@ -584,7 +886,11 @@ fn impl_paths(handler_paths: Option<&Punctuated<ExprPath, Comma>>) -> Paths {
             .unwrap();
             (usage, tag, handler_ident_config)
         })
-        .collect::<Vec<_>>();
+        .collect::<Vec<_>>()
+}
+
+fn impl_paths(handler_paths: Option<&Punctuated<ExprPath, Comma>>) -> Paths {
+    let handlers = handler_usages(handler_paths.into_iter().flatten(), "config");
 
     let handlers_impls = handlers
         .iter()
@@ -614,17 +920,9 @@ fn impl_paths(handler_paths: Option<&Punctuated<ExprPath, Comma>>) -> Paths {
         })
         .collect::<TokenStream>();
 
-    let tokens = handler_paths.into_iter().flatten().fold(
+    let tokens = handlers.iter().fold(
         quote! { #handlers_impls utoipa::openapi::path::PathsBuilder::new() },
-        |mut paths, handler| {
-            let segments = handler.path.segments.iter().collect::<Vec<_>>();
-            let handler_config_name = segments
-                .iter()
-                .map(|segment| segment.ident.to_string())
-                .collect::<Vec<_>>()
-                .join("_");
-            let handler_ident_config = format_ident!("{}_config", handler_config_name);
-
+        |mut paths, (_, _, handler_ident_config)| {
             paths.extend(quote! {
                 .path_from::<#handler_ident_config>()
             });
@@ -636,6 +934,60 @@ fn impl_paths(handler_paths: Option<&Punctuated<ExprPath, Comma>>) -> Paths {
     Paths(tokens, handlers)
 }
 
+struct Webhooks(Option<TokenStream>, Vec<(ExprPath, String, Ident)>);
+
+fn impl_webhooks(handler_paths: Option<&Punctuated<ExprPath, Comma>>) -> Webhooks {
+    let handlers = handler_usages(handler_paths.into_iter().flatten(), "webhook_config");
+    if handlers.is_empty() {
+        return Webhooks(None, handlers);
+    }
+
+    let (handlers_impls, webhooks): (TokenStream, Vec<TokenStream>) = handlers
+        .iter()
+        .map(|(usage, tag, handler_ident_nested)| {
+            let config = quote! {
+                #[allow(non_camel_case_types)]
+                struct #handler_ident_nested;
+                #[allow(non_camel_case_types)]
+                impl utoipa::__dev::PathConfig for #handler_ident_nested {
+                    fn path() -> String {
+                        <#usage as utoipa::__dev::Webhook>::name()
+                    }
+                    fn methods() -> Vec<utoipa::openapi::path::HttpMethod> {
+                        <#usage as utoipa::__dev::Webhook>::methods()
+                    }
+                    fn tags_and_operation() -> (Vec<&'static str>, utoipa::openapi::path::Operation) {
+                        let item = <#usage as utoipa::__dev::Webhook>::operation();
+                        let mut tags = <#usage as utoipa::__dev::Tags>::tags();
+                        if !#tag.is_empty() && tags.is_empty() {
+                            tags.push(#tag);
+                        }
+
+                        (tags, item)
+                    }
+                }
+            };
+            let webhook = quote! {
+                (
+                    <#handler_ident_nested as utoipa::Path>::path(),
+                    utoipa::openapi::path::PathItem::from_path::<#handler_ident_nested>(),
+                )
+            };
+
+            (config, webhook)
+        })
+        .unzip();
+
+    let tokens = quote! {
+        .webhooks({
+            #handlers_impls
+            [#( #webhooks ),*]
+        })
+    };
+
+    Webhooks(Some(tokens), handlers)
+}
+
 /// (path = "/nest/path", api = NestApi, tags = ["tag1", "tag2"])
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Default)]
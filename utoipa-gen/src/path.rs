@@ -12,18 +12,20 @@ use syn::{Expr, ExprLit, Lit, LitStr};
 
 use crate::component::{features::attributes::Extensions, ComponentSchema, GenericType, TypeTree};
 use crate::server::Server;
-use crate::token_stream::quote_diagnostics;
+use crate::token_stream::{as_tokens_or_diagnostics, quote_diagnostics};
 use crate::{parse_utils, token_stream::ToTokensDiagnostics, Deprecated, Diagnostics, OptionExt};
 use crate::{schema_type::SchemaType, security_requirement::SecurityRequirementsAttr, Array};
 
+use self::callback::Callback;
 use self::response::Response;
 use self::{parameter::Parameter, request_body::RequestBodyAttr, response::Responses};
 
+mod callback;
 pub mod example;
 pub mod handler;
 pub mod media_type;
 pub mod parameter;
-mod request_body;
+pub mod request_body;
 pub mod response;
 mod status;
 
@@ -44,6 +46,7 @@ pub struct PathAttr<'p> {
     request_body: Option<RequestBodyAttr<'p>>,
     responses: Vec<Response<'p>>,
     pub(super) path: Option<parse_utils::LitStrOrExpr>,
+    webhook: Option<parse_utils::LitStrOrExpr>,
     operation_id: Option<Expr>,
     tag: Option<parse_utils::LitStrOrExpr>,
     tags: Vec<parse_utils::LitStrOrExpr>,
@@ -55,6 +58,7 @@ pub struct PathAttr<'p> {
     summary: Option<parse_utils::LitStrOrExpr>,
     extensions: Option<Extensions>,
     servers: Vec<Server>,
+    callbacks: Vec<Callback>,
 }
 
 impl<'p> PathAttr<'p> {
@@ -109,7 +113,7 @@ impl<'p> PathAttr<'p> {
 
 impl Parse for PathAttr<'_> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected identifier, expected any of: method, get, post, put, delete, options, head, patch, trace, operation_id, path, request_body, responses, params, tag, security, context_path, description, summary";
+        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected identifier, expected any of: method, get, post, put, delete, options, head, patch, trace, query, operation_id, path, webhook, request_body, responses, params, tag, security, context_path, description, summary, callbacks";
         let mut path_attr = PathAttr::default();
 
         while !input.is_empty() {
@@ -135,6 +139,9 @@ impl Parse for PathAttr<'_> {
                 "path" => {
                     path_attr.path = Some(parse_utils::parse_next_literal_str_or_expr(input)?);
                 }
+                "webhook" => {
+                    path_attr.webhook = Some(parse_utils::parse_next_literal_str_or_expr(input)?);
+                }
                 "request_body" => {
                     path_attr.request_body = Some(input.parse::<RequestBodyAttr>()?);
                 }
@@ -195,6 +202,14 @@ impl Parse for PathAttr<'_> {
                             .into_iter()
                             .collect();
                 }
+                "callbacks" => {
+                    let callbacks;
+                    syn::parenthesized!(callbacks in input);
+                    path_attr.callbacks =
+                        Punctuated::<Callback, Token![,]>::parse_terminated(&callbacks)?
+                            .into_iter()
+                            .collect();
+                }
                 _ => {
                     if let Some(path_operation) =
                         attribute_name.parse::<HttpMethod>().into_iter().next()
@@ -226,10 +241,28 @@ pub enum HttpMethod {
     Head,
     Patch,
     Trace,
+    Query,
+    Custom(LitStr),
 }
 
 impl Parse for HttpMethod {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            let method = input.parse::<LitStr>()?;
+            let name = method.value();
+            if let Ok(predefined) = name.to_ascii_lowercase().parse::<HttpMethod>() {
+                return Err(syn::Error::new(
+                    method.span(),
+                    format!(
+                        "unexpected custom http method: \"{name}\", use predefined method: {} instead",
+                        predefined.name()
+                    ),
+                ));
+            }
+
+            return Ok(Self::Custom(method));
+        }
+
         let method = input
             .parse::<Ident>()
             .map_err(|error| syn::Error::new(error.span(), HttpMethod::ERROR_MESSAGE))?;
@@ -242,7 +275,7 @@ impl Parse for HttpMethod {
 }
 
 impl HttpMethod {
-    const ERROR_MESSAGE: &'static str = "unexpected http method, expected one of: get, post, put, delete, options, head, patch, trace";
+    const ERROR_MESSAGE: &'static str = "unexpected http method, expected one of: get, post, put, delete, options, head, patch, trace, query or custom method as string literal e.g. \"COPY\"";
     /// Create path operation from ident
     ///
     /// Ident must have value of http request type as lower case string such as `get`.
@@ -262,6 +295,24 @@ impl HttpMethod {
     }
 }
 
+impl HttpMethod {
+    /// Get name of the method as used in the OpenAPI document.
+    fn name(&self) -> String {
+        match self {
+            Self::Get => String::from("get"),
+            Self::Post => String::from("post"),
+            Self::Put => String::from("put"),
+            Self::Delete => String::from("delete"),
+            Self::Options => String::from("options"),
+            Self::Head => String::from("head"),
+            Self::Patch => String::from("patch"),
+            Self::Trace => String::from("trace"),
+            Self::Query => String::from("query"),
+            Self::Custom(method) => method.value(),
+        }
+    }
+}
+
 impl FromStr for HttpMethod {
     type Err = Error;
 
@@ -275,6 +326,7 @@ impl FromStr for HttpMethod {
             "head" => Ok(Self::Head),
             "patch" => Ok(Self::Patch),
             "trace" => Ok(Self::Trace),
+            "query" => Ok(Self::Query),
             _ => Err(Error::other(HttpMethod::ERROR_MESSAGE)),
         }
     }
@@ -291,6 +343,10 @@ impl ToTokens for HttpMethod {
             Self::Head => quote! { utoipa::openapi::HttpMethod::Head },
             Self::Patch => quote! { utoipa::openapi::HttpMethod::Patch },
             Self::Trace => quote! { utoipa::openapi::HttpMethod::Trace },
+            Self::Query => quote! { utoipa::openapi::HttpMethod::Query },
+            Self::Custom(method) => {
+                quote! { utoipa::openapi::HttpMethod::Custom(String::from(#method)) }
+            }
         };
 
         tokens.extend(path_item_type);
@@ -389,50 +445,63 @@ impl<'p> ToTokensDiagnostics for Path<'p> {
         }
 
         let method_operations = methods.iter().collect::<Array<_>>();
+        let method_names = methods.iter().map(HttpMethod::name).collect::<Array<_>>();
 
         let path = self
             .path_attr
             .path
             .as_ref()
             .map(|path| path.to_token_stream())
-            .or(self.path.as_ref().map(|path| path.to_token_stream()))
-            .ok_or_else(|| {
+            .or(self.path.as_ref().map(|path| path.to_token_stream()));
+        let path = match (path, &self.path_attr.webhook) {
+            (Some(_), Some(webhook)) => {
+                return Err(Diagnostics::with_span(
+                    webhook.span(),
+                    "webhook cannot have a path, remove the path or the webhook",
+                ))
+            }
+            (None, None) => {
                 let diagnostics = || {
-                    Diagnostics::new("path is not defined for #[utoipa::path(...)]").help(
-                        r#"Did you forget to define it in #[utoipa::path(..., path = "...")]"#,
-                    )
+                    Diagnostics::new("path is not defined for #[utoipa::path(...)]")
+                        .help(
+                            r#"Did you forget to define it in #[utoipa::path(..., path = "...")]"#,
+                        )
+                        .help(r#"Or perhaps #[utoipa::path(..., webhook = "...")] for webhook"#)
                 };
 
                 #[cfg(any(feature = "actix_extras", feature = "rocket_extras"))]
                 {
-                    diagnostics().help(
+                    return Err(diagnostics().help(
                         "Did you forget to define operation path attribute macro e.g #[get(...)]",
-                    )
+                    ));
                 }
 
                 #[cfg(not(any(feature = "actix_extras", feature = "rocket_extras")))]
-                diagnostics()
-            })?;
+                return Err(diagnostics());
+            }
+            (path, _) => path,
+        };
 
-        let path_with_context_path = self
-            .path_attr
-            .context_path
-            .as_ref()
-            .map(|context_path| {
-                let context_path = context_path.to_token_stream();
-                let context_path_tokens = quote! {
-                    format!("{}{}",
-                        #context_path,
-                        #path
-                    )
-                };
-                context_path_tokens
-            })
-            .unwrap_or_else(|| {
-                quote! {
-                    String::from(#path)
-                }
-            });
+        let path_with_context_path = path.as_ref().map(|path| {
+            self.path_attr
+                .context_path
+                .as_ref()
+                .map(|context_path| {
+                    let context_path = context_path.to_token_stream();
+                    let context_path_tokens = quote! {
+                        format!("{}{}",
+                            #context_path,
+                            #path
+                        )
+                    };
+                    context_path_tokens
+                })
+                .unwrap_or_else(|| {
+                    quote! {
+                        String::from(#path)
+                    }
+                })
+        });
 
         let split_comment = self.doc_comments.as_ref().map(|comments| {
             let mut split = comments.split(|comment| comment.trim().is_empty());
@@ -470,7 +539,7 @@ impl<'p> ToTokensDiagnostics for Path<'p> {
 
         let operation: Operation = Operation {
             deprecated: self.deprecated,
-            operation_id,
+            operation_id: operation_id.clone(),
             summary,
             description,
             parameters: self.path_attr.params.as_ref(),
@@ -479,37 +548,9 @@ impl<'p> ToTokensDiagnostics for Path<'p> {
             security: self.path_attr.security.as_ref(),
             extensions: self.path_attr.extensions.as_ref(),
             servers: self.path_attr.servers.as_ref(),
+            callbacks: self.path_attr.callbacks.as_ref(),
         };
 
-        fn to_schema_references(
-            mut schemas: TokenStream2,
-            (is_inline, component_schema): (bool, ComponentSchema),
-        ) -> TokenStream2 {
-            for reference in component_schema.schema_references {
-                let name = &reference.name;
-                let tokens = &reference.tokens;
-                let references = &reference.references;
-
-                #[cfg(feature = "config")]
-                let should_collect_schema = (matches!(
-                    crate::CONFIG.schema_collect,
-                    utoipa_config::SchemaCollect::NonInlined
-                ) && !is_inline)
-                    || matches!(
-                        crate::CONFIG.schema_collect,
-                        utoipa_config::SchemaCollect::All
-                    );
-                #[cfg(not(feature = "config"))]
-                let should_collect_schema = !is_inline;
-                if should_collect_schema {
-                    schemas.extend(quote!( schemas.push((#name, #tokens)); ));
-                }
-                schemas.extend(quote!( #references; ));
-            }
-
-            schemas
-        }
-
         let response_schemas = self
             .path_attr
             .responses
@@ -518,6 +559,10 @@ impl<'p> ToTokensDiagnostics for Path<'p> {
             .collect::<Result<Vec<_>, Diagnostics>>()?
             .into_iter()
             .flatten()
+            .collect::<Vec<_>>();
+        let response_names = schema_variant_names(&response_schemas, true);
+        let response_schemas = response_schemas
+            .into_iter()
             .fold(TokenStream2::new(), to_schema_references);
 
         let schemas = self
@@ -527,8 +572,31 @@ impl<'p> ToTokensDiagnostics for Path<'p> {
             .map_try(|request_body| request_body.get_component_schemas())?
             .into_iter()
             .flatten()
+            .collect::<Vec<_>>();
+        let request_names = schema_variant_names(&schemas, false);
+        let schemas = schemas
+            .into_iter()
             .fold(TokenStream2::new(), to_schema_references);
 
+        let operation = as_tokens_or_diagnostics!(&operation);
+        let operation = if request_names.is_empty() && response_names.is_empty() {
+            quote! { #operation.into() }
+        } else {
+            quote! {
+                utoipa::__dev::operation_schema_variants(#operation.into(), &#request_names, &#response_names)
+            }
+        };
+
+        let callback_schemas = self
+            .path_attr
+            .callbacks
+            .iter()
+            .flat_map(Callback::path_structs)
+            .map(|path_struct| {
+                quote! { <#path_struct as utoipa::__dev::SchemaReferences>::schemas(schemas); }
+            })
+            .collect::<TokenStream2>();
+
         let mut tags = self.path_attr.tags.clone();
         if let Some(tag) = self.path_attr.tag.as_ref() {
             // if defined tag is the first before the additional tags
@@ -552,7 +620,10 @@ impl<'p> ToTokensDiagnostics for Path<'p> {
             {
                 // Add supporting passthrough implementations only if actix-web service config
                 // is implemented and no impl_for has been defined
-                if self.path_attr.impl_for.is_none() && !self.ext_methods.is_empty() {
+                if self.path_attr.impl_for.is_none()
+                    && !self.ext_methods.is_empty()
+                    && path_with_context_path.is_some()
+                {
                     let fn_ident = self.fn_ident;
                     tokens.extend(quote! {
                         impl ::actix_web::dev::HttpServiceFactory for #path_struct {
@@ -591,41 +662,115 @@ impl<'p> ToTokensDiagnostics for Path<'p> {
             path_struct
         };
 
-        tokens.extend(quote_diagnostics! {
-            impl<'t> utoipa::__dev::Tags<'t> for #impl_for {
-                fn tags() -> Vec<&'t str> {
-                    #tags_list.into()
+        let path_impl = if let Some(path_with_context_path) = path_with_context_path {
+            quote! {
+                impl utoipa::Path for #impl_for {
+                    fn path() -> String {
+                        #path_with_context_path
+                    }
+
+                    fn methods() -> Vec<utoipa::openapi::path::HttpMethod> {
+                        #method_operations.into()
+                    }
+
+                    fn operation() -> utoipa::openapi::path::Operation {
+                        use utoipa::openapi::ToArray;
+                        use std::iter::FromIterator;
+                        #operation
+                    }
                 }
-            }
-            impl utoipa::Path for #impl_for {
-                fn path() -> String {
-                    #path_with_context_path
+
+                impl utoipa::__dev::MethodNames for #impl_for {
+                    const METHOD_NAMES: &'static [&'static str] = &#method_names;
                 }
+            }
+        } else {
+            let webhook = &self.path_attr.webhook;
+            quote! {
+                impl utoipa::__dev::Webhook for #impl_for {
+                    fn name() -> String {
+                        String::from(#webhook)
+                    }
+
+                    fn methods() -> Vec<utoipa::openapi::path::HttpMethod> {
+                        #method_operations.into()
+                    }
 
-                fn methods() -> Vec<utoipa::openapi::path::HttpMethod> {
-                    #method_operations.into()
+                    fn operation() -> utoipa::openapi::path::Operation {
+                        use utoipa::openapi::ToArray;
+                        use std::iter::FromIterator;
+                        #operation
+                    }
                 }
+            }
+        };
 
-                fn operation() -> utoipa::openapi::path::Operation {
-                    use utoipa::openapi::ToArray;
-                    use std::iter::FromIterator;
-                    @operation.into()
+        tokens.extend(quote! {
+            impl<'t> utoipa::__dev::Tags<'t> for #impl_for {
+                fn tags() -> Vec<&'t str> {
+                    #tags_list.into()
                 }
             }
+            #path_impl
 
             impl utoipa::__dev::SchemaReferences for #impl_for {
                 fn schemas(schemas: &mut Vec<(String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>)>) {
                     #schemas
                     #response_schemas
+                    #callback_schemas
                 }
             }
 
-        }?);
+        });
 
         Ok(())
     }
 }
 
+/// Collect `(name, variant_name)` pairs of request or response schema variants of the schema
+/// references of the [`ComponentSchema`]s.
+fn schema_variant_names(
+    component_schemas: &[(bool, ComponentSchema)],
+    response: bool,
+) -> Array<'static, TokenStream2> {
+    component_schemas
+        .iter()
+        .flat_map(|(_, component_schema)| &component_schema.schema_references)
+        .filter_map(|schema_reference| schema_reference.variant_names(response))
+        .collect()
+}
+
+/// Fold schema references of the [`ComponentSchema`] to tokens pushing collected schemas to
+/// `schemas: &mut Vec<(String, RefOr<Schema>)>` variable in scope.
+pub fn to_schema_references(
+    mut schemas: TokenStream2,
+    (is_inline, component_schema): (bool, ComponentSchema),
+) -> TokenStream2 {
+    for reference in component_schema.schema_references {
+        let name = &reference.name;
+        let tokens = &reference.tokens;
+        let references = &reference.references;
+
+        #[cfg(feature = "config")]
+        let should_collect_schema = (matches!(
+            crate::CONFIG.schema_collect,
+            utoipa_config::SchemaCollect::NonInlined
+        ) && !is_inline)
+            || matches!(
+                crate::CONFIG.schema_collect,
+                utoipa_config::SchemaCollect::All
+            );
+        #[cfg(not(feature = "config"))]
+        let should_collect_schema = !is_inline;
+        if should_collect_schema {
+            schemas.extend(quote!( schemas.push((#name, #tokens)); ));
+        }
+        schemas.extend(quote!( #references; ));
+    }
+
+    schemas
+}
+
 #[cfg_attr(feature = "debug", derive(Debug))]
 struct Operation<'a> {
     operation_id: Expr,
@@ -638,6 +783,7 @@ struct Operation<'a> {
     security: Option<&'a Array<'a, SecurityRequirementsAttr>>,
     extensions: Option<&'a Extensions>,
     servers: &'a Vec<Server>,
+    callbacks: &'a Vec<Callback>,
 }
 
 impl ToTokensDiagnostics for Operation<'_> {
@@ -688,6 +834,10 @@ impl ToTokensDiagnostics for Operation<'_> {
             parameter.to_tokens(tokens)?;
         }
 
+        for callback in self.callbacks {
+            callback.to_tokens(tokens);
+        }
+
         if let Some(extensions) = self.extensions {
             tokens.extend(quote! { .extensions(Some(#extensions)) })
         }
@@ -790,7 +940,12 @@ impl<'p> PathTypeTree for TypeTree<'p> {
     /// Check whether [`TypeTree`] is a Vec, slice, array or other supported array type
     fn is_array(&self) -> bool {
         match self.generic_type {
-            Some(GenericType::Vec | GenericType::Set) => true,
+            Some(
+                GenericType::Vec
+                | GenericType::VecDeque
+                | GenericType::BinaryHeap
+                | GenericType::Set,
+            ) => true,
             Some(_) => self
                 .children
                 .as_ref()
@@ -0,0 +1,68 @@
+use std::borrow::Cow;
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::parse::Parse;
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::{ExprPath, Token};
+
+use crate::parse_utils;
+
+use super::format_path_ident;
+
+/// ("name" = handler) or ("name" = (handler, module::other_handler))
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Callback {
+    name: parse_utils::LitStrOrExpr,
+    handlers: Punctuated<ExprPath, Comma>,
+}
+
+impl Callback {
+    /// Get [`ExprPath`]s of the generated path structs of the callback handlers.
+    pub fn path_structs(&self) -> impl Iterator<Item = ExprPath> + '_ {
+        self.handlers.iter().map(|handler| {
+            let mut path_struct = handler.clone();
+            if let Some(last) = path_struct.path.segments.last_mut() {
+                last.ident = format_path_ident(Cow::Borrowed(&last.ident)).into_owned();
+            }
+
+            path_struct
+        })
+    }
+}
+
+impl Parse for Callback {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let inner;
+        syn::parenthesized!(inner in input);
+
+        let name = inner.parse::<parse_utils::LitStrOrExpr>()?;
+        inner.parse::<Token![=]>()?;
+
+        let handlers = if inner.peek(syn::token::Paren) {
+            let handlers;
+            syn::parenthesized!(handlers in inner);
+            Punctuated::<ExprPath, Comma>::parse_terminated(&handlers)?
+        } else {
+            let mut handlers = Punctuated::new();
+            handlers.push(inner.parse::<ExprPath>()?);
+            handlers
+        };
+
+        Ok(Self { name, handlers })
+    }
+}
+
+impl ToTokens for Callback {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = &self.name;
+        let paths = self
+            .path_structs()
+            .map(|path_struct| quote! { .path_from::<#path_struct>() });
+
+        tokens.extend(quote! {
+            .callback(#name, utoipa::openapi::path::CallbackBuilder::new() #( #paths )* )
+        })
+    }
+}
@@ -10,7 +10,7 @@ use crate::{parse_utils, AnyValue};
 #[derive(Default)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct Example {
-    pub(super) name: String,
+    pub(crate) name: String,
     pub(super) summary: Option<String>,
     pub(super) description: Option<String>,
     pub(super) value: Option<AnyValue>,
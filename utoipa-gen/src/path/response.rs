@@ -29,7 +29,7 @@ use super::{
 };
 
 pub mod derive;
-mod header;
+pub mod header;
 pub mod link;
 
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -108,6 +108,56 @@ fn derive_openapi_tags_with_const_name() {
     }
 }
 
+#[test]
+fn derive_openapi_32_with_hierarchical_tags() {
+    #[derive(OpenApi)]
+    #[openapi(
+        openapi_version = "3.2",
+        tags(
+            (name = "animals", kind = "nav"),
+            (name = "pets", parent = "animals", kind = "nav"),
+        )
+    )]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_value! {doc=>
+        "openapi" = r###""3.2.0""###, "OpenAPI version"
+        "tags.[0].name" = r###""animals""###, "Tags animals name"
+        "tags.[0].parent" = r###"null"###, "Tags animals parent"
+        "tags.[0].kind" = r###""nav""###, "Tags animals kind"
+        "tags.[1].name" = r###""pets""###, "Tags pets name"
+        "tags.[1].parent" = r###""animals""###, "Tags pets parent"
+        "tags.[1].kind" = r###""nav""###, "Tags pets kind"
+    }
+}
+
+#[test]
+fn derive_openapi_with_include() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Pet {
+        name: String,
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(
+        include = "tests/testdata/openapi-derive-include.json",
+        components(schemas(Pet))
+    )]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_value! {doc=>
+        "paths.~1legacy~1status.get.operationId" = r###""legacy_status""###, "Included operation id"
+        "components.schemas.LegacyStatus.type" = r###""object""###, "Included schema type"
+        "components.schemas.Pet.type" = r###""object""###, "Derived schema takes precedence"
+        "info.title" = r###""utoipa-gen""###, "Derived info title"
+    }
+}
+
 #[test]
 fn derive_openapi_with_external_docs() {
     #[derive(OpenApi)]
@@ -391,6 +441,205 @@ fn derive_nest_openapi_with_tags() {
     assert_json_snapshot!(paths);
 }
 
+#[test]
+fn derive_openapi_with_request_response_schema_variants() {
+    #[derive(ToSchema, serde::Deserialize)]
+    #[schema(request_response)]
+    #[allow(dead_code)]
+    struct Owner {
+        #[schema(read_only)]
+        id: i64,
+        name: String,
+    }
+
+    #[derive(ToSchema, serde::Deserialize)]
+    #[schema(request_response(request_suffix = "Input", response_suffix = "Output"))]
+    #[allow(dead_code)]
+    struct Pet {
+        #[schema(read_only)]
+        id: i64,
+        name: String,
+        #[serde(skip_serializing)]
+        password: String,
+        owner: Owner,
+    }
+
+    #[utoipa::path(
+        post,
+        path = "/pets",
+        request_body = Pet,
+        responses(
+            (status = 200, description = "Created pet", body = Pet)
+        )
+    )]
+    #[allow(dead_code)]
+    fn create_pet() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(create_pet))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_value! {doc=>
+        "paths.~1pets.post.requestBody.content.application~1json.schema.$ref" = r###""#/components/schemas/PetInput""###, "Request body references request variant"
+        "paths.~1pets.post.responses.200.content.application~1json.schema.$ref" = r###""#/components/schemas/PetOutput""###, "Response references response variant"
+    }
+    assert_json_snapshot!(doc.pointer("/components/schemas"));
+}
+
+#[test]
+fn derive_openapi_with_request_response_split_rename() {
+    #[derive(ToSchema, serde::Serialize, serde::Deserialize)]
+    #[schema(request_response)]
+    #[allow(dead_code)]
+    struct User {
+        #[serde(rename(serialize = "userId", deserialize = "id"))]
+        user_id: i64,
+        name: String,
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(components(schemas(User)))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_value! {doc=>
+        "components.schemas.User.required" = r###"["name"]"###, "Schema does not require split names"
+        "components.schemas.UserRequest.required" = r###"["name","id"]"###, "Request requires deserialize name"
+        "components.schemas.UserResponse.required" = r###"["name","userId"]"###, "Response requires serialize name"
+    }
+    assert_json_snapshot!(doc.pointer("/components/schemas"));
+}
+
+#[test]
+fn derive_openapi_with_non_zero_integers() {
+    use std::num::{NonZeroI8, NonZeroU32};
+
+    #[derive(ToSchema)]
+    #[allow(dead_code)]
+    struct Limits {
+        max: NonZeroU32,
+        step: NonZeroI8,
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(components(schemas(Limits)))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_json_snapshot!(doc.pointer("/components/schemas"));
+}
+
+#[test]
+fn derive_openapi_with_webhooks() {
+    #[derive(ToSchema)]
+    #[allow(dead_code)]
+    struct Pet {
+        name: String,
+    }
+
+    #[utoipa::path(
+        post,
+        webhook = "newPet",
+        request_body = Pet,
+        responses(
+            (status = 200, description = "Return 200 status if the data was received successfully")
+        )
+    )]
+    #[allow(dead_code)]
+    fn new_pet() {}
+
+    mod pets {
+        #[utoipa::path(
+            delete,
+            webhook = "petRemoved",
+            operation_id = "remove_pet",
+            responses(
+                (status = 200, description = "Pet removal received")
+            )
+        )]
+        #[allow(dead_code)]
+        fn pet_removed() {}
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(webhooks(new_pet, pets::pet_removed))]
+    struct ApiDoc;
+
+    let api = serde_json::to_value(ApiDoc::openapi()).expect("should serialize to value");
+
+    assert_json_snapshot!(api.pointer("/webhooks"));
+    assert_json_snapshot!(api.pointer("/components/schemas"));
+}
+
+#[test]
+fn derive_openapi_with_reusable_components() {
+    #![allow(dead_code)]
+
+    #[derive(ToSchema)]
+    struct Pet {
+        name: String,
+    }
+
+    #[derive(utoipa::IntoParams)]
+    #[into_params(parameter_in = Query)]
+    struct Pagination {
+        page: u64,
+        per_page: u64,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/pets",
+        params(Pagination),
+        responses(
+            (status = 200, description = "List pets", body = [Pet],
+                headers(("X-Request-Id" = String, description = "Request id")),
+                examples(("Pets" = (summary = "List of pets", value = json!([{"name": "Tom"}]))))
+            )
+        )
+    )]
+    fn list_pets() {}
+
+    #[utoipa::path(
+        post,
+        path = "/pets",
+        request_body = Pet,
+        responses(
+            (status = 201, description = "Pet created",
+                headers(
+                    ("X-Request-Id" = String, description = "Request id"),
+                    ("X-Trace-Id" = String, description = "Request id")
+                )
+            )
+        )
+    )]
+    fn create_pet() {}
+
+    #[derive(OpenApi)]
+    #[openapi(
+        paths(list_pets, create_pet),
+        components(
+            parameters(Pagination),
+            request_bodies(("NewPet" = Pet)),
+            headers(
+                ("X-Correlation-Id" = String, description = "Request id"),
+                ("X-Request-Id" = String, description = "Request id")
+            ),
+            examples(("Pets" = (summary = "List of pets", value = json!([{"name": "Tom"}]))))
+        )
+    )]
+    struct ApiDoc;
+
+    let api = serde_json::to_value(ApiDoc::openapi()).expect("should serialize to value");
+
+    assert_json_snapshot!(api.pointer("/paths"));
+    assert_json_snapshot!(api.pointer("/components"));
+}
+
 #[test]
 fn openapi_schemas_resolve_generic_enum_schema() {
     #![allow(dead_code)]
@@ -529,6 +778,69 @@ fn openapi_resolvle_recursive_references() {
     assert_json_snapshot!(schemas);
 }
 
+#[test]
+fn derive_openapi_dereference_recursive_schema() {
+    #![allow(dead_code)]
+
+    #[derive(ToSchema)]
+    struct Owner {
+        name: String,
+    }
+
+    #[derive(ToSchema)]
+    struct Pet {
+        owner: Owner,
+        #[schema(no_recursion)]
+        offspring: Vec<Pet>,
+    }
+
+    #[utoipa::path(get, path = "/pet", responses((status = 200, body = Pet)))]
+    #[allow(unused)]
+    fn get_pet() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(get_pet))]
+    struct ApiDoc;
+
+    let doc = ApiDoc::openapi()
+        .dereference()
+        .expect("OpenApi should dereference");
+
+    let value = serde_json::to_value(doc).expect("OpenAPI is serde serializable");
+    let schema = value
+        .pointer("/paths/~1pet/get/responses/200/content/application~1json/schema")
+        .expect("OpenAPI must have response schema");
+
+    assert_json_snapshot!(schema, @r##"
+    {
+      "properties": {
+        "offspring": {
+          "items": {
+            "$ref": "#/components/schemas/Pet"
+          },
+          "type": "array"
+        },
+        "owner": {
+          "properties": {
+            "name": {
+              "type": "string"
+            }
+          },
+          "required": [
+            "name"
+          ],
+          "type": "object"
+        }
+      },
+      "required": [
+        "owner",
+        "offspring"
+      ],
+      "type": "object"
+    }
+    "##);
+}
+
 #[test]
 fn derive_generic_openapi_component_schemas() {
     #[derive(Serialize, ToSchema)]
@@ -847,6 +847,27 @@ fn derive_path_params_into_params_with_value_type() {
     assert_json_snapshot!(parameters)
 }
 
+#[test]
+#[cfg(feature = "validator")]
+fn derive_path_params_into_params_with_validator_attributes() {
+    use validator::Validate;
+
+    #[derive(IntoParams, Validate)]
+    #[into_params(parameter_in = Query)]
+    #[allow(unused)]
+    struct Filter {
+        #[validate(length(min = 3))]
+        name: String,
+        #[validate(range(min = 1, max = 100))]
+        #[param(maximum = 50)]
+        limit: Option<i32>,
+    }
+
+    let parameters = serde_json::to_value(Filter::into_params(|| None)).unwrap();
+
+    assert_json_snapshot!(parameters)
+}
+
 #[test]
 fn derive_path_params_into_params_with_raw_identifier() {
     #[derive(IntoParams)]
@@ -1562,6 +1583,52 @@ fn derive_path_with_multiple_methods() {
     assert_json_snapshot!(&paths);
 }
 
+#[test]
+fn derive_path_with_query_and_custom_methods() {
+    #[utoipa::path(
+        query,
+        path = "/pets",
+        responses(
+            (status = 200, description = "pets matching the query")
+        ),
+    )]
+    #[allow(unused)]
+    async fn search_pets() {}
+
+    #[utoipa::path(
+        method(get, "COPY"),
+        path = "/pets/{id}",
+        params(("id" = i32, Path, description = "pet id")),
+        responses(
+            (status = 200, description = "success response")
+        ),
+    )]
+    #[allow(unused)]
+    async fn get_or_copy_pet() {}
+
+    use utoipa::OpenApi;
+    #[derive(OpenApi, Default)]
+    #[openapi(openapi_version = "3.2", paths(search_pets, get_or_copy_pet))]
+    struct ApiDoc;
+
+    let doc = &serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let paths = doc.pointer("/paths").expect("OpenApi must have paths");
+
+    assert_eq!(doc.pointer("/openapi"), Some(&serde_json::json!("3.2.0")));
+    assert_eq!(
+        paths.pointer("/~1pets/query/operationId"),
+        Some(&serde_json::json!("search_pets"))
+    );
+    assert_eq!(
+        paths.pointer("/~1pets~1{id}/get/operationId"),
+        Some(&serde_json::json!("get_or_copy_pet"))
+    );
+    assert_eq!(
+        paths.pointer("/~1pets~1{id}/additionalOperations/COPY/operationId"),
+        Some(&serde_json::json!("get_or_copy_pet"))
+    );
+}
+
 #[test]
 fn derive_path_with_response_links() {
     #![allow(dead_code)]
@@ -1600,6 +1667,67 @@ fn derive_path_with_response_links() {
     assert_json_snapshot!(&paths);
 }
 
+#[test]
+fn derive_path_with_callbacks() {
+    #![allow(dead_code)]
+
+    mod callbacks {
+        use utoipa::ToSchema;
+
+        #[derive(ToSchema)]
+        pub struct Event {
+            id: i32,
+        }
+
+        #[utoipa::path(
+            post,
+            path = "{$request.body#/callbackUrl}",
+            request_body = Event,
+            responses(
+                (status = 200, description = "Event received")
+            )
+        )]
+        #[allow(unused)]
+        pub async fn on_event(_body: Event) {}
+    }
+
+    #[utoipa::path(
+        put,
+        path = "{$request.body#/callbackUrl}",
+        responses(
+            (status = 204, description = "Cancellation received")
+        )
+    )]
+    #[allow(unused)]
+    async fn on_cancel() {}
+
+    #[utoipa::path(
+        post,
+        path = "/subscribe",
+        responses(
+            (status = 201, description = "Subscription created")
+        ),
+        callbacks(
+            ("onEvent" = callbacks::on_event),
+            ("onCancel" = (on_cancel))
+        )
+    )]
+    #[allow(unused)]
+    async fn subscribe() {}
+
+    use utoipa::OpenApi;
+    #[derive(OpenApi)]
+    #[openapi(paths(subscribe))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_json_snapshot!(&doc.pointer("/paths").expect("OpenApi must have paths"));
+    assert_json_snapshot!(&doc
+        .pointer("/components/schemas")
+        .expect("OpenApi must have schemas"));
+}
+
 #[test]
 fn derive_path_test_collect_request_body() {
     #![allow(dead_code)]
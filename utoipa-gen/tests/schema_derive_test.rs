@@ -1,7 +1,7 @@
 use std::{borrow::Cow, cell::RefCell, collections::HashMap, marker::PhantomData};
 
 use insta::assert_json_snapshot;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use utoipa::openapi::{Object, ObjectBuilder};
 use utoipa::{OpenApi, ToSchema};
@@ -1549,6 +1549,71 @@ fn derive_struct_with_nullable_and_required() {
     assert_json_snapshot!(user);
 }
 
+#[test]
+fn derive_struct_with_serde_as_fields() {
+    use serde_with::{
+        base64::Base64, serde_as, BoolFromInt, DisplayFromStr, DurationSeconds, Map,
+        TimestampSecondsWithFrac,
+    };
+    use std::time::{Duration, SystemTime};
+
+    #[derive(Serialize, ToSchema)]
+    #[allow(unused)]
+    struct Item {
+        id: u32,
+    }
+
+    #[serde_as]
+    #[derive(Serialize, ToSchema)]
+    #[allow(unused)]
+    struct Record {
+        #[serde_as(as = "Base64")]
+        data: Vec<u8>,
+        #[serde_as(as = "Option<Base64>")]
+        thumbnail: Option<Vec<u8>>,
+        #[serde_as(as = "DurationSeconds")]
+        timeout: Duration,
+        #[serde_as(as = "DurationSeconds<f64>")]
+        interval: Duration,
+        #[serde_as(as = "TimestampSecondsWithFrac<String>")]
+        created: SystemTime,
+        #[serde_as(as = "DisplayFromStr")]
+        big_number: u64,
+        #[serde_as(as = "Vec<DisplayFromStr>")]
+        numbers: Vec<u64>,
+        #[serde_as(as = "BoolFromInt")]
+        active: bool,
+        #[serde_as(as = "Vec<(_, _)>")]
+        items: HashMap<u32, Item>,
+        #[serde_as(as = "Map<DisplayFromStr, _>")]
+        pairs: Vec<(u32, String)>,
+        #[serde_as(as = "DisplayFromStr")]
+        #[schema(value_type = i64)]
+        overridden: u64,
+    }
+
+    let value = serde_json::to_value(<Record as utoipa::PartialSchema>::schema()).unwrap();
+
+    assert_json_snapshot!(value);
+}
+
+#[test]
+fn derive_unnamed_struct_with_serde_as_field() {
+    use serde_with::{serde_as, DisplayFromStr};
+
+    #[serde_as]
+    #[derive(Serialize, ToSchema)]
+    #[allow(unused)]
+    struct Id(#[serde_as(as = "DisplayFromStr")] u64);
+
+    let value = serde_json::to_value(<Id as utoipa::PartialSchema>::schema()).unwrap();
+
+    assert_value! {value=>
+        "type" = r#""string""#, "Id type"
+        "format" = r#"null"#, "Id format"
+    }
+}
+
 #[test]
 fn derive_enum_with_inline_variant() {
     #[allow(dead_code)]
@@ -2196,6 +2261,97 @@ fn derive_parse_serde_mixed_enum() {
     }
 }
 
+#[test]
+fn derive_parse_serde_split_rename_and_alias() {
+    let value = api_doc! {
+        #[derive(Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct User {
+            #[serde(rename(serialize = "userId", deserialize = "id"))]
+            user_id: i64,
+            #[serde(alias = "fullName", alias = "displayName")]
+            name: String,
+            #[serde(rename(serialize = "createdAt"))]
+            created: Option<String>,
+        }
+    };
+    let plain_enum = api_doc! {
+        #[derive(Serialize, Deserialize)]
+        #[serde(rename_all = "lowercase")]
+        enum Role {
+            #[serde(rename(serialize = "administrator", deserialize = "admin"))]
+            Admin,
+            #[serde(alias = "guest")]
+            User,
+        }
+    };
+
+    assert_json_snapshot!(value);
+    assert_json_snapshot!(plain_enum);
+}
+
+#[test]
+fn derive_parse_serde_transparent() {
+    #[derive(Serialize, ToSchema)]
+    #[allow(unused)]
+    struct Inner {
+        value: String,
+    }
+
+    let named = api_doc! {
+        /// User identifier
+        #[derive(Serialize, Default)]
+        #[serde(transparent)]
+        #[schema(default)]
+        struct UserId {
+            #[schema(minimum = 1)]
+            id: i64,
+            #[serde(skip)]
+            _marker: PhantomData<String>,
+        }
+    };
+    let unnamed = api_doc! {
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct Wrapper(Inner);
+    };
+
+    assert_json_snapshot!(named);
+    assert_json_snapshot!(unnamed);
+}
+
+#[test]
+fn derive_parse_serde_other_simple_enum() {
+    let value = api_doc! {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "lowercase")]
+        enum Status {
+            Active,
+            Inactive,
+            #[serde(other)]
+            Unknown,
+        }
+    };
+
+    assert_json_snapshot!(value);
+}
+
+#[test]
+fn derive_parse_serde_other_mixed_enum_tagged() {
+    let value = api_doc! {
+        #[derive(Deserialize)]
+        #[serde(tag = "kind", rename_all = "snake_case")]
+        enum Event {
+            Created { id: i64 },
+            Deleted { id: i64 },
+            #[serde(other)]
+            Unknown,
+        }
+    };
+
+    assert_json_snapshot!(value);
+}
+
 #[test]
 fn derive_component_with_generic_types_having_path_expression() {
     let ty = api_doc! {
@@ -2310,6 +2466,43 @@ fn derive_component_with_linked_list() {
     assert_json_snapshot!(example_schema);
 }
 
+#[test]
+fn derive_component_with_std_containers() {
+    use std::cell::Cell;
+    use std::collections::{BinaryHeap, VecDeque};
+    use std::num::Wrapping;
+    use std::sync::{Mutex, RwLock};
+
+    let example_schema = api_doc! {
+        struct ExampleSchema {
+            queue: VecDeque<String>,
+            heap: BinaryHeap<i32>,
+            cell: Cell<bool>,
+            mutex: Mutex<Vec<String>>,
+            lock: RwLock<Option<String>>,
+            wrapping: Wrapping<u32>,
+            marker: PhantomData<String>,
+        }
+    };
+
+    assert_json_snapshot!(example_schema);
+}
+
+#[test]
+fn derive_schema_with_fixed_size_arrays() {
+    let value = api_doc! {
+        struct Fixed {
+            bytes: [u8; 4],
+            matrix: [[f32; 2]; 3],
+            #[schema(min_items = 1)]
+            names: [String; 8],
+            slice: &'static [u8],
+        }
+    };
+
+    assert_json_snapshot!(value);
+}
+
 #[test]
 #[cfg(feature = "smallvec")]
 fn derive_component_with_smallvec_feature() {
@@ -2601,6 +2794,69 @@ fn derive_struct_with_validation_fields() {
     }
 }
 
+#[test]
+#[cfg(feature = "validator")]
+fn derive_struct_with_validator_attributes() {
+    use validator::Validate;
+
+    let value = api_doc! {
+        #[derive(Validate)]
+        struct User {
+            #[validate(length(min = 1, max = 64), email)]
+            email: String,
+
+            #[validate(range(min = 18, max = 150))]
+            age: Option<u16>,
+
+            #[validate(url)]
+            #[schema(format = "iri")]
+            homepage: String,
+
+            #[validate(length(max = 5))]
+            #[schema(max_items = 3)]
+            tags: Vec<String>,
+
+            #[validate(range(exclusive_min = 0.0, exclusive_max = 1.0))]
+            ratio: f64,
+        }
+    };
+
+    assert_json_snapshot!(value);
+}
+
+#[test]
+#[cfg(feature = "garde")]
+fn derive_struct_with_garde_attributes() {
+    use garde::Validate;
+
+    let value = api_doc! {
+        #[derive(Validate)]
+        struct User {
+            #[garde(length(min = 1, max = 64, chars), email)]
+            email: String,
+
+            #[garde(length(min = 3, max = 32))]
+            username: String,
+
+            #[garde(range(min = 18, max = 150))]
+            age: Option<u16>,
+
+            #[garde(ipv4)]
+            #[schema(format = "ip")]
+            address: String,
+
+            #[garde(length(max = 5), inner(length(min = 1)))]
+            #[schema(max_items = 3)]
+            tags: Vec<String>,
+
+            #[garde(pattern(r"^[a-z]+$"))]
+            slug: String,
+        }
+    };
+
+    assert_json_snapshot!(value);
+}
+
 #[test]
 #[cfg(feature = "non_strict_integers")]
 fn uint_non_strict_integers_format() {
@@ -3651,3 +3907,277 @@ fn derive_inline_option_ref_with_nullable_false_and_default() {
 
     assert_json_snapshot!(schema);
 }
+
+#[test]
+fn derive_struct_with_const_field() {
+    let value = api_doc! {
+        struct CardPayment {
+            #[schema(const = "card")]
+            kind: String,
+            #[schema(const = json!(1))]
+            version: u8,
+            card_number: String,
+        }
+    };
+
+    assert_json_snapshot!(value, @r#"
+    {
+      "properties": {
+        "card_number": {
+          "type": "string"
+        },
+        "kind": {
+          "const": "card",
+          "type": "string"
+        },
+        "version": {
+          "const": 1,
+          "format": "int32",
+          "minimum": 0,
+          "type": "integer"
+        }
+      },
+      "required": [
+        "kind",
+        "version",
+        "card_number"
+      ],
+      "type": "object"
+    }
+    "#);
+}
+
+#[test]
+fn derive_struct_with_mutually_exclusive_fields() {
+    let value = api_doc! {
+        #[serde(rename_all = "camelCase")]
+        struct Payment {
+            #[schema(not(iban, r#ref))]
+            card_number: Option<String>,
+            #[schema(not(card_number))]
+            iban: Option<String>,
+            #[serde(rename = "reference")]
+            r#ref: Option<String>,
+        }
+    };
+
+    assert_json_snapshot!(value, @r#"
+    {
+      "dependentSchemas": {
+        "cardNumber": {
+          "not": {
+            "anyOf": [
+              {
+                "required": [
+                  "iban"
+                ],
+                "type": "object"
+              },
+              {
+                "required": [
+                  "reference"
+                ],
+                "type": "object"
+              }
+            ]
+          }
+        },
+        "iban": {
+          "not": {
+            "required": [
+              "cardNumber"
+            ],
+            "type": "object"
+          }
+        }
+      },
+      "properties": {
+        "cardNumber": {
+          "type": [
+            "string",
+            "null"
+          ]
+        },
+        "iban": {
+          "type": [
+            "string",
+            "null"
+          ]
+        },
+        "reference": {
+          "type": [
+            "string",
+            "null"
+          ]
+        }
+      },
+      "type": "object"
+    }
+    "#);
+}
+
+#[test]
+fn derive_map_with_pattern_properties() {
+    let value = api_doc! {
+        struct Metrics {
+            #[schema(pattern_properties = "^[a-z_]+$")]
+            values: HashMap<String, f64>,
+        }
+    };
+
+    assert_json_snapshot!(value, @r#"
+    {
+      "properties": {
+        "values": {
+          "additionalProperties": false,
+          "patternProperties": {
+            "^[a-z_]+$": {
+              "format": "double",
+              "type": "number"
+            }
+          },
+          "propertyNames": {
+            "type": "string"
+          },
+          "type": "object"
+        }
+      },
+      "required": [
+        "values"
+      ],
+      "type": "object"
+    }
+    "#);
+}
+
+#[test]
+fn derive_flattened_struct_with_unevaluated_properties() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Audit {
+        created_by: String,
+    }
+
+    let value = api_doc! {
+        #[schema(unevaluated_properties = false)]
+        struct Document {
+            id: i32,
+            #[serde(flatten)]
+            audit: Audit,
+        }
+    };
+
+    assert_json_snapshot!(value, @r##"
+    {
+      "allOf": [
+        {
+          "$ref": "#/components/schemas/Audit"
+        },
+        {
+          "properties": {
+            "id": {
+              "format": "int32",
+              "type": "integer"
+            }
+          },
+          "required": [
+            "id"
+          ],
+          "type": "object"
+        }
+      ],
+      "unevaluatedProperties": false
+    }
+    "##);
+}
+
+#[test]
+fn derive_vec_with_contains() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    enum Role {
+        Admin,
+        User,
+    }
+
+    let value = api_doc! {
+        struct Group {
+            #[schema(contains = Role, min_contains = 1, max_contains = 3, unevaluated_items = false)]
+            roles: Vec<Role>,
+        }
+    };
+
+    assert_json_snapshot!(value, @r##"
+    {
+      "properties": {
+        "roles": {
+          "contains": {
+            "$ref": "#/components/schemas/Role"
+          },
+          "items": {
+            "$ref": "#/components/schemas/Role"
+          },
+          "maxContains": 3,
+          "minContains": 1,
+          "type": "array",
+          "unevaluatedItems": false
+        }
+      },
+      "required": [
+        "roles"
+      ],
+      "type": "object"
+    }
+    "##);
+}
+
+#[test]
+fn derive_json_schema_of_recursive_struct() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    enum Species {
+        Cat,
+        Dog,
+    }
+
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Pet {
+        species: Species,
+        #[schema(no_recursion)]
+        offspring: Vec<Pet>,
+    }
+
+    let value = serde_json::to_value(utoipa::json_schema::<Pet>()).unwrap();
+
+    assert_json_snapshot!(value, @r##"
+    {
+      "$defs": {
+        "Species": {
+          "enum": [
+            "Cat",
+            "Dog"
+          ],
+          "type": "string"
+        }
+      },
+      "$schema": "https://json-schema.org/draft/2020-12/schema",
+      "properties": {
+        "offspring": {
+          "items": {
+            "$ref": "#"
+          },
+          "type": "array"
+        },
+        "species": {
+          "$ref": "#/$defs/Species"
+        }
+      },
+      "required": [
+        "species",
+        "offspring"
+      ],
+      "type": "object"
+    }
+    "##);
+}
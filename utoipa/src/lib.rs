@@ -61,6 +61,9 @@
 //!
 //! * **`macros`** Enable `utoipa-gen` macros. **This is enabled by default.**
 //! * **`yaml`** Enables **yaml_serde** serialization of OpenAPI objects.
+//! * **`schema_validation`** Enables validation of JSON values against OpenAPI schemas with
+//!   [`SchemaValidator`][schema_validator] and [`OpenApi::validate_examples`][validate_examples] to check
+//!   that the examples of the document match their schemas.
 //! * **`actix_extras`** Enhances [actix-web](https://github.com/actix/actix-web/) integration with being able to
 //!   parse `path`, `path` and `query` parameters from actix web path attribute macros. See [actix extras support][actix_path] or
 //!   [examples](https://github.com/juhaku/utoipa/tree/master/examples) for more details.
@@ -123,6 +126,12 @@
 //!   serialization and deserialization of `Arc<T>` and `Rc<T>` types. See more about [serde feature flags](https://serde.rs/feature-flags.html).
 //! * **`config`** Enables [`utoipa-config`](https://docs.rs/utoipa-config/) for the project which allows
 //!   defining global configuration options for `utoipa`.
+//! * **`validator`** Map [validator](https://crates.io/crates/validator) `#[validate(...)]` attributes
+//!   of `ToSchema` and `IntoParams` fields to schema validation keywords. See
+//!   [`ToSchema` derive][validator_garde] for more details.
+//! * **`garde`** Map [garde](https://crates.io/crates/garde) `#[garde(...)]` attributes of `ToSchema`
+//!   and `IntoParams` fields to schema validation keywords. See [`ToSchema` derive][validator_garde]
+//!   for more details.
 //!
 //! ### Default Library Support
 //!
@@ -236,11 +245,14 @@
 //! [actix_path]: attr.path.html#actix_extras-feature-support-for-actix-web
 //! [axum_path]: attr.path.html#axum_extras-feature-support-for-axum
 //! [serde]: derive.ToSchema.html#partial-serde-attributes-support
+//! [validator_garde]: derive.ToSchema.html#validator-and-garde-attributes-support
 //! [utoipa_swagger]: https://docs.rs/utoipa-swagger-ui/
 //! [utoipa_config]: https://docs.rs/utoipa-config/
 //!
 //! [security]: openapi/security/index.html
 //! [to_schema_derive]: derive.ToSchema.html
+//! [schema_validator]: openapi/schema_validation/struct.SchemaValidator.html
+//! [validate_examples]: openapi/struct.OpenApi.html#method.validate_examples
 
 pub mod openapi;
 
@@ -421,6 +433,24 @@ pub trait ToSchema: PartialSchema {
         Cow::Borrowed(type_name)
     }
 
+    /// Return name of the schema used for this type in request bodies.
+    ///
+    /// By default this is the same as [`ToSchema::name`]. Types deriving [`ToSchema`] with
+    /// `request_response` use the name of the request variant of the schema which does not
+    /// have the read only properties, e.g. _`FooRequest`_.
+    fn request_name() -> Cow<'static, str> {
+        Self::name()
+    }
+
+    /// Return name of the schema used for this type in responses.
+    ///
+    /// By default this is the same as [`ToSchema::name`]. Types deriving [`ToSchema`] with
+    /// `request_response` use the name of the response variant of the schema which does not
+    /// have the write only properties, e.g. _`FooResponse`_.
+    fn response_name() -> Cow<'static, str> {
+        Self::name()
+    }
+
     /// Implement reference [`utoipa::openapi::schema::Schema`]s for this type.
     ///
     /// When [`ToSchema`] is being derived this is implemented automatically but if one needs to
@@ -480,6 +510,40 @@ impl<T: ToSchema> From<T> for openapi::RefOr<openapi::schema::Schema> {
     }
 }
 
+/// Create a standalone [JSON Schema 2020-12][json_schema] document of given [`ToSchema`] type.
+///
+/// All the schemas the type depends on are collected with [`ToSchema::schemas`] to the `$defs` of
+/// the document and every `#/components/schemas/{name}` reference is rewritten to
+/// `#/$defs/{name}`. This allows using the same types outside of the OpenAPI document, e.g. to
+/// validate configuration files or message payloads.
+///
+/// # Examples
+///
+/// _**Create JSON Schema of a `Pet`.**_
+/// ```rust
+/// #[derive(utoipa::ToSchema)]
+/// struct Owner {
+///     name: String,
+/// }
+///
+/// #[derive(utoipa::ToSchema)]
+/// struct Pet {
+///     name: String,
+///     owner: Owner,
+/// }
+///
+/// let json_schema = utoipa::json_schema::<Pet>();
+/// let json = serde_json::to_value(&json_schema).unwrap();
+///
+/// assert_eq!(json["$schema"], "https://json-schema.org/draft/2020-12/schema");
+/// assert_eq!(json["properties"]["owner"]["$ref"], "#/$defs/Owner");
+/// ```
+///
+/// [json_schema]: https://json-schema.org/draft/2020-12/json-schema-core
+pub fn json_schema<T: ToSchema>() -> openapi::schema::JsonSchema {
+    openapi::schema::JsonSchema::from_schema::<T>()
+}
+
 /// Represents _`nullable`_ type. This can be used anywhere where "nothing" needs to be evaluated.
 /// This will serialize to _`null`_ in JSON and [`openapi::schema::empty`] is used to create the
 /// [`openapi::schema::Schema`] for the type.
@@ -795,6 +859,187 @@ where
     }
 }
 
+#[cfg(feature = "macros")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "macros")))]
+impl<T: ToSchema> ToSchema for std::collections::VecDeque<T>
+where
+    std::collections::VecDeque<T>: PartialSchema,
+{
+    fn schemas(
+        schemas: &mut Vec<(
+            String,
+            utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>,
+        )>,
+    ) {
+        T::schemas(schemas);
+    }
+}
+
+#[cfg(feature = "macros")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "macros")))]
+impl<T: ToSchema> ToSchema for std::collections::BinaryHeap<T>
+where
+    std::collections::BinaryHeap<T>: PartialSchema,
+{
+    fn schemas(
+        schemas: &mut Vec<(
+            String,
+            utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>,
+        )>,
+    ) {
+        T::schemas(schemas);
+    }
+}
+
+#[cfg(feature = "macros")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "macros")))]
+impl<T: ToSchema, const N: usize> ToSchema for [T; N]
+where
+    [T; N]: PartialSchema,
+{
+    fn schemas(
+        schemas: &mut Vec<(
+            String,
+            utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>,
+        )>,
+    ) {
+        T::schemas(schemas);
+    }
+}
+
+#[cfg(feature = "macros")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "macros")))]
+impl<T: ToSchema> ToSchema for std::cell::Cell<T>
+where
+    std::cell::Cell<T>: PartialSchema,
+{
+    fn schemas(
+        schemas: &mut Vec<(
+            String,
+            utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>,
+        )>,
+    ) {
+        T::schemas(schemas);
+    }
+}
+
+#[cfg(feature = "macros")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "macros")))]
+impl<T: ToSchema> ToSchema for std::sync::Mutex<T>
+where
+    std::sync::Mutex<T>: PartialSchema,
+{
+    fn schemas(
+        schemas: &mut Vec<(
+            String,
+            utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>,
+        )>,
+    ) {
+        T::schemas(schemas);
+    }
+}
+
+#[cfg(feature = "macros")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "macros")))]
+impl<T: ToSchema> ToSchema for std::sync::RwLock<T>
+where
+    std::sync::RwLock<T>: PartialSchema,
+{
+    fn schemas(
+        schemas: &mut Vec<(
+            String,
+            utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>,
+        )>,
+    ) {
+        T::schemas(schemas);
+    }
+}
+
+#[cfg(feature = "macros")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "macros")))]
+impl<T: ToSchema> ToSchema for std::num::Wrapping<T>
+where
+    std::num::Wrapping<T>: PartialSchema,
+{
+    fn schemas(
+        schemas: &mut Vec<(
+            String,
+            utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>,
+        )>,
+    ) {
+        T::schemas(schemas);
+    }
+}
+
+#[cfg(feature = "macros")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "macros")))]
+impl<T: ?Sized> ToSchema for std::marker::PhantomData<T> where
+    std::marker::PhantomData<T>: PartialSchema
+{
+}
+
+macro_rules! impl_to_schema_tuple {
+    ( $( ( $( $ty:ident ),+ ) ),* ) => {
+        $(
+        #[cfg(feature = "macros")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "macros")))]
+        impl<$( $ty: ToSchema ),+> ToSchema for ( $( $ty, )+ )
+        where
+            ( $( $ty, )+ ): PartialSchema,
+        {
+            /// Name is prefixed with the arity of the tuple and names of the items are separated
+            /// with `-` e.g. `Tuple2-String-i32` to keep names of different tuples distinct.
+            fn name() -> Cow<'static, str> {
+                let names = [$( $ty::name() ),+];
+                Cow::Owned(format!("Tuple{}-{}", names.len(), names.join("-")))
+            }
+
+            fn schemas(
+                schemas: &mut Vec<(
+                    String,
+                    utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>,
+                )>,
+            ) {
+                $( $ty::schemas(schemas); )+
+            }
+        }
+        )*
+    };
+}
+
+#[rustfmt::skip]
+impl_to_schema_tuple!(
+    (T0), (T0, T1), (T0, T1, T2), (T0, T1, T2, T3), (T0, T1, T2, T3, T4),
+    (T0, T1, T2, T3, T4, T5), (T0, T1, T2, T3, T4, T5, T6), (T0, T1, T2, T3, T4, T5, T6, T7),
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8), (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9),
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10), (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11)
+);
+
+/// Name is the name of the type alias e.g. `NonZeroU32` instead of the default name of the
+/// type which would be `NonZero` for all `NonZero*` types.
+macro_rules! impl_to_schema_std {
+    ( $( $module:ident :: $ty:ident ),* ) => {
+        $(
+        #[cfg(feature = "macros")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "macros")))]
+        impl ToSchema for std::$module::$ty {
+            fn name() -> Cow<'static, str> {
+                Cow::Borrowed(stringify!( $ty ))
+            }
+        }
+        )*
+    };
+}
+
+#[rustfmt::skip]
+impl_to_schema_std!(
+    num::NonZeroI8, num::NonZeroI16, num::NonZeroI32, num::NonZeroI64, num::NonZeroI128,
+    num::NonZeroIsize, num::NonZeroU8, num::NonZeroU16, num::NonZeroU32, num::NonZeroU64,
+    num::NonZeroU128, num::NonZeroUsize, time::Duration, net::IpAddr, net::Ipv4Addr,
+    net::Ipv6Addr, net::SocketAddr, net::SocketAddrV4, net::SocketAddrV6, path::PathBuf,
+    path::Path
+);
+
 impl PartialSchema for serde_json::Value {
     fn schema() -> openapi::RefOr<openapi::schema::Schema> {
         utoipa::openapi::schema::Object::builder()
@@ -1040,7 +1285,11 @@ pub trait Path {
 /// }
 /// ```
 ///
+/// Modifiers touching every node of a kind e.g. every schema or every response can use
+/// [`VisitMut`][visit_mut] to traverse the document instead of hand written loops.
+///
 /// [server]: https://spec.openapis.org/oas/latest.html#server-object
+/// [visit_mut]: openapi::visit::VisitMut
 pub trait Modify {
     /// Apply mutation for [`openapi::OpenApi`] instance before it is returned by
     /// [`openapi::OpenApi::openapi`] method call.
@@ -1284,6 +1533,20 @@ pub mod __dev {
         fn tags() -> Vec<&'t str>;
     }
 
+    /// Names of the HTTP methods of path operation as used in the OpenAPI document available in
+    /// const context. Used to validate the methods at compile time by framework bindings.
+    pub trait MethodNames {
+        const METHOD_NAMES: &'static [&'static str];
+    }
+
+    pub trait Webhook {
+        fn name() -> String;
+
+        fn methods() -> Vec<crate::openapi::path::HttpMethod>;
+
+        fn operation() -> crate::openapi::path::Operation;
+    }
+
     impl<T: PathConfig> utoipa::Path for T {
         fn path() -> String {
             <Self as PathConfig>::path()
@@ -1561,6 +1824,203 @@ pub mod __dev {
         }
     }
 
+    impl<T: ComposeSchema> ComposeSchema for std::collections::VecDeque<T> {
+        fn compose(
+            schemas: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            utoipa::openapi::schema::ArrayBuilder::new()
+                .items(schema_or_compose::<T>(schemas, 0))
+                .into()
+        }
+    }
+
+    impl<T: ComposeSchema> ComposeSchema for std::collections::BinaryHeap<T> {
+        fn compose(
+            schemas: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            utoipa::openapi::schema::ArrayBuilder::new()
+                .items(schema_or_compose::<T>(schemas, 0))
+                .into()
+        }
+    }
+
+    impl<T: ComposeSchema, const N: usize> ComposeSchema for [T; N] {
+        fn compose(
+            schemas: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            utoipa::openapi::schema::ArrayBuilder::new()
+                .items(schema_or_compose::<T>(schemas, 0))
+                .min_items(Some(N))
+                .max_items(Some(N))
+                .into()
+        }
+    }
+
+    impl<T: ComposeSchema> ComposeSchema for std::cell::Cell<T> {
+        fn compose(
+            schemas: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            schema_or_compose::<T>(schemas, 0)
+        }
+    }
+
+    impl<T: ComposeSchema> ComposeSchema for std::sync::Mutex<T> {
+        fn compose(
+            schemas: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            schema_or_compose::<T>(schemas, 0)
+        }
+    }
+
+    impl<T: ComposeSchema> ComposeSchema for std::sync::RwLock<T> {
+        fn compose(
+            schemas: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            schema_or_compose::<T>(schemas, 0)
+        }
+    }
+
+    impl<T: ComposeSchema> ComposeSchema for std::num::Wrapping<T> {
+        fn compose(
+            schemas: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            schema_or_compose::<T>(schemas, 0)
+        }
+    }
+
+    impl<T: ?Sized> ComposeSchema for std::marker::PhantomData<T> {
+        fn compose(
+            _: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            utoipa::openapi::ObjectBuilder::new()
+                .schema_type(utoipa::openapi::schema::Type::Null)
+                .into()
+        }
+    }
+
+    /// Tuple items are `prefixItems` which cannot be references so referenced item is wrapped
+    /// to _`allOf`_ schema.
+    fn prefix_item<T: ComposeSchema>(
+        schemas: &[utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>],
+        index: usize,
+    ) -> utoipa::openapi::schema::Schema {
+        match schema_or_compose::<T>(schemas.to_vec(), index) {
+            utoipa::openapi::RefOr::T(schema) => schema,
+            utoipa::openapi::RefOr::Ref(reference) => utoipa::openapi::schema::AllOfBuilder::new()
+                .item(reference)
+                .into(),
+        }
+    }
+
+    macro_rules! impl_compose_schema_tuple {
+        ( $( ( $( $ty:ident => $index:tt ),+ ) ),* ) => {
+            $(
+            impl<$( $ty: ComposeSchema ),+> ComposeSchema for ( $( $ty, )+ ) {
+                fn compose(
+                    schemas: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+                ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+                    utoipa::openapi::schema::ArrayBuilder::new()
+                        .items(utoipa::openapi::schema::ArrayItems::False)
+                        .prefix_items([$( prefix_item::<$ty>(&schemas, $index) ),+])
+                        .into()
+                }
+            }
+            )*
+        };
+    }
+
+    #[rustfmt::skip]
+    impl_compose_schema_tuple!(
+        (T0 => 0),
+        (T0 => 0, T1 => 1),
+        (T0 => 0, T1 => 1, T2 => 2),
+        (T0 => 0, T1 => 1, T2 => 2, T3 => 3),
+        (T0 => 0, T1 => 1, T2 => 2, T3 => 3, T4 => 4),
+        (T0 => 0, T1 => 1, T2 => 2, T3 => 3, T4 => 4, T5 => 5),
+        (T0 => 0, T1 => 1, T2 => 2, T3 => 3, T4 => 4, T5 => 5, T6 => 6),
+        (T0 => 0, T1 => 1, T2 => 2, T3 => 3, T4 => 4, T5 => 5, T6 => 6, T7 => 7),
+        (T0 => 0, T1 => 1, T2 => 2, T3 => 3, T4 => 4, T5 => 5, T6 => 6, T7 => 7, T8 => 8),
+        (T0 => 0, T1 => 1, T2 => 2, T3 => 3, T4 => 4, T5 => 5, T6 => 6, T7 => 7, T8 => 8, T9 => 9),
+        (T0 => 0, T1 => 1, T2 => 2, T3 => 3, T4 => 4, T5 => 5, T6 => 6, T7 => 7, T8 => 8, T9 => 9, T10 => 10),
+        (T0 => 0, T1 => 1, T2 => 2, T3 => 3, T4 => 4, T5 => 5, T6 => 6, T7 => 7, T8 => 8, T9 => 9, T10 => 10, T11 => 11)
+    );
+
+    macro_rules! impl_compose_schema_non_zero {
+        ( $( $ty:ident => $primitive:ident ),* ) => {
+            $(
+            impl ComposeSchema for std::num::$ty {
+                fn compose(_: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+                    schema!( $primitive )
+                        .not_schema(Some(
+                            utoipa::openapi::ObjectBuilder::new()
+                                .schema_type(utoipa::openapi::schema::Type::Integer)
+                                .const_value(Some(0)),
+                        ))
+                        .into()
+                }
+            }
+            )*
+        };
+        ( $( $ty:ident => $primitive:ident minimum $minimum:literal ),* ) => {
+            $(
+            impl ComposeSchema for std::num::$ty {
+                fn compose(_: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+                    schema!( $primitive ).minimum(Some($minimum)).into()
+                }
+            }
+            )*
+        };
+    }
+
+    #[rustfmt::skip]
+    impl_compose_schema_non_zero!(
+        NonZeroI8 => i8, NonZeroI16 => i16, NonZeroI32 => i32, NonZeroI64 => i64,
+        NonZeroI128 => i128, NonZeroIsize => isize
+    );
+
+    #[rustfmt::skip]
+    impl_compose_schema_non_zero!(
+        NonZeroU8 => u8 minimum 1, NonZeroU16 => u16 minimum 1, NonZeroU32 => u32 minimum 1,
+        NonZeroU64 => u64 minimum 1, NonZeroU128 => u128 minimum 1, NonZeroUsize => usize minimum 1
+    );
+
+    impl ComposeSchema for std::time::Duration {
+        fn compose(
+            _: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            utoipa::openapi::ObjectBuilder::new()
+                .property("secs", schema!(u64))
+                .required("secs")
+                .property("nanos", schema!(u32))
+                .required("nanos")
+                .into()
+        }
+    }
+
+    macro_rules! impl_compose_schema_string {
+        ( $( $ty:path $( => $format:ident )? ),* ) => {
+            $(
+            impl ComposeSchema for $ty {
+                fn compose(_: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+                    utoipa::openapi::ObjectBuilder::new()
+                        .schema_type(utoipa::openapi::schema::Type::String)
+                        $( .format(Some(utoipa::openapi::SchemaFormat::KnownFormat(
+                            utoipa::openapi::KnownFormat::$format
+                        ))) )?
+                        .into()
+                }
+            }
+            )*
+        };
+    }
+
+    #[rustfmt::skip]
+    impl_compose_schema_string!(
+        std::net::IpAddr, std::net::Ipv4Addr => Ipv4, std::net::Ipv6Addr => Ipv6,
+        std::net::SocketAddr, std::net::SocketAddrV4, std::net::SocketAddrV6, std::path::PathBuf,
+        std::path::Path
+    );
+
     // For types not implementing `ToSchema`
     pub trait SchemaReferences {
         fn schemas(
@@ -1571,6 +2031,267 @@ pub mod __dev {
         );
     }
 
+    /// Replace inlined parameters, headers and examples of the [`OpenApi`][openapi] with
+    /// references to the reusable components of the same name and value found from the
+    /// [`Components`][components]. Inlined request bodies do not have a name, thus they are
+    /// replaced only when exactly one of the reusable request bodies is equal to them.
+    ///
+    /// [openapi]: crate::openapi::OpenApi
+    /// [components]: crate::openapi::Components
+    pub fn reference_components(openapi: &mut crate::openapi::OpenApi) {
+        use std::collections::BTreeMap;
+
+        use crate::openapi::{
+            example::Example,
+            path::{Operation, Parameter},
+            Components, Content, Ref, RefOr, Response,
+        };
+
+        /// Replace `item` with reference to the component of given `name` if it is equal.
+        fn reference<T: PartialEq>(
+            name: &str,
+            item: &mut RefOr<T>,
+            components: &BTreeMap<String, RefOr<T>>,
+            to_ref: impl Fn(String) -> Ref,
+        ) {
+            if let RefOr::T(value) = item {
+                if matches!(components.get(name), Some(RefOr::T(component)) if component == value) {
+                    *item = RefOr::Ref(to_ref(name.to_string()));
+                }
+            }
+        }
+
+        fn reference_examples<'e, T: IntoIterator<Item = (&'e String, &'e mut RefOr<Example>)>>(
+            examples: T,
+            components: &Components,
+        ) {
+            for (name, example) in examples {
+                reference(name, example, &components.examples, Ref::from_example_name);
+            }
+        }
+
+        fn reference_content<'c>(
+            content: impl IntoIterator<Item = &'c mut Content>,
+            components: &Components,
+        ) {
+            for content in content {
+                reference_examples(content.examples.iter_mut(), components);
+            }
+        }
+
+        fn reference_parameter(parameter: &mut RefOr<Parameter>, components: &Components) {
+            let name = match parameter {
+                RefOr::T(parameter) => parameter.name.clone(),
+                RefOr::Ref(_) => return,
+            };
+            reference(
+                &name,
+                parameter,
+                &components.parameters,
+                Ref::from_parameter_name,
+            );
+        }
+
+        fn reference_response(response: &mut RefOr<Response>, components: &Components) {
+            if let RefOr::T(response) = response {
+                for (name, header) in response.headers.iter_mut() {
+                    reference(name, header, &components.headers, Ref::from_header_name);
+                    if let RefOr::T(header) = header {
+                        reference_examples(header.examples.iter_mut(), components);
+                        reference_content(header.content.values_mut(), components);
+                    }
+                }
+                reference_content(response.content.values_mut(), components);
+            }
+        }
+
+        fn reference_operation(operation: &mut Operation, components: &Components) {
+            for parameter in operation.parameters.iter_mut().flatten() {
+                reference_parameter(parameter, components);
+            }
+            if let Some(request_body) = operation.request_body.as_mut() {
+                if let RefOr::T(value) = request_body {
+                    let mut equal = components
+                        .request_bodies
+                        .iter()
+                        .filter(|(_, component)| matches!(component, RefOr::T(component) if component == value));
+                    if let (Some((name, _)), None) = (equal.next(), equal.next()) {
+                        *request_body = RefOr::Ref(Ref::from_request_body_name(name.clone()));
+                    }
+                }
+                if let RefOr::T(request_body) = request_body {
+                    reference_content(request_body.content.values_mut(), components);
+                }
+            }
+            for response in operation.responses.responses.values_mut() {
+                reference_response(response, components);
+            }
+        }
+
+        let Some(mut components) = openapi.components.take() else {
+            return;
+        };
+
+        let mut responses = std::mem::take(&mut components.responses);
+        for response in responses.values_mut() {
+            reference_response(response, &components);
+        }
+        components.responses = responses;
+
+        let webhooks = openapi
+            .webhooks
+            .values_mut()
+            .filter_map(|webhook| match webhook {
+                RefOr::T(path_item) => Some(path_item),
+                RefOr::Ref(_) => None,
+            });
+        for path_item in openapi.paths.paths.values_mut().chain(webhooks) {
+            for parameter in path_item.parameters.iter_mut().flatten() {
+                reference_parameter(parameter, &components);
+            }
+            for operation in path_item.operations_mut() {
+                reference_operation(operation, &components);
+            }
+        }
+
+        openapi.components = Some(components);
+    }
+
+    /// Variant of the schema of a type deriving `ToSchema` with `request_response`.
+    pub enum SchemaVariant {
+        /// Schema used in request bodies without the read only properties.
+        Request,
+        /// Schema used in responses without the write only properties.
+        Response,
+    }
+
+    /// Points schema references listed in `names` as `(name, variant_name)` pairs to the schema
+    /// variants and removes properties not present in the `variant`.
+    struct SchemaVariants<'n> {
+        variant: Option<SchemaVariant>,
+        names: &'n [(String, String)],
+    }
+
+    impl SchemaVariants<'_> {
+        fn is_excluded(
+            &self,
+            property: &crate::openapi::RefOr<crate::openapi::schema::Schema>,
+        ) -> bool {
+            use crate::openapi::{schema::Schema, RefOr};
+
+            let flag = |read_only: Option<bool>, write_only: Option<bool>| match self.variant {
+                Some(SchemaVariant::Request) => read_only == Some(true),
+                Some(SchemaVariant::Response) => write_only == Some(true),
+                None => false,
+            };
+
+            match property {
+                RefOr::Ref(reference) => flag(reference.read_only, reference.write_only),
+                RefOr::T(Schema::Object(object)) => flag(object.read_only, object.write_only),
+                RefOr::T(Schema::OneOf(one_of)) => flag(one_of.read_only, one_of.write_only),
+                RefOr::T(Schema::AllOf(all_of)) => {
+                    all_of.items.iter().any(|item| self.is_excluded(item))
+                }
+                _ => false,
+            }
+        }
+    }
+
+    impl crate::openapi::visit::VisitMut for SchemaVariants<'_> {
+        fn visit_ref_mut(&mut self, reference: &mut crate::openapi::Ref) {
+            let Some(name) = reference.ref_location.strip_prefix("#/components/schemas/") else {
+                return;
+            };
+            if let Some((_, variant_name)) = self.names.iter().find(|(other, _)| other == name) {
+                reference.ref_location = format!("#/components/schemas/{variant_name}");
+            }
+        }
+
+        fn visit_object_mut(&mut self, object: &mut crate::openapi::schema::Object) {
+            let mut excluded = Vec::new();
+            object.properties.retain(|name, property| {
+                let is_excluded = self.is_excluded(property);
+                if is_excluded {
+                    excluded.push(name.clone());
+                }
+                !is_excluded
+            });
+            object.required.retain(|name| !excluded.contains(name));
+
+            crate::openapi::visit::walk_object_mut(self, object);
+        }
+    }
+
+    /// Create request or response [`SchemaVariant`] of the `schema` of a type deriving
+    /// `ToSchema` with `request_response`. References of the `names` are pointed to the same
+    /// variant of the referenced schemas and the `required` properties which only belong to
+    /// this variant are marked required.
+    pub fn schema_variant(
+        mut schema: crate::openapi::RefOr<crate::openapi::schema::Schema>,
+        variant: SchemaVariant,
+        names: &[(String, String)],
+        required: &[&str],
+    ) -> crate::openapi::RefOr<crate::openapi::schema::Schema> {
+        use crate::openapi::{schema::Schema, visit::VisitMut, RefOr};
+
+        let mut variants = SchemaVariants {
+            variant: Some(variant),
+            names,
+        };
+        match &mut schema {
+            RefOr::Ref(reference) => variants.visit_ref_mut(reference),
+            RefOr::T(schema) => variants.visit_schema_mut(schema),
+        }
+
+        let require = |object: &mut crate::openapi::schema::Object| {
+            for name in required {
+                if object.properties.contains_key(*name)
+                    && !object.required.iter().any(|other| other == name)
+                {
+                    object.required.push(name.to_string());
+                }
+            }
+        };
+        match &mut schema {
+            RefOr::T(Schema::Object(object)) => require(object),
+            RefOr::T(Schema::AllOf(all_of)) => {
+                for item in &mut all_of.items {
+                    if let RefOr::T(Schema::Object(object)) = item {
+                        require(object);
+                    }
+                }
+            }
+            _ => (),
+        }
+
+        schema
+    }
+
+    /// Point schema references of the request body of the `operation` to request variants and
+    /// references of the responses to response variants of the schemas.
+    pub fn operation_schema_variants(
+        mut operation: crate::openapi::path::Operation,
+        request_names: &[(String, String)],
+        response_names: &[(String, String)],
+    ) -> crate::openapi::path::Operation {
+        use crate::openapi::{visit::VisitMut, RefOr};
+
+        if let Some(RefOr::T(request_body)) = operation.request_body.as_mut() {
+            SchemaVariants {
+                variant: None,
+                names: request_names,
+            }
+            .visit_request_body_mut(request_body);
+        }
+        SchemaVariants {
+            variant: None,
+            names: response_names,
+        }
+        .visit_responses_mut(&mut operation.responses);
+
+        operation
+    }
+
     #[deprecated(
         note = "Using a function path for `ignore` is deprecated and will be removed in a future version. Use `ignore = true` or `ignore = false` instead."
     )]
@@ -1644,6 +2365,122 @@ mod tests {
         assert_compact_json_snapshot!(u64::schema(), @r#"{"type": "integer", "format": "int64", "minimum": 0}"#);
     }
 
+    #[test]
+    fn test_partial_schema_std_types() {
+        use std::num::{NonZeroI32, NonZeroU64};
+
+        for (name, schema, value) in [
+            (
+                "(String, Option<i32>)",
+                <(String, Option<i32>)>::schema(),
+                json!({
+                    "type": "array",
+                    "items": false,
+                    "prefixItems": [
+                        {"type": "string"},
+                        {"oneOf": [{"type": "null"}, {"type": "integer", "format": "int32"}]}
+                    ]
+                }),
+            ),
+            (
+                "[bool; 3]",
+                <[bool; 3]>::schema(),
+                json!({"type": "array", "items": {"type": "boolean"}, "minItems": 3, "maxItems": 3}),
+            ),
+            (
+                "VecDeque<String>",
+                std::collections::VecDeque::<String>::schema(),
+                json!({"type": "array", "items": {"type": "string"}}),
+            ),
+            (
+                "BinaryHeap<String>",
+                std::collections::BinaryHeap::<String>::schema(),
+                json!({"type": "array", "items": {"type": "string"}}),
+            ),
+            (
+                "NonZeroU64",
+                NonZeroU64::schema(),
+                json!({"type": "integer", "format": "int64", "minimum": 1}),
+            ),
+            (
+                "NonZeroI32",
+                NonZeroI32::schema(),
+                json!({
+                    "type": "integer",
+                    "format": "int32",
+                    "not": {"type": "integer", "const": 0}
+                }),
+            ),
+            (
+                "Duration",
+                std::time::Duration::schema(),
+                json!({
+                    "type": "object",
+                    "required": ["secs", "nanos"],
+                    "properties": {
+                        "secs": {"type": "integer", "format": "int64", "minimum": 0},
+                        "nanos": {"type": "integer", "format": "int32", "minimum": 0}
+                    }
+                }),
+            ),
+            (
+                "IpAddr",
+                std::net::IpAddr::schema(),
+                json!({"type": "string"}),
+            ),
+            (
+                "Ipv4Addr",
+                std::net::Ipv4Addr::schema(),
+                json!({"type": "string", "format": "ipv4"}),
+            ),
+            (
+                "Ipv6Addr",
+                std::net::Ipv6Addr::schema(),
+                json!({"type": "string", "format": "ipv6"}),
+            ),
+            (
+                "SocketAddr",
+                std::net::SocketAddr::schema(),
+                json!({"type": "string"}),
+            ),
+            (
+                "PathBuf",
+                std::path::PathBuf::schema(),
+                json!({"type": "string"}),
+            ),
+            (
+                "PhantomData<String>",
+                std::marker::PhantomData::<String>::schema(),
+                json!({"type": "null"}),
+            ),
+            (
+                "Mutex<Wrapping<Cell<bool>>>",
+                std::sync::Mutex::<std::num::Wrapping<std::cell::Cell<bool>>>::schema(),
+                json!({"type": "boolean"}),
+            ),
+            (
+                "RwLock<String>",
+                std::sync::RwLock::<String>::schema(),
+                json!({"type": "string"}),
+            ),
+        ] {
+            println!(
+                "{name}: {json}",
+                json = serde_json::to_string(&schema).unwrap()
+            );
+            let schema = serde_json::to_value(schema).unwrap();
+            assert_eq!(schema, value);
+        }
+
+        assert_eq!(<(String, i32)>::name(), Cow::Borrowed("Tuple2-String-i32"));
+        assert_eq!(NonZeroU64::name(), Cow::Borrowed("NonZeroU64"));
+        assert_eq!(NonZeroI32::name(), Cow::Borrowed("NonZeroI32"));
+        assert_ne!(
+            <((String, i32), bool)>::name(),
+            <((String,), i32, bool)>::name()
+        );
+    }
+
     #[test]
     fn test_partial_schema() {
         for (name, schema, value) in [
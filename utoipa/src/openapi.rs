@@ -4,15 +4,18 @@ use serde::{
     de::{Error, Expected, Visitor},
     Deserialize, Deserializer, Serialize, Serializer,
 };
-use std::fmt::Formatter;
+use std::{collections::BTreeMap, fmt::Formatter};
 
 use self::path::PathsMap;
 pub use self::{
     content::{Content, ContentBuilder},
+    diff::DiffReport,
     external_docs::ExternalDocs,
     header::{Header, HeaderBuilder},
     info::{Contact, ContactBuilder, Info, InfoBuilder, License, LicenseBuilder},
+    merge::{MergeReport, MergeStrategy},
     path::{HttpMethod, PathItem, Paths, PathsBuilder},
+    resolver::{Referenceable, ResolveError},
     response::{Response, ResponseBuilder, Responses, ResponsesBuilder},
     schema::{
         AllOf, AllOfBuilder, Array, ArrayBuilder, Components, ComponentsBuilder, Discriminator,
@@ -22,23 +25,36 @@ pub use self::{
     security::SecurityRequirement,
     server::{Server, ServerBuilder, ServerVariable, ServerVariableBuilder},
     tag::Tag,
+    validation::ValidationError,
+    visit::{Visit, VisitMut},
 };
 
+pub mod bundle;
 pub mod content;
+pub mod diff;
+pub mod downgrade;
 pub mod encoding;
 pub mod example;
 pub mod extensions;
 pub mod external_docs;
+mod filter;
 pub mod header;
 pub mod info;
 pub mod link;
+pub mod merge;
 pub mod path;
 pub mod request_body;
+pub mod resolver;
 pub mod response;
 pub mod schema;
+#[cfg(feature = "schema_validation")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "schema_validation")))]
+pub mod schema_validation;
 pub mod security;
 pub mod server;
 pub mod tag;
+pub mod validation;
+pub mod visit;
 pub mod xml;
 
 builder! {
@@ -91,6 +107,14 @@ builder! {
         /// See more details at <https://spec.openapis.org/oas/latest.html#paths-object>.
         pub paths: Paths,
 
+        /// Incoming webhooks that may be received as part of this API and that the API consumer
+        /// may choose to implement. The key is a unique name of the webhook and the [`PathItem`]
+        /// describes the request that may be initiated by the API provider.
+        ///
+        /// See more details at <https://spec.openapis.org/oas/latest.html#fixed-fields>.
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        pub webhooks: BTreeMap<String, RefOr<PathItem>>,
+
         /// Holds various reusable schemas for the OpenAPI document.
         ///
         /// Few of these elements are security schemas and object schemas.
@@ -153,6 +177,32 @@ impl OpenApi {
         }
     }
 
+    /// Parse [`OpenApi`] from JSON String. This method essentially calls [`serde_json::from_str`] method.
+    ///
+    /// # Examples
+    ///
+    /// _**Parse hand written OpenAPI document and merge it to derived document.**_
+    /// ```rust
+    /// # use utoipa::openapi::OpenApi;
+    /// # fn merge(mut openapi: OpenApi) -> Result<OpenApi, serde_json::Error> {
+    /// let legacy = OpenApi::from_json(
+    ///     r#"{"openapi": "3.1.0", "info": {"title": "legacy", "version": "1"}, "paths": {}}"#,
+    /// )?;
+    /// openapi.merge(legacy);
+    /// # Ok(openapi)
+    /// # }
+    /// ```
+    pub fn from_json(json: &str) -> Result<OpenApi, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Parse [`OpenApi`] from YAML String. This method essentially calls [`yaml_serde::from_str`].
+    #[cfg(feature = "yaml")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "yaml")))]
+    pub fn from_yaml(yaml: &str) -> Result<OpenApi, yaml_serde::Error> {
+        yaml_serde::from_str(yaml)
+    }
+
     /// Converts this [`OpenApi`] to JSON String. This method essentially calls [`serde_json::to_string`] method.
     pub fn to_json(&self) -> Result<String, serde_json::Error> {
         serde_json::to_string(self)
@@ -170,6 +220,342 @@ impl OpenApi {
         yaml_serde::to_string(self)
     }
 
+    /// Validate this [`OpenApi`] document returning all found [`ValidationError`]s.
+    ///
+    /// Validation checks that:
+    /// * Every `$ref` pointing to `#/components/...` resolves to defined component.
+    /// * Every `operationId` is unique within the document.
+    /// * Every path template parameter e.g. `{id}` has a matching [`ParameterIn::Path`][in_path]
+    ///   parameter.
+    /// * Every [`SecurityRequirement`] names a security scheme defined in
+    ///   [`Components::security_schemes`].
+    /// * Every tag used by an operation is defined in [`OpenApi::tags`].
+    /// * Fields supported from OpenAPI 3.2 onwards are not used when [`OpenApi::openapi`] is
+    ///   [`OpenApiVersion::Version31`].
+    ///
+    /// # Examples
+    ///
+    /// _**Find undefined security scheme.**_
+    /// ```rust
+    /// # use utoipa::openapi::validation::ValidationError;
+    /// #[utoipa::path(get, path = "/pets", security(("api_keyy" = [])), responses((status = 200)))]
+    /// fn get_pets() {}
+    ///
+    /// #[derive(utoipa::OpenApi)]
+    /// #[openapi(paths(get_pets))]
+    /// struct ApiDoc;
+    ///
+    /// # use utoipa::OpenApi;
+    /// let errors = ApiDoc::openapi().validate().unwrap_err();
+    ///
+    /// assert!(matches!(
+    ///     &errors[0],
+    ///     ValidationError::UndefinedSecurityScheme { name, .. } if name == "api_keyy"
+    /// ));
+    /// ```
+    ///
+    /// [in_path]: path::ParameterIn::Path
+    pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let errors = validation::validate(self);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Validate every `example` and `examples` value of the document against the [`Schema`] it
+    /// is an example of.
+    ///
+    /// Examples of media types, parameters and headers are validated against their schema and
+    /// examples of schemas, including the nested schemas, are validated against the schema itself.
+    /// References are resolved through the [`Components`] of the document. See
+    /// [`schema_validation`] for the supported JSON Schema keywords.
+    ///
+    /// This is useful for checking in tests that the examples still match the schemas after
+    /// the types of the API have changed.
+    ///
+    /// # Examples
+    ///
+    /// _**Find example not matching the schema.**_
+    /// ```rust
+    /// #[derive(utoipa::ToSchema)]
+    /// #[schema(example = json!({"name": "Tom", "age": "ten"}))]
+    /// struct Pet {
+    ///     name: String,
+    ///     age: u8,
+    /// }
+    ///
+    /// #[derive(utoipa::OpenApi)]
+    /// #[openapi(components(schemas(Pet)))]
+    /// struct ApiDoc;
+    ///
+    /// # use utoipa::OpenApi;
+    /// let errors = ApiDoc::openapi().validate_examples().unwrap_err();
+    ///
+    /// assert_eq!(errors[0].location, "#/components/schemas/Pet/example/age");
+    /// ```
+    #[cfg(feature = "schema_validation")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "schema_validation")))]
+    pub fn validate_examples(&self) -> Result<(), Vec<schema_validation::SchemaValidationError>> {
+        schema_validation::validate_examples(self)
+    }
+
+    /// Resolve given [`RefOr`] to the concrete component. [`RefOr::T`] is returned as is and
+    /// [`RefOr::Ref`] is resolved with [`OpenApi::resolve_ref`].
+    ///
+    /// # Examples
+    ///
+    /// _**Resolve schema of a response.**_
+    /// ```rust
+    /// # use utoipa::openapi::{ComponentsBuilder, ObjectBuilder, OpenApiBuilder, Ref, RefOr, Schema};
+    /// let openapi = OpenApiBuilder::new()
+    ///     .components(Some(
+    ///         ComponentsBuilder::new()
+    ///             .schema("Pet", ObjectBuilder::new())
+    ///             .build(),
+    ///     ))
+    ///     .build();
+    ///
+    /// let schema: RefOr<Schema> = Ref::from_schema_name("Pet").into();
+    /// assert!(matches!(openapi.resolve(&schema), Ok(Schema::Object(_))));
+    /// ```
+    pub fn resolve<'a, T: Referenceable>(
+        &'a self,
+        ref_or: &'a RefOr<T>,
+    ) -> Result<&'a T, ResolveError> {
+        match ref_or {
+            RefOr::T(component) => Ok(component),
+            RefOr::Ref(reference) => self.resolve_ref(reference),
+        }
+    }
+
+    /// Resolve given [`Ref`] to the concrete component in [`OpenApi::components`].
+    ///
+    /// The reference must point to the [`Components`] of the type `T`, e.g.
+    /// `#/components/schemas/Pet` for [`Schema`]. If the referenced component is itself a
+    /// [`Ref`] it will be followed until the concrete component is found.
+    ///
+    /// Returns [`ResolveError`] if the component does not exist, the reference points elsewhere
+    /// than the [`Components`] of type `T` or the references form a cycle.
+    pub fn resolve_ref<T: Referenceable>(&self, reference: &Ref) -> Result<&T, ResolveError> {
+        resolver::resolve_ref(self, reference)
+    }
+
+    /// Create a fully dereferenced copy of this [`OpenApi`] document.
+    ///
+    /// Every `$ref` to the [`Components`] is replaced with a copy of the referenced component.
+    /// Values set alongside the `$ref` such as `description` override the values of the
+    /// component. References of recursive types e.g. types using `no_recursion` cannot be inlined
+    /// infinitely. Therefore a reference to a component that is already being inlined is left
+    /// as is, and the [`Components`] are retained in the returned document.
+    ///
+    /// Returns [`ResolveError`] if any of the references cannot be resolved.
+    pub fn dereference(&self) -> Result<OpenApi, ResolveError> {
+        resolver::dereference(self)
+    }
+
+    /// Compare this [`OpenApi`] document to a `new` version of it and get [`DiffReport`] of the
+    /// changes made to the paths, webhooks and their operations.
+    ///
+    /// Each [`Change`][change] is classified as breaking or non-breaking. Removed paths, webhooks,
+    /// operations, responses and media types are breaking as well as new required parameters and
+    /// request bodies. Schemas of parameters, request bodies and responses are compared following
+    /// the references to [`Components`]; narrowing a request schema e.g. removing `enum` values
+    /// or making a property required is breaking, as is widening a response schema e.g. making
+    /// a property optional. Changing the type of a schema is breaking unless it only widens a
+    /// request or narrows a response.
+    ///
+    /// # Examples
+    ///
+    /// _**Fail when the new version of the API has breaking changes.**_
+    /// ```rust
+    /// # use utoipa::openapi::{OpenApiBuilder, PathsBuilder, PathItem, HttpMethod};
+    /// # use utoipa::openapi::path::OperationBuilder;
+    /// let released = OpenApiBuilder::new()
+    ///     .paths(PathsBuilder::new().path(
+    ///         "/pets",
+    ///         PathItem::new(HttpMethod::Get, OperationBuilder::new()),
+    ///     ))
+    ///     .build();
+    /// let current = OpenApiBuilder::new().build();
+    ///
+    /// let report = released.diff(&current);
+    ///
+    /// assert!(report.is_breaking());
+    /// assert_eq!(report.to_string(), "[breaking] /pets: path removed\n");
+    /// ```
+    ///
+    /// [change]: diff::Change
+    pub fn diff(&self, new: &OpenApi) -> DiffReport {
+        diff::diff(self, new)
+    }
+
+    /// Remove all components that are not reachable from the paths, webhooks or other parts of
+    /// the document outside of [`Components`].
+    ///
+    /// Reachability follows the [`Ref`]s transitively, thus a component only referenced by
+    /// another reachable component is retained. All the referenceable components such as
+    /// [`Components::schemas`] and [`Components::responses`] are cleaned up;
+    /// [`Components::security_schemes`] are referenced by [`SecurityRequirement`]s instead of
+    /// [`Ref`]s and are retained as is.
+    ///
+    /// Components are left untouched if the reachability cannot be resolved because the document
+    /// fails to serialize.
+    ///
+    /// # Examples
+    ///
+    /// _**Remove schema not used by any operation.**_
+    /// ```rust
+    /// # use utoipa::openapi::{ComponentsBuilder, ObjectBuilder, OpenApiBuilder};
+    /// let mut openapi = OpenApiBuilder::new()
+    ///     .components(Some(
+    ///         ComponentsBuilder::new()
+    ///             .schema("Internal", ObjectBuilder::new())
+    ///             .build(),
+    ///     ))
+    ///     .build();
+    ///
+    /// openapi.remove_unused_components();
+    ///
+    /// assert!(openapi.components.unwrap().schemas.is_empty());
+    /// ```
+    pub fn remove_unused_components(&mut self) {
+        let Ok(reachable) = resolver::reachable_components(self) else {
+            return;
+        };
+        if let Some(components) = self.components.as_mut() {
+            resolver::retain_components(components, |location| reachable.contains(location));
+        }
+    }
+
+    /// Create a new [`OpenApi`] document keeping only the [`Operation`][operation]s of
+    /// [`OpenApi::paths`] matching the given `predicate`.
+    ///
+    /// The `predicate` is called with the path, [`HttpMethod`] and [`Operation`][operation] of
+    /// each operation and can be used to filter operations e.g. by tag, path prefix, HTTP method
+    /// or extension. Paths left without operations are removed.
+    ///
+    /// Components, tags and security schemes that were used by the removed operations and are not
+    /// used by the retained ones are removed as well. Items that were not used by any operation
+    /// in the first place are retained as is.
+    ///
+    /// # Examples
+    ///
+    /// _**Create public API document without operations marked with `x-audience: partner`.**_
+    /// ```rust
+    /// # use utoipa::openapi::OpenApi;
+    /// # fn filter(openapi: OpenApi) -> OpenApi {
+    /// openapi.filter(|_path, _method, operation| {
+    ///     operation
+    ///         .extensions
+    ///         .as_ref()
+    ///         .and_then(|extensions| extensions.get("x-audience"))
+    ///         .is_none_or(|audience| audience != "partner")
+    /// })
+    /// # }
+    /// ```
+    ///
+    /// _**Create document of operations tagged with `pets` under `/api/v1`.**_
+    /// ```rust
+    /// # use utoipa::openapi::OpenApi;
+    /// # fn filter(openapi: OpenApi) -> OpenApi {
+    /// openapi.filter(|path, _method, operation| {
+    ///     path.starts_with("/api/v1")
+    ///         && operation
+    ///             .tags
+    ///             .iter()
+    ///             .flatten()
+    ///             .any(|tag| tag == "pets")
+    /// })
+    /// # }
+    /// ```
+    ///
+    /// [operation]: path::Operation
+    pub fn filter<F: Fn(&str, &HttpMethod, &path::Operation) -> bool>(
+        &self,
+        predicate: F,
+    ) -> OpenApi {
+        filter::filter(self, predicate)
+    }
+
+    /// Convert this OpenAPI 3.1 document to OpenAPI 3.0 document for tools that do not yet
+    /// support OpenAPI 3.1.
+    ///
+    /// The converted document declares OpenAPI version `3.0.3` and its schemas are mapped to
+    /// their closest OpenAPI 3.0 equivalents. E.g. `type: [T, "null"]` is converted to
+    /// `type: T` with `nullable: true`, numeric `exclusiveMinimum` is converted to `minimum`
+    /// with boolean `exclusiveMinimum`, `const` is converted to single value `enum` and
+    /// `contentEncoding: base64` is converted to `format: byte`.
+    ///
+    /// Features that do not exist in OpenAPI 3.0 such as webhooks are removed and reported
+    /// together with lossy conversions in [`downgrade::OpenApiV30::warnings`].
+    ///
+    /// # Examples
+    ///
+    /// _**Convert document to OpenAPI 3.0 and print the conversion warnings.**_
+    /// ```rust
+    /// # use utoipa::openapi::OpenApi;
+    /// # fn convert(openapi: OpenApi) -> Result<String, serde_json::Error> {
+    /// let openapi_30 = openapi.to_openapi_30();
+    /// for warning in &openapi_30.warnings {
+    ///     eprintln!("{warning}");
+    /// }
+    /// openapi_30.to_pretty_json()
+    /// # }
+    /// ```
+    pub fn to_openapi_30(&self) -> downgrade::OpenApiV30 {
+        downgrade::downgrade(self)
+    }
+
+    /// Split this [`OpenApi`] document to multiple files in the given [`bundle::FileFormat`].
+    ///
+    /// Every path item is moved to a file of its own in `paths` directory and every schema of the
+    /// [`Components`] to a file of its own in `schemas` directory. The moved items are replaced
+    /// with relative external [`Ref`]s in the root document and the references within the moved
+    /// items are rewritten to be relative to the file they are in.
+    ///
+    /// Use [`OpenApi::bundle`] to bundle the files back to single document.
+    ///
+    /// # Examples
+    ///
+    /// _**Write split document to `api` directory.**_
+    /// ```rust,no_run
+    /// # use utoipa::openapi::OpenApi;
+    /// # use utoipa::openapi::bundle::FileFormat;
+    /// # fn write(openapi: OpenApi) -> std::io::Result<()> {
+    /// openapi.split(FileFormat::Json).write("api")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn split(&self, format: bundle::FileFormat) -> bundle::SplitOpenApi {
+        bundle::split(self, format)
+    }
+
+    /// Bundle [`OpenApi`] document split to multiple files back to single document.
+    ///
+    /// The `root` is path to the root document which is read together with every file it
+    /// references with relative external [`Ref`]s. References to components defined in other
+    /// files are rewritten to local references of the bundled document and other external
+    /// references are replaced with the referenced values. Files with `.yaml` or `.yml`
+    /// extension require `yaml` feature and other files are parsed as JSON. References pointing
+    /// outside of the directory of the root document are rejected.
+    ///
+    /// # Examples
+    ///
+    /// _**Bundle document from `api` directory.**_
+    /// ```rust,no_run
+    /// # use utoipa::openapi::OpenApi;
+    /// # use utoipa::openapi::bundle::BundleError;
+    /// # fn read() -> Result<OpenApi, BundleError> {
+    /// let openapi = OpenApi::bundle("api/openapi.json")?;
+    /// # Ok(openapi)
+    /// # }
+    /// ```
+    pub fn bundle<P: AsRef<std::path::Path>>(root: P) -> Result<OpenApi, bundle::BundleError> {
+        bundle::bundle_files(root.as_ref())
+    }
+
     /// Merge `other` [`OpenApi`] moving `self` and returning combined [`OpenApi`].
     ///
     /// In functionality wise this is exactly same as calling [`OpenApi::merge`] but but provides
@@ -181,13 +567,14 @@ impl OpenApi {
 
     /// Merge `other` [`OpenApi`] consuming it and resuming it's content.
     ///
-    /// Merge function will take all `self` nonexistent _`servers`, `paths`, `schemas`, `responses`,
-    /// `security_schemes`, `security_requirements` and `tags`_ from _`other`_ [`OpenApi`].
+    /// Merge function will take all `self` nonexistent _`servers`, `paths`, `webhooks`, `components`,
+    /// `security_requirements` and `tags`_ from _`other`_ [`OpenApi`].
     ///
-    /// This function performs a shallow comparison for `paths`, `schemas`, `responses` and
-    /// `security schemes` which means that only _`name`_ and _`path`_ is used for comparison. When
+    /// This function performs a shallow comparison for `paths`, `webhooks` and `components` e.g.
+    /// `schemas` which means that only _`name`_ and _`path`_ is used for comparison. When
     /// match occurs the whole item will be ignored from merged results. Only items not
-    /// found will be appended to `self`.
+    /// found will be appended to `self`. Use [`OpenApi::merge_with_strategy`] to detect and
+    /// resolve conflicting items.
     ///
     /// For _`servers`_, _`tags`_ and _`security_requirements`_ the whole item will be used for
     /// comparison. Items not found from `self` will be appended to `self`.
@@ -204,25 +591,38 @@ impl OpenApi {
             self.paths.merge(other.paths);
         };
 
+        other
+            .webhooks
+            .retain(|name, _| !self.webhooks.contains_key(name));
+        self.webhooks.append(&mut other.webhooks);
+
         if let Some(other_components) = &mut other.components {
             let components = self.components.get_or_insert(Components::default());
 
-            other_components
-                .schemas
-                .retain(|name, _| !components.schemas.contains_key(name));
-            components.schemas.append(&mut other_components.schemas);
-
-            other_components
-                .responses
-                .retain(|name, _| !components.responses.contains_key(name));
-            components.responses.append(&mut other_components.responses);
-
-            other_components
-                .security_schemes
-                .retain(|name, _| !components.security_schemes.contains_key(name));
-            components
-                .security_schemes
-                .append(&mut other_components.security_schemes);
+            fn append_nonexistent<T>(
+                items: &mut BTreeMap<String, T>,
+                other_items: &mut BTreeMap<String, T>,
+            ) {
+                other_items.retain(|name, _| !items.contains_key(name));
+                items.append(other_items);
+            }
+
+            append_nonexistent(&mut components.schemas, &mut other_components.schemas);
+            append_nonexistent(&mut components.responses, &mut other_components.responses);
+            append_nonexistent(&mut components.parameters, &mut other_components.parameters);
+            append_nonexistent(&mut components.examples, &mut other_components.examples);
+            append_nonexistent(
+                &mut components.request_bodies,
+                &mut other_components.request_bodies,
+            );
+            append_nonexistent(&mut components.headers, &mut other_components.headers);
+            append_nonexistent(
+                &mut components.security_schemes,
+                &mut other_components.security_schemes,
+            );
+            append_nonexistent(&mut components.links, &mut other_components.links);
+            append_nonexistent(&mut components.callbacks, &mut other_components.callbacks);
+            append_nonexistent(&mut components.path_items, &mut other_components.path_items);
         }
 
         if let Some(other_security) = &mut other.security {
@@ -238,6 +638,51 @@ impl OpenApi {
         }
     }
 
+    /// Merge `other` [`OpenApi`] resolving conflicts with given [`MergeStrategy`].
+    ///
+    /// Works the same way as [`OpenApi::merge`] but every component, webhook and operation of
+    /// `other` that has the same name as an existing one but different content is reported as
+    /// [`MergeConflict`][merge_conflict] and resolved according to the `strategy`. Operations
+    /// conflicts are resolved with [`Paths::merge_with_strategy`].
+    ///
+    /// Returns [`MergeReport`] of the resolved conflicts or [`MergeError`][merge_error] without
+    /// merging anything if [`MergeStrategy::ErrorOnDifference`] is used and conflicts are found.
+    ///
+    /// # Examples
+    ///
+    /// _**Fail the merge when two different `Error` schemas are found.**_
+    /// ```rust
+    /// # use utoipa::openapi::{ComponentsBuilder, ObjectBuilder, OpenApiBuilder, Type};
+    /// # use utoipa::openapi::merge::MergeStrategy;
+    /// let api_with_error = |schema_type: Type| {
+    ///     OpenApiBuilder::new()
+    ///         .components(Some(
+    ///             ComponentsBuilder::new()
+    ///                 .schema("Error", ObjectBuilder::new().schema_type(schema_type))
+    ///                 .build(),
+    ///         ))
+    ///         .build()
+    /// };
+    ///
+    /// let mut api = api_with_error(Type::Object);
+    /// let result = api.merge_with_strategy(
+    ///     api_with_error(Type::String),
+    ///     MergeStrategy::ErrorOnDifference,
+    /// );
+    ///
+    /// assert!(result.is_err());
+    /// ```
+    ///
+    /// [merge_conflict]: merge::MergeConflict
+    /// [merge_error]: merge::MergeError
+    pub fn merge_with_strategy(
+        &mut self,
+        other: OpenApi,
+        strategy: MergeStrategy,
+    ) -> Result<MergeReport, merge::MergeError> {
+        merge::merge(self, other, strategy)
+    }
+
     /// Nest `other` [`OpenApi`] to this [`OpenApi`].
     ///
     /// Nesting performs custom [`OpenApi::merge`] where `other` [`OpenApi`] paths are prepended with given
@@ -321,6 +766,11 @@ impl OpenApi {
 }
 
 impl OpenApiBuilder {
+    /// Add [`OpenApiVersion`] of the document. Defaults to [`OpenApiVersion::Version31`].
+    pub fn openapi(mut self, openapi: OpenApiVersion) -> Self {
+        set_value!(self openapi openapi)
+    }
+
     /// Add [`Info`] metadata of the API.
     pub fn info<I: Into<Info>>(mut self, info: I) -> Self {
         set_value!(self info info.into())
@@ -336,6 +786,46 @@ impl OpenApiBuilder {
         set_value!(self paths paths.into())
     }
 
+    /// Add map of webhooks to describe requests the API may initiate to API consumers.
+    pub fn webhooks<I: IntoIterator<Item = (N, W)>, N: Into<String>, W: Into<RefOr<PathItem>>>(
+        mut self,
+        webhooks: I,
+    ) -> Self {
+        set_value!(self webhooks webhooks
+            .into_iter()
+            .map(|(name, webhook)| (name.into(), webhook.into()))
+            .collect())
+    }
+
+    /// Append named webhook [`PathItem`] to the webhooks. If webhook with same name already
+    /// exists it will be replaced.
+    ///
+    /// # Examples
+    ///
+    /// _**Add `newPet` webhook to the [`OpenApi`].**_
+    /// ```rust
+    /// # use utoipa::openapi::{HttpMethod, OpenApiBuilder, PathItem, Response};
+    /// # use utoipa::openapi::path::OperationBuilder;
+    /// let openapi = OpenApiBuilder::new()
+    ///     .webhook(
+    ///         "newPet",
+    ///         PathItem::new(
+    ///             HttpMethod::Post,
+    ///             OperationBuilder::new().response("200", Response::new("Webhook received")),
+    ///         ),
+    ///     )
+    ///     .build();
+    /// ```
+    pub fn webhook<N: Into<String>, W: Into<RefOr<PathItem>>>(
+        mut self,
+        name: N,
+        webhook: W,
+    ) -> Self {
+        self.webhooks.insert(name.into(), webhook.into());
+
+        self
+    }
+
     /// Add [`Components`] to configure reusable schemas.
     pub fn components(mut self, components: Option<Components>) -> Self {
         set_value!(self components components)
@@ -381,10 +871,16 @@ impl OpenApiBuilder {
 #[derive(Serialize, Clone, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub enum OpenApiVersion {
-    /// Will serialize to `3.1.0` the latest released OpenAPI version.
+    /// Will serialize to `3.1.0` the default OpenAPI version.
     #[serde(rename = "3.1.0")]
     #[default]
     Version31,
+    /// Will serialize to `3.2.0`. Use this version when the document uses OpenAPI 3.2 features
+    /// such as [`HttpMethod::Query`] operations, [`PathItem::additional_operations`],
+    /// hierarchical [`Tag`][tag::Tag]s or [`Flow::DeviceAuthorization`][security::Flow::DeviceAuthorization].
+    /// [`OpenApi::validate`] reports these features used with [`OpenApiVersion::Version31`].
+    #[serde(rename = "3.2.0")]
+    Version32,
 }
 
 impl<'de> Deserialize<'de> for OpenApiVersion {
@@ -398,7 +894,7 @@ impl<'de> Deserialize<'de> for OpenApiVersion {
             type Value = OpenApiVersion;
 
             fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
-                formatter.write_str("a version string in 3.1.x format")
+                formatter.write_str("a version string in 3.1.x or 3.2.x format")
             }
 
             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
@@ -417,14 +913,16 @@ impl<'de> Deserialize<'de> for OpenApiVersion {
                     .flat_map(|digit| digit.parse::<i8>())
                     .collect::<Vec<_>>();
 
-                if version.len() == 3 && version.first() == Some(&3) && version.get(1) == Some(&1) {
-                    Ok(OpenApiVersion::Version31)
-                } else {
-                    let expected: &dyn Expected = &"3.1.0";
-                    Err(Error::invalid_value(
-                        serde::de::Unexpected::Str(&v),
-                        expected,
-                    ))
+                match version.as_slice() {
+                    [3, 1, _] => Ok(OpenApiVersion::Version31),
+                    [3, 2, _] => Ok(OpenApiVersion::Version32),
+                    _ => {
+                        let expected: &dyn Expected = &"3.1.0 or 3.2.0";
+                        Err(Error::invalid_value(
+                            serde::de::Unexpected::Str(&v),
+                            expected,
+                        ))
+                    }
                 }
             }
         }
@@ -667,6 +1165,12 @@ mod tests {
     #[test]
     fn serialize_deserialize_openapi_version_success() -> Result<(), serde_json::Error> {
         assert_eq!(serde_json::to_value(&OpenApiVersion::Version31)?, "3.1.0");
+        assert_eq!(serde_json::to_value(&OpenApiVersion::Version32)?, "3.2.0");
+        assert!(
+            serde_json::from_value::<OpenApiVersion>(serde_json::json!("3.2.1"))?
+                == OpenApiVersion::Version32
+        );
+        assert!(serde_json::from_value::<OpenApiVersion>(serde_json::json!("3.0.3")).is_err());
         Ok(())
     }
 
@@ -906,6 +1410,27 @@ mod tests {
         assert_json_snapshot!(paths);
     }
 
+    #[test]
+    fn merge_openapi_webhooks() {
+        let webhook = |description: &str| {
+            PathItem::new(
+                HttpMethod::Post,
+                OperationBuilder::new().response("200", Response::new(description)),
+            )
+        };
+        let mut api_1 = OpenApiBuilder::new()
+            .webhook("newPet", webhook("New pet received"))
+            .build();
+        let api_2 = OpenApiBuilder::new()
+            .webhook("newPet", webhook("This will not get added"))
+            .webhook("petRemoved", webhook("Pet removed received"))
+            .build();
+
+        api_1.merge(api_2);
+
+        assert_json_snapshot!(api_1);
+    }
+
     #[test]
     fn openapi_custom_extension() {
         let mut api = OpenApiBuilder::new().build();
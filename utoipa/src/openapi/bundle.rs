@@ -0,0 +1,822 @@
+//! Implements splitting of [`OpenApi`] document to multiple files and bundling them back to a
+//! single document.
+//!
+//! Use [`OpenApi::split`] to move the [`Paths`][paths] and the schemas of the
+//! [`Components`][components] to files of their own which are referenced with relative external
+//! [`Ref`][ref]s. The split document has a directory layout such as:
+//!
+//! ```text
+//! openapi.yaml
+//! paths/pets.yaml
+//! paths/pets_id.yaml
+//! schemas/Pet.yaml
+//! ```
+//!
+//! Use [`OpenApi::bundle`] or [`SplitOpenApi::bundle`] to resolve the external references back to
+//! a single [`OpenApi`] document.
+//!
+//! [paths]: super::Paths
+//! [components]: super::Components
+//! [ref]: super::Ref
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+use serde_json::Value;
+
+use super::resolver::unescape;
+use super::validation::escape;
+use super::OpenApi;
+
+/// Name of the root document file without extension.
+const ROOT: &str = "openapi";
+const SCHEMAS: &str = "schemas";
+const PATHS: &str = "paths";
+const SCHEMAS_PREFIX: &str = "#/components/schemas/";
+
+/// File format of the files of [`SplitOpenApi`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileFormat {
+    /// Files are written as pretty printed JSON with `.json` extension.
+    Json,
+    /// Files are written as YAML with `.yaml` extension.
+    #[cfg(feature = "yaml")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "yaml")))]
+    Yaml,
+}
+
+impl FileFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            #[cfg(feature = "yaml")]
+            Self::Yaml => "yaml",
+        }
+    }
+
+    fn serialize(self, value: &Value) -> std::io::Result<String> {
+        match self {
+            Self::Json => Ok(serde_json::to_string_pretty(value)?),
+            #[cfg(feature = "yaml")]
+            Self::Yaml => yaml_serde::to_string(value).map_err(std::io::Error::other),
+        }
+    }
+}
+
+/// [`OpenApi`] document split to multiple files with [`OpenApi::split`].
+///
+/// Files are represented as [`serde_json::Value`]s by their path relative to the directory of
+/// the root document.
+#[non_exhaustive]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct SplitOpenApi {
+    /// Path of the root document e.g. `openapi.yaml`.
+    pub root: String,
+
+    /// Format of the files.
+    pub format: FileFormat,
+
+    /// All files of the split document including the root document.
+    pub files: BTreeMap<String, Value>,
+}
+
+impl SplitOpenApi {
+    /// Write the files to the given directory creating the `paths` and `schemas` sub
+    /// directories if they do not exist.
+    ///
+    /// Existing files are overwritten but files which are not part of this document are left
+    /// untouched, thus a clean directory should be used to avoid stale files.
+    pub fn write<P: AsRef<Path>>(&self, dir: P) -> std::io::Result<()> {
+        let dir = dir.as_ref();
+        for (file, content) in &self.files {
+            let path = dir.join(file);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, self.format.serialize(content)?)?;
+        }
+
+        Ok(())
+    }
+
+    /// Bundle the files of this document back to single [`OpenApi`] document.
+    ///
+    /// This is the in memory counterpart of [`OpenApi::bundle`].
+    pub fn bundle(&self) -> Result<OpenApi, BundleError> {
+        bundle(&self.root, |file| {
+            self.files
+                .get(file)
+                .cloned()
+                .ok_or_else(|| BundleError::NotFound {
+                    reference: file.to_string(),
+                })
+        })
+    }
+}
+
+/// Error returned when split [`OpenApi`] document cannot be bundled.
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum BundleError {
+    /// File of the document cannot be read.
+    Io {
+        /// Path of the file relative to the directory of the root document.
+        file: String,
+        /// The underlying I/O error.
+        error: std::io::Error,
+    },
+    /// File of the document is not valid JSON or YAML.
+    Parse {
+        /// Path of the file relative to the directory of the root document.
+        file: String,
+        /// Reason why the file cannot be parsed.
+        reason: String,
+    },
+    /// Referenced file or location within the file does not exist.
+    NotFound {
+        /// The reference that cannot be resolved.
+        reference: String,
+    },
+    /// Reference is not a relative reference to a file, e.g. it is an URL.
+    Unsupported {
+        /// The unsupported reference.
+        reference: String,
+    },
+    /// Reference points outside of the directory of the root document.
+    OutsideRoot {
+        /// The reference pointing outside of the root directory.
+        reference: String,
+    },
+    /// References refer to each other without ever reaching a concrete value.
+    Cycle {
+        /// The reference that closes the cycle.
+        reference: String,
+    },
+    /// Bundled document is not a valid [`OpenApi`] document.
+    InvalidDocument {
+        /// Reason why the document is invalid.
+        reason: String,
+    },
+}
+
+impl Display for BundleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io { file, error } => write!(f, "failed to read file `{file}`: {error}"),
+            Self::Parse { file, reason } => write!(f, "failed to parse file `{file}`: {reason}"),
+            Self::NotFound { reference } => write!(f, "reference `{reference}` does not exist"),
+            Self::Unsupported { reference } => {
+                write!(f, "reference `{reference}` is not supported")
+            }
+            Self::OutsideRoot { reference } => {
+                write!(
+                    f,
+                    "reference `{reference}` points outside of the root directory"
+                )
+            }
+            Self::Cycle { reference } => write!(f, "reference `{reference}` is cyclic"),
+            Self::InvalidDocument { reason } => {
+                write!(f, "bundled document is invalid: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BundleError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Get unique file names for the given names. Characters not safe for file names are replaced
+/// with `_` and names colliding on case insensitive file systems are suffixed with a number.
+fn file_names<'a, I: IntoIterator<Item = &'a String>>(
+    names: I,
+    extension: &str,
+) -> BTreeMap<String, String> {
+    let mut used = BTreeSet::new();
+    names
+        .into_iter()
+        .map(|name| {
+            let stem = name
+                .chars()
+                .filter(|c| !matches!(c, '{' | '}'))
+                .map(|c| {
+                    if c.is_ascii_alphanumeric() || c == '-' {
+                        c
+                    } else {
+                        '_'
+                    }
+                })
+                .collect::<String>();
+            let stem = match stem.trim_matches('_') {
+                "" => "index",
+                stem => stem,
+            };
+
+            let mut file = stem.to_string();
+            let mut index = 1;
+            while !used.insert(file.to_lowercase()) {
+                index += 1;
+                file = format!("{stem}_{index}");
+            }
+
+            (name.clone(), format!("{file}.{extension}"))
+        })
+        .collect()
+}
+
+/// Rewrite local references of a value moved to a file of its own to be relative to the file.
+/// References to schemas are rewritten to point the schema files within `schemas_dir` and other
+/// references to point the `root` document.
+fn rewrite_refs(
+    value: &mut Value,
+    schemas_dir: &str,
+    root: &str,
+    schema_files: &BTreeMap<String, String>,
+) {
+    match value {
+        Value::Object(object) => {
+            if let Some(Value::String(reference)) = object.get_mut("$ref") {
+                rewrite_ref(reference, schemas_dir, root, schema_files);
+            }
+            if let Some(Value::Object(mapping)) = object
+                .get_mut("discriminator")
+                .and_then(|discriminator| discriminator.get_mut("mapping"))
+            {
+                for reference in mapping.values_mut() {
+                    if let Value::String(reference) = reference {
+                        rewrite_ref(reference, schemas_dir, root, schema_files);
+                    }
+                }
+            }
+            for value in object.values_mut() {
+                rewrite_refs(value, schemas_dir, root, schema_files);
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                rewrite_refs(value, schemas_dir, root, schema_files);
+            }
+        }
+        _ => (),
+    }
+}
+
+fn rewrite_ref(
+    reference: &mut String,
+    schemas_dir: &str,
+    root: &str,
+    schema_files: &BTreeMap<String, String>,
+) {
+    if reference.starts_with('#') {
+        let schema = reference.strip_prefix(SCHEMAS_PREFIX).and_then(|location| {
+            let (name, fragment) = location
+                .split_once('/')
+                .map(|(name, rest)| (name, format!("#/{rest}")))
+                .unwrap_or((location, String::new()));
+            schema_files
+                .get(&unescape(name))
+                .map(|file| format!("{schemas_dir}{file}{fragment}"))
+        });
+        *reference = schema.unwrap_or_else(|| format!("{root}{reference}"));
+    }
+}
+
+fn external_ref(reference: String) -> Value {
+    Value::Object(
+        [("$ref".to_string(), Value::String(reference))]
+            .into_iter()
+            .collect(),
+    )
+}
+
+pub(super) fn split(openapi: &OpenApi, format: FileFormat) -> SplitOpenApi {
+    let extension = format.extension();
+    let root = format!("{ROOT}.{extension}");
+    let root_ref = format!("../{root}");
+    let mut document = serde_json::to_value(openapi).unwrap_or_default();
+    let mut files = BTreeMap::new();
+
+    let mut schemas = document
+        .pointer_mut("/components/schemas")
+        .and_then(Value::as_object_mut)
+        .map(std::mem::take)
+        .unwrap_or_default();
+    let schema_files = file_names(schemas.keys(), extension);
+    for (name, schema) in schemas.iter_mut() {
+        let file = format!("{SCHEMAS}/{}", schema_files[name]);
+        let mut content = std::mem::replace(schema, external_ref(file.clone()));
+        rewrite_refs(&mut content, "", &root_ref, &schema_files);
+        files.insert(file, content);
+    }
+    if let Some(components) = document
+        .pointer_mut("/components")
+        .and_then(Value::as_object_mut)
+    {
+        if !schemas.is_empty() {
+            components.insert(SCHEMAS.to_string(), Value::Object(schemas));
+        }
+    }
+
+    if let Some(paths) = document.get_mut(PATHS).and_then(Value::as_object_mut) {
+        let schemas_dir = format!("../{SCHEMAS}/");
+        let path_files = file_names(paths.keys(), extension);
+        for (path, item) in paths.iter_mut() {
+            let file = format!("{PATHS}/{}", path_files[path]);
+            let mut content = std::mem::replace(item, external_ref(file.clone()));
+            rewrite_refs(&mut content, &schemas_dir, &root_ref, &schema_files);
+            files.insert(file, content);
+        }
+    }
+
+    files.insert(root.clone(), document);
+
+    SplitOpenApi {
+        root,
+        format,
+        files,
+    }
+}
+
+/// Resolve relative file `reference` against the directory of `file`. References pointing
+/// outside of the directory of the root document are rejected.
+fn join(file: &str, reference: &str) -> Result<String, BundleError> {
+    let mut segments = file.split('/').collect::<Vec<_>>();
+    segments.pop();
+    for segment in reference.split('/') {
+        match segment {
+            "" | "." => (),
+            ".." => {
+                segments.pop().ok_or_else(|| BundleError::OutsideRoot {
+                    reference: reference.to_string(),
+                })?;
+            }
+            segment => segments.push(segment),
+        }
+    }
+
+    Ok(segments.join("/"))
+}
+
+fn parse(file: &str, content: &str) -> Result<Value, BundleError> {
+    let value = if file.ends_with(".yaml") || file.ends_with(".yml") {
+        #[cfg(feature = "yaml")]
+        {
+            yaml_serde::from_str(content).map_err(|error| error.to_string())
+        }
+        #[cfg(not(feature = "yaml"))]
+        {
+            Err("parsing YAML files requires `yaml` feature".to_string())
+        }
+    } else {
+        serde_json::from_str(content).map_err(|error| error.to_string())
+    };
+
+    value.map_err(|reason| BundleError::Parse {
+        file: file.to_string(),
+        reason,
+    })
+}
+
+/// Location within a file as path of the file and JSON Pointer to the value within the file.
+type Target = (String, String);
+
+struct Bundler<L> {
+    load: L,
+    root: String,
+    files: BTreeMap<String, Value>,
+    /// Components of the root document defined in other files by the location of the component.
+    components: BTreeMap<Target, String>,
+    /// Locations currently being inlined, used to detect cycles.
+    inlining: Vec<Target>,
+}
+
+enum Resolved {
+    Ref(String),
+    Inline(Value),
+}
+
+impl<L: FnMut(&str) -> Result<Value, BundleError>> Bundler<L> {
+    fn file(&mut self, file: &str) -> Result<&Value, BundleError> {
+        if !self.files.contains_key(file) {
+            let value = (self.load)(file)?;
+            self.files.insert(file.to_string(), value);
+        }
+
+        Ok(&self.files[file])
+    }
+
+    fn target(&self, file: &str, reference: &str) -> Result<Target, BundleError> {
+        if reference.contains("://") {
+            return Err(BundleError::Unsupported {
+                reference: reference.to_string(),
+            });
+        }
+
+        Ok(match reference.split_once('#') {
+            Some(("", fragment)) => (file.to_string(), fragment.to_string()),
+            Some((path, fragment)) => (join(file, path)?, fragment.to_string()),
+            None => (join(file, reference)?, String::new()),
+        })
+    }
+
+    fn inline(&mut self, target: Target) -> Result<Value, BundleError> {
+        let reference = format!("{}#{}", target.0, target.1);
+        if self.inlining.contains(&target) {
+            return Err(BundleError::Cycle { reference });
+        }
+
+        let mut value = self
+            .file(&target.0)?
+            .pointer(&target.1)
+            .cloned()
+            .ok_or(BundleError::NotFound { reference })?;
+        let file = target.0.clone();
+        self.inlining.push(target);
+        self.resolve(&mut value, &file)?;
+        self.inlining.pop();
+
+        Ok(value)
+    }
+
+    fn reference(&mut self, file: &str, reference: &str) -> Result<Resolved, BundleError> {
+        let target = self.target(file, reference)?;
+        if target.0 == self.root {
+            return Ok(Resolved::Ref(format!("#{}", target.1)));
+        }
+        if let Some(component) = self.components.get(&target) {
+            return Ok(Resolved::Ref(component.clone()));
+        }
+        if let Some(component) = self.components.get(&(target.0.clone(), String::new())) {
+            return Ok(Resolved::Ref(format!("{component}{}", target.1)));
+        }
+
+        self.inline(target).map(Resolved::Inline)
+    }
+
+    /// Resolve discriminator mapping value within `file`. Values naming a schema of the root
+    /// document are left as is and references must resolve to a component of the root document
+    /// since mapping values cannot be inlined.
+    fn resolve_mapping(&mut self, reference: &mut String, file: &str) -> Result<(), BundleError> {
+        let is_schema_name = self.files[&self.root]
+            .pointer("/components/schemas")
+            .and_then(Value::as_object)
+            .is_some_and(|schemas| schemas.contains_key(reference.as_str()));
+        if is_schema_name || (file == self.root && reference.starts_with('#')) {
+            return Ok(());
+        }
+
+        match self.reference(file, reference)? {
+            Resolved::Ref(resolved) => *reference = resolved,
+            Resolved::Inline(_) => {
+                return Err(BundleError::Unsupported {
+                    reference: reference.clone(),
+                })
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Resolve references of a value within `file`. Local references of the root document are
+    /// left as is and everything else is either rewritten to local reference of the root
+    /// document or inlined.
+    fn resolve(&mut self, value: &mut Value, file: &str) -> Result<(), BundleError> {
+        match value {
+            Value::Object(object) => {
+                for value in object.values_mut() {
+                    self.resolve(value, file)?;
+                }
+                if let Some(Value::Object(mapping)) = object
+                    .get_mut("discriminator")
+                    .and_then(|discriminator| discriminator.get_mut("mapping"))
+                {
+                    for reference in mapping.values_mut() {
+                        if let Value::String(reference) = reference {
+                            self.resolve_mapping(reference, file)?;
+                        }
+                    }
+                }
+
+                let reference = match object.get("$ref") {
+                    Some(Value::String(reference))
+                        if file != self.root || !reference.starts_with('#') =>
+                    {
+                        reference.clone()
+                    }
+                    _ => return Ok(()),
+                };
+                match self.reference(file, &reference)? {
+                    Resolved::Ref(reference) => {
+                        object.insert("$ref".to_string(), Value::String(reference));
+                    }
+                    Resolved::Inline(Value::Object(inlined)) => {
+                        object.remove("$ref");
+                        for (key, value) in inlined {
+                            object.entry(key).or_insert(value);
+                        }
+                    }
+                    Resolved::Inline(inlined) => *value = inlined,
+                }
+            }
+            Value::Array(values) => {
+                for value in values {
+                    self.resolve(value, file)?;
+                }
+            }
+            _ => (),
+        }
+
+        Ok(())
+    }
+}
+
+fn bundle<L: FnMut(&str) -> Result<Value, BundleError>>(
+    root: &str,
+    load: L,
+) -> Result<OpenApi, BundleError> {
+    let mut bundler = Bundler {
+        load,
+        root: root.to_string(),
+        files: BTreeMap::new(),
+        components: BTreeMap::new(),
+        inlining: Vec::new(),
+    };
+    let mut document = bundler.file(root)?.clone();
+
+    // components defined in other files are registered first so references to them can be
+    // rewritten to local references instead of inlining the component to every usage
+    let mut components = Vec::new();
+    if let Some(Value::Object(kinds)) = document.get("components") {
+        for (kind, items) in kinds {
+            for (name, item) in items.as_object().into_iter().flatten() {
+                if let Some(Value::String(reference)) = item.get("$ref") {
+                    if !reference.starts_with('#') {
+                        let target = bundler.target(root, reference)?;
+                        let location = format!("#/components/{kind}/{}", escape(name));
+                        bundler.components.insert(target.clone(), location);
+                        components.push((format!("/components/{kind}/{}", escape(name)), target));
+                    }
+                }
+            }
+        }
+    }
+    for (location, target) in components {
+        let value = bundler.inline(target)?;
+        if let Some(component) = document.pointer_mut(&location) {
+            if let Value::Object(component) = component {
+                component.remove("$ref");
+            }
+            match (component, value) {
+                (Value::Object(component), Value::Object(value)) => {
+                    for (key, value) in value {
+                        component.entry(key).or_insert(value);
+                    }
+                }
+                (component, value) => *component = value,
+            }
+        }
+    }
+
+    bundler.resolve(&mut document, root)?;
+
+    serde_json::from_value(document).map_err(|error| BundleError::InvalidDocument {
+        reason: error.to_string(),
+    })
+}
+
+pub(super) fn bundle_files(root: &Path) -> Result<OpenApi, BundleError> {
+    let dir = root.parent().unwrap_or(Path::new(""));
+    let root = root
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    bundle(&root, |file| {
+        let content = std::fs::read_to_string(dir.join(file)).map_err(|error| BundleError::Io {
+            file: file.to_string(),
+            error,
+        })?;
+        parse(file, &content)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::openapi::path::{OperationBuilder, ParameterBuilder, ParameterIn};
+    use crate::openapi::{
+        ArrayBuilder, ComponentsBuilder, Content, Discriminator, HttpMethod, Info, ObjectBuilder,
+        OneOfBuilder, OpenApiBuilder, PathItem, PathsBuilder, Ref, ResponseBuilder, Type,
+    };
+
+    use super::*;
+
+    fn openapi() -> OpenApi {
+        OpenApiBuilder::new()
+            .info(Info::new("api", "1.0.0"))
+            .paths(
+                PathsBuilder::new()
+                    .path(
+                        "/pets",
+                        PathItem::new(
+                            HttpMethod::Get,
+                            OperationBuilder::new()
+                                .response("200", Ref::from_response_name("Pets")),
+                        ),
+                    )
+                    .path(
+                        "/pets/{id}",
+                        PathItem::new(
+                            HttpMethod::Get,
+                            OperationBuilder::new()
+                                .parameter(
+                                    ParameterBuilder::new()
+                                        .name("id")
+                                        .parameter_in(ParameterIn::Path),
+                                )
+                                .response(
+                                    "200",
+                                    ResponseBuilder::new().content(
+                                        "application/json",
+                                        Content::new(Some(Ref::from_schema_name("Pet"))),
+                                    ),
+                                ),
+                        ),
+                    ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Pet",
+                        ObjectBuilder::new()
+                            .property("name", ObjectBuilder::new().schema_type(Type::String))
+                            .property("parent", Ref::from_schema_name("Pet"))
+                            .property(
+                                "tags",
+                                ArrayBuilder::new().items(Ref::from_schema_name("Tag")),
+                            ),
+                    )
+                    .schema("Tag", ObjectBuilder::new().schema_type(Type::String))
+                    .schema(
+                        "Animal",
+                        OneOfBuilder::new()
+                            .item(Ref::from_schema_name("Pet"))
+                            .item(Ref::from_schema_name("Tag"))
+                            .discriminator(Some(Discriminator::with_mapping(
+                                "kind",
+                                [("pet", "#/components/schemas/Pet"), ("tag", "Tag")],
+                            ))),
+                    )
+                    .response(
+                        "Pets",
+                        ResponseBuilder::new().content(
+                            "application/json",
+                            Content::new(Some(
+                                ArrayBuilder::new().items(Ref::from_schema_name("Pet")),
+                            )),
+                        ),
+                    )
+                    .build(),
+            ))
+            .build()
+    }
+
+    #[test]
+    fn split_and_bundle_openapi() {
+        let openapi = openapi();
+        let split = openapi.split(FileFormat::Json);
+
+        assert_eq!(
+            split.files.keys().collect::<Vec<_>>(),
+            [
+                "openapi.json",
+                "paths/pets.json",
+                "paths/pets_id.json",
+                "schemas/Animal.json",
+                "schemas/Pet.json",
+                "schemas/Tag.json"
+            ]
+        );
+        let root = &split.files["openapi.json"];
+        assert_eq!(
+            root.pointer("/paths/~1pets~1{id}"),
+            Some(&json!({"$ref": "paths/pets_id.json"}))
+        );
+        assert_eq!(
+            root.pointer("/components/schemas/Pet"),
+            Some(&json!({"$ref": "schemas/Pet.json"}))
+        );
+        assert_eq!(
+            root.pointer("/components/responses/Pets/content/application~1json/schema/items"),
+            Some(&json!({"$ref": "#/components/schemas/Pet"}))
+        );
+        assert_eq!(
+            split.files["schemas/Pet.json"].pointer("/properties/tags/items"),
+            Some(&json!({"$ref": "Tag.json"}))
+        );
+        assert_eq!(
+            split.files["schemas/Animal.json"].pointer("/discriminator/mapping"),
+            Some(&json!({"pet": "Pet.json", "tag": "Tag"}))
+        );
+        assert_eq!(
+            split.files["paths/pets.json"].pointer("/get/responses/200"),
+            Some(&json!({"$ref": "../openapi.json#/components/responses/Pets"}))
+        );
+        assert_eq!(
+            split.files["paths/pets_id.json"]
+                .pointer("/get/responses/200/content/application~1json/schema"),
+            Some(&json!({"$ref": "../schemas/Pet.json"}))
+        );
+
+        let bundled = split.bundle().expect("split document should bundle");
+        assert_eq!(
+            serde_json::to_value(bundled).unwrap(),
+            serde_json::to_value(openapi).unwrap()
+        );
+    }
+
+    #[test]
+    fn bundle_rejects_references_outside_of_root_directory() {
+        assert_eq!(
+            join("paths/pets.json", "../schemas/Pet.json").ok(),
+            Some("schemas/Pet.json".to_string())
+        );
+        assert!(matches!(
+            join("paths/pets.json", "../../schemas/Pet.json"),
+            Err(BundleError::OutsideRoot { reference }) if reference == "../../schemas/Pet.json"
+        ));
+    }
+
+    #[test]
+    fn bundle_openapi_from_files() {
+        let dir = std::env::temp_dir().join(format!("utoipa-bundle-{}", std::process::id()));
+        let openapi = openapi();
+        openapi
+            .split(FileFormat::Json)
+            .write(&dir)
+            .expect("split document should be written");
+
+        let bundled = OpenApi::bundle(dir.join("openapi.json"));
+        let missing = OpenApi::bundle(dir.join("missing.json"));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(bundled.expect("files should bundle")).unwrap(),
+            serde_json::to_value(openapi).unwrap()
+        );
+        assert!(matches!(missing, Err(BundleError::Io { file, .. }) if file == "missing.json"));
+    }
+
+    #[test]
+    fn bundle_inlines_external_values() {
+        let split = SplitOpenApi {
+            root: "openapi.json".to_string(),
+            format: FileFormat::Json,
+            files: BTreeMap::from_iter([
+                (
+                    "openapi.json".to_string(),
+                    json!({
+                        "openapi": "3.1.0",
+                        "info": {"title": "api", "version": "1.0.0"},
+                        "paths": {
+                            "/pets": {"$ref": "shared/pets.json#/item"},
+                            "/loop": {"$ref": "shared/loop.json"}
+                        }
+                    }),
+                ),
+                (
+                    "shared/pets.json".to_string(),
+                    json!({"item": {"get": {"responses": {"200": {"$ref": "#/ok"}}}}, "ok": {"description": "ok"}}),
+                ),
+                (
+                    "shared/loop.json".to_string(),
+                    json!({"$ref": "./loop.json"}),
+                ),
+            ]),
+        };
+
+        let error = split.bundle().expect_err("cyclic reference should fail");
+        assert!(
+            matches!(error, BundleError::Cycle { reference } if reference == "shared/loop.json#")
+        );
+
+        let mut split = split;
+        split.files.get_mut("openapi.json").unwrap()["paths"]
+            .as_object_mut()
+            .unwrap()
+            .remove("/loop");
+        let bundled = split.bundle().expect("document should bundle");
+        assert_eq!(
+            serde_json::to_value(bundled.paths).unwrap(),
+            json!({"/pets": {"get": {"responses": {"200": {"description": "ok"}}}}})
+        );
+    }
+}
@@ -0,0 +1,908 @@
+//! Implements semantic comparison of two [`OpenApi`] documents.
+//!
+//! Use [`OpenApi::diff`] to compare an old document to a new one. Each found [`Change`] is
+//! classified as breaking or non-breaking from the API consumer point of view, e.g. a removed
+//! operation or a request parameter that became required breaks existing clients while a new
+//! optional response property does not.
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use super::path::{HttpMethod, Operation, Parameter, ParameterIn, PathItem};
+use super::schema::SchemaType;
+use super::{Content, OpenApi, RefOr, Required, Schema};
+
+/// Report of all the [`Change`]s found between two [`OpenApi`] documents.
+///
+/// The report can be printed with [`Display`] or serialized e.g. to JSON.
+#[derive(Serialize, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct DiffReport {
+    /// All changes found between the documents.
+    pub changes: Vec<Change>,
+}
+
+impl DiffReport {
+    /// Check whether the report contains any breaking changes.
+    pub fn is_breaking(&self) -> bool {
+        self.changes.iter().any(|change| change.breaking)
+    }
+
+    /// Get iterator over breaking [`Change`]s of the report.
+    pub fn breaking(&self) -> impl Iterator<Item = &Change> {
+        self.changes.iter().filter(|change| change.breaking)
+    }
+}
+
+impl Display for DiffReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Single change between two [`OpenApi`] documents.
+#[derive(Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[serde(rename_all = "camelCase")]
+pub struct Change {
+    /// Path of the changed operation e.g. `/pets/{id}` or name of the webhook if
+    /// [`Change::webhook`] is `true`.
+    pub path: String,
+    /// Whether the change is in a webhook instead of a path.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub webhook: bool,
+    /// [`HttpMethod`] of the changed operation. `None` if the whole path was added or removed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<HttpMethod>,
+    /// Location of the change within the operation e.g. `parameters.query.limit` or
+    /// `responses.200.application/json.name`. Empty if the whole operation was changed.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub location: String,
+    /// What has changed.
+    pub kind: ChangeKind,
+    /// Whether the change breaks existing API consumers.
+    pub breaking: bool,
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.breaking {
+            write!(f, "[breaking] ")?;
+        }
+        if let Some(method) = &self.method {
+            write!(f, "{} ", method.as_str().to_uppercase())?;
+        }
+        if self.webhook {
+            write!(f, "webhook ")?;
+        }
+        write!(f, "{}", self.path)?;
+        if !self.location.is_empty() {
+            write!(f, " {}", self.location)?;
+        }
+        write!(f, ": {}", self.kind)
+    }
+}
+
+/// Kind of the [`Change`].
+#[non_exhaustive]
+#[derive(Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ChangeKind {
+    /// Path was removed.
+    PathRemoved,
+    /// Path was added.
+    PathAdded,
+    /// Webhook was removed.
+    WebhookRemoved,
+    /// Webhook was added.
+    WebhookAdded,
+    /// Operation was removed.
+    OperationRemoved,
+    /// Operation was added.
+    OperationAdded,
+    /// Parameter was removed.
+    ParameterRemoved,
+    /// Parameter was added.
+    ParameterAdded {
+        /// Whether the added parameter is required.
+        required: bool,
+    },
+    /// Optional parameter became required.
+    ParameterRequired,
+    /// Required parameter became optional.
+    ParameterOptional,
+    /// Request body was removed.
+    RequestBodyRemoved,
+    /// Request body was added.
+    RequestBodyAdded {
+        /// Whether the added request body is required.
+        required: bool,
+    },
+    /// Optional request body became required.
+    RequestBodyRequired,
+    /// Required request body became optional.
+    RequestBodyOptional,
+    /// Response status was removed.
+    ResponseRemoved,
+    /// Response status was added.
+    ResponseAdded,
+    /// Media type of request body or response was removed.
+    MediaTypeRemoved,
+    /// Media type of request body or response was added.
+    MediaTypeAdded,
+    /// Type of the schema was changed.
+    TypeChanged {
+        /// Old type of the schema.
+        from: String,
+        /// New type of the schema.
+        to: String,
+    },
+    /// Values were removed from the `enum` of the schema.
+    EnumValuesRemoved {
+        /// Removed values.
+        values: Vec<Value>,
+    },
+    /// Values were added to the `enum` of the schema.
+    EnumValuesAdded {
+        /// Added values.
+        values: Vec<Value>,
+    },
+    /// Property was removed from the object schema.
+    PropertyRemoved,
+    /// Property was added to the object schema.
+    PropertyAdded {
+        /// Whether the added property is required.
+        required: bool,
+    },
+    /// Optional property became required.
+    PropertyRequired,
+    /// Required property became optional.
+    PropertyOptional,
+}
+
+impl Display for ChangeKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let values = |values: &[Value]| {
+            values
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let required = |required: &bool| if *required { "required" } else { "optional" };
+
+        match self {
+            Self::PathRemoved => write!(f, "path removed"),
+            Self::PathAdded => write!(f, "path added"),
+            Self::WebhookRemoved => write!(f, "webhook removed"),
+            Self::WebhookAdded => write!(f, "webhook added"),
+            Self::OperationRemoved => write!(f, "operation removed"),
+            Self::OperationAdded => write!(f, "operation added"),
+            Self::ParameterRemoved => write!(f, "parameter removed"),
+            Self::ParameterAdded { required: r } => write!(f, "{} parameter added", required(r)),
+            Self::ParameterRequired => write!(f, "parameter became required"),
+            Self::ParameterOptional => write!(f, "parameter became optional"),
+            Self::RequestBodyRemoved => write!(f, "request body removed"),
+            Self::RequestBodyAdded { required: r } => {
+                write!(f, "{} request body added", required(r))
+            }
+            Self::RequestBodyRequired => write!(f, "request body became required"),
+            Self::RequestBodyOptional => write!(f, "request body became optional"),
+            Self::ResponseRemoved => write!(f, "response removed"),
+            Self::ResponseAdded => write!(f, "response added"),
+            Self::MediaTypeRemoved => write!(f, "media type removed"),
+            Self::MediaTypeAdded => write!(f, "media type added"),
+            Self::TypeChanged { from, to } => write!(f, "type changed from {from} to {to}"),
+            Self::EnumValuesRemoved { values: v } => {
+                write!(f, "enum values removed: {}", values(v))
+            }
+            Self::EnumValuesAdded { values: v } => write!(f, "enum values added: {}", values(v)),
+            Self::PropertyRemoved => write!(f, "property removed"),
+            Self::PropertyAdded { required: r } => write!(f, "{} property added", required(r)),
+            Self::PropertyRequired => write!(f, "property became required"),
+            Self::PropertyOptional => write!(f, "property became optional"),
+        }
+    }
+}
+
+/// Direction of the data described by a schema. Narrowing a request schema breaks clients
+/// sending requests while widening a response schema breaks clients reading responses.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Request,
+    Response,
+}
+
+struct Differ<'a> {
+    old: &'a OpenApi,
+    new: &'a OpenApi,
+    changes: Vec<Change>,
+    path: &'a str,
+    webhook: bool,
+    method: Option<HttpMethod>,
+    /// References currently being compared to break from recursive schemas.
+    visited: Vec<(String, String)>,
+}
+
+pub(super) fn diff(old: &OpenApi, new: &OpenApi) -> DiffReport {
+    let mut changes = Vec::new();
+
+    diff_path_items(old, new, &paths(old), &paths(new), false, &mut changes);
+    diff_path_items(old, new, &webhooks(old), &webhooks(new), true, &mut changes);
+
+    DiffReport { changes }
+}
+
+fn paths(openapi: &OpenApi) -> Vec<(&String, &PathItem)> {
+    openapi.paths.paths.iter().collect()
+}
+
+/// Get webhooks of the `openapi` with [`PathItem`] references resolved.
+fn webhooks(openapi: &OpenApi) -> Vec<(&String, &PathItem)> {
+    openapi
+        .webhooks
+        .iter()
+        .filter_map(|(name, item)| Some((name, openapi.resolve(item).ok()?)))
+        .collect()
+}
+
+/// Diff `(name, item)` pairs of [`PathItem`]s of either paths or webhooks of the documents.
+fn diff_path_items<'a>(
+    old: &'a OpenApi,
+    new: &'a OpenApi,
+    old_items: &[(&'a String, &'a PathItem)],
+    new_items: &[(&'a String, &'a PathItem)],
+    webhook: bool,
+    changes: &mut Vec<Change>,
+) {
+    let find = |items: &[(&'a String, &'a PathItem)], path: &str| {
+        items
+            .iter()
+            .find(|(other, _)| *other == path)
+            .map(|(_, item)| *item)
+    };
+    let (removed, added) = if webhook {
+        (ChangeKind::WebhookRemoved, ChangeKind::WebhookAdded)
+    } else {
+        (ChangeKind::PathRemoved, ChangeKind::PathAdded)
+    };
+
+    for (path, old_item) in old_items {
+        let Some(new_item) = find(new_items, path) else {
+            changes.push(Change {
+                path: path.to_string(),
+                webhook,
+                method: None,
+                location: String::new(),
+                kind: removed.clone(),
+                breaking: true,
+            });
+            continue;
+        };
+
+        let mut differ = Differ {
+            old,
+            new,
+            changes: Vec::new(),
+            path,
+            webhook,
+            method: None,
+            visited: Vec::new(),
+        };
+        differ.diff_path_item(old_item, new_item);
+        changes.append(&mut differ.changes);
+    }
+
+    changes.extend(
+        new_items
+            .iter()
+            .filter(|(path, _)| find(old_items, path).is_none())
+            .map(|(path, _)| Change {
+                path: path.to_string(),
+                webhook,
+                method: None,
+                location: String::new(),
+                kind: added.clone(),
+                breaking: false,
+            }),
+    );
+}
+
+impl<'a> Differ<'a> {
+    fn push(&mut self, location: &str, kind: ChangeKind, breaking: bool) {
+        self.changes.push(Change {
+            path: self.path.to_string(),
+            webhook: self.webhook,
+            method: self.method.clone(),
+            location: location.to_string(),
+            kind,
+            breaking,
+        });
+    }
+
+    fn diff_path_item(&mut self, old_item: &'a PathItem, new_item: &'a PathItem) {
+        for (method, old_operation) in old_item.operations() {
+            self.method = Some(method.clone());
+            match new_item.operation(&method) {
+                Some(new_operation) => {
+                    let old_parameters = self.parameters(self.old, old_item, old_operation);
+                    let new_parameters = self.parameters(self.new, new_item, new_operation);
+                    self.diff_parameters(&old_parameters, &new_parameters);
+                    self.diff_operation(old_operation, new_operation);
+                }
+                None => self.push("", ChangeKind::OperationRemoved, true),
+            }
+        }
+
+        for (method, _) in new_item.operations() {
+            if old_item.operation(&method).is_none() {
+                self.method = Some(method);
+                self.push("", ChangeKind::OperationAdded, false);
+            }
+        }
+    }
+
+    /// Get resolved parameters of the [`Operation`] including the [`PathItem`] parameters not
+    /// overridden by the [`Operation`].
+    fn parameters(
+        &self,
+        openapi: &'a OpenApi,
+        item: &'a PathItem,
+        operation: &'a Operation,
+    ) -> Vec<&'a Parameter> {
+        let resolve = |parameter: &'a RefOr<Parameter>| openapi.resolve(parameter).ok();
+        let operation_parameters = operation
+            .parameters
+            .iter()
+            .flatten()
+            .filter_map(resolve)
+            .collect::<Vec<_>>();
+
+        item.parameters
+            .iter()
+            .flatten()
+            .filter_map(resolve)
+            .filter(|parameter| {
+                !operation_parameters.iter().any(|operation_parameter| {
+                    operation_parameter.name == parameter.name
+                        && operation_parameter.parameter_in == parameter.parameter_in
+                })
+            })
+            .chain(operation_parameters.iter().copied())
+            .collect()
+    }
+
+    fn diff_parameters(&mut self, old: &[&'a Parameter], new: &[&'a Parameter]) {
+        let location = |parameter: &Parameter| {
+            let parameter_in = match parameter.parameter_in {
+                ParameterIn::Query => "query",
+                ParameterIn::Path => "path",
+                ParameterIn::Header => "header",
+                ParameterIn::Cookie => "cookie",
+            };
+            format!("parameters.{parameter_in}.{}", parameter.name)
+        };
+        let find = |parameters: &[&'a Parameter], parameter: &Parameter| {
+            parameters
+                .iter()
+                .find(|other| {
+                    other.name == parameter.name && other.parameter_in == parameter.parameter_in
+                })
+                .copied()
+        };
+
+        for old_parameter in old {
+            let location = location(old_parameter);
+            let Some(new_parameter) = find(new, old_parameter) else {
+                self.push(&location, ChangeKind::ParameterRemoved, false);
+                continue;
+            };
+
+            match (&old_parameter.required, &new_parameter.required) {
+                (Required::False, Required::True) => {
+                    self.push(&location, ChangeKind::ParameterRequired, true)
+                }
+                (Required::True, Required::False) => {
+                    self.push(&location, ChangeKind::ParameterOptional, false)
+                }
+                _ => (),
+            }
+
+            if let (Some(old_schema), Some(new_schema)) =
+                (&old_parameter.schema, &new_parameter.schema)
+            {
+                self.diff_schema(&location, old_schema, new_schema, Direction::Request);
+            }
+        }
+
+        for new_parameter in new {
+            if find(old, new_parameter).is_none() {
+                let required = new_parameter.required == Required::True;
+                self.push(
+                    &location(new_parameter),
+                    ChangeKind::ParameterAdded { required },
+                    required,
+                );
+            }
+        }
+    }
+
+    fn diff_operation(&mut self, old: &'a Operation, new: &'a Operation) {
+        let old_body = old
+            .request_body
+            .as_ref()
+            .and_then(|body| self.old.resolve(body).ok());
+        let new_body = new
+            .request_body
+            .as_ref()
+            .and_then(|body| self.new.resolve(body).ok());
+        let is_required = |required: &Option<Required>| *required == Some(Required::True);
+
+        match (old_body, new_body) {
+            (Some(old_body), Some(new_body)) => {
+                match (
+                    is_required(&old_body.required),
+                    is_required(&new_body.required),
+                ) {
+                    (false, true) => {
+                        self.push("requestBody", ChangeKind::RequestBodyRequired, true)
+                    }
+                    (true, false) => {
+                        self.push("requestBody", ChangeKind::RequestBodyOptional, false)
+                    }
+                    _ => (),
+                }
+                self.diff_content(
+                    "requestBody",
+                    old_body.content.iter(),
+                    new_body.content.iter(),
+                    Direction::Request,
+                );
+            }
+            (Some(_), None) => self.push("requestBody", ChangeKind::RequestBodyRemoved, false),
+            (None, Some(new_body)) => {
+                let required = is_required(&new_body.required);
+                self.push(
+                    "requestBody",
+                    ChangeKind::RequestBodyAdded { required },
+                    required,
+                );
+            }
+            (None, None) => (),
+        }
+
+        for (status, old_response) in &old.responses.responses {
+            let location = format!("responses.{status}");
+            let Some(new_response) = new.responses.responses.get(status) else {
+                self.push(&location, ChangeKind::ResponseRemoved, true);
+                continue;
+            };
+
+            if let (Ok(old_response), Ok(new_response)) = (
+                self.old.resolve(old_response),
+                self.new.resolve(new_response),
+            ) {
+                self.diff_content(
+                    &location,
+                    old_response.content.iter(),
+                    new_response.content.iter(),
+                    Direction::Response,
+                );
+            }
+        }
+
+        for status in new.responses.responses.keys() {
+            if !old.responses.responses.contains_key(status) {
+                self.push(
+                    &format!("responses.{status}"),
+                    ChangeKind::ResponseAdded,
+                    false,
+                );
+            }
+        }
+    }
+
+    fn diff_content(
+        &mut self,
+        location: &str,
+        old: impl Iterator<Item = (&'a String, &'a Content)>,
+        new: impl Iterator<Item = (&'a String, &'a Content)> + Clone,
+        direction: Direction,
+    ) {
+        let old = old.collect::<Vec<_>>();
+
+        for (media_type, old_content) in &old {
+            let location = format!("{location}.{media_type}");
+            let Some((_, new_content)) = new
+                .clone()
+                .find(|(new_media_type, _)| new_media_type == media_type)
+            else {
+                self.push(&location, ChangeKind::MediaTypeRemoved, true);
+                continue;
+            };
+
+            if let (Some(old_schema), Some(new_schema)) = (&old_content.schema, &new_content.schema)
+            {
+                self.diff_schema(&location, old_schema, new_schema, direction);
+            }
+        }
+
+        for (media_type, _) in new {
+            if !old
+                .iter()
+                .any(|(old_media_type, _)| *old_media_type == media_type)
+            {
+                self.push(
+                    &format!("{location}.{media_type}"),
+                    ChangeKind::MediaTypeAdded,
+                    false,
+                );
+            }
+        }
+    }
+
+    fn diff_schema(
+        &mut self,
+        location: &str,
+        old: &'a RefOr<Schema>,
+        new: &'a RefOr<Schema>,
+        direction: Direction,
+    ) {
+        let references = match (old, new) {
+            (RefOr::Ref(old), RefOr::Ref(new)) => {
+                Some((old.ref_location.clone(), new.ref_location.clone()))
+            }
+            _ => None,
+        };
+        if let Some(references) = &references {
+            if self.visited.contains(references) {
+                return;
+            }
+            self.visited.push(references.clone());
+        }
+
+        if let (Ok(old), Ok(new)) = (self.old.resolve(old), self.new.resolve(new)) {
+            self.diff_resolved_schema(location, old, new, direction);
+        }
+
+        if references.is_some() {
+            self.visited.pop();
+        }
+    }
+
+    fn diff_resolved_schema(
+        &mut self,
+        location: &str,
+        old: &'a Schema,
+        new: &'a Schema,
+        direction: Direction,
+    ) {
+        // widening a request or narrowing a response is safe for existing consumers
+        let is_breaking = |widened: bool| match direction {
+            Direction::Request => !widened,
+            Direction::Response => widened,
+        };
+
+        match (old, new) {
+            (Schema::Object(old), Schema::Object(new)) => {
+                let old_types = types(&old.schema_type);
+                let new_types = types(&new.schema_type);
+                if old_types != new_types {
+                    let widened = match (&old_types, &new_types) {
+                        (_, None) => true,
+                        (None, _) => false,
+                        (Some(old_types), Some(new_types)) => old_types.is_subset(new_types),
+                    };
+                    let narrowed = match (&old_types, &new_types) {
+                        (Some(old_types), Some(new_types)) => new_types.is_subset(old_types),
+                        (None, _) => true,
+                        (_, None) => false,
+                    };
+                    let breaking = match direction {
+                        Direction::Request => !widened,
+                        Direction::Response => !narrowed,
+                    };
+                    self.push(
+                        location,
+                        ChangeKind::TypeChanged {
+                            from: type_name(&old.schema_type),
+                            to: type_name(&new.schema_type),
+                        },
+                        breaking,
+                    );
+                }
+
+                if let (Some(old_values), Some(new_values)) = (&old.enum_values, &new.enum_values) {
+                    let removed = old_values
+                        .iter()
+                        .filter(|value| !new_values.contains(value))
+                        .cloned()
+                        .collect::<Vec<_>>();
+                    let added = new_values
+                        .iter()
+                        .filter(|value| !old_values.contains(value))
+                        .cloned()
+                        .collect::<Vec<_>>();
+                    if !removed.is_empty() {
+                        self.push(
+                            location,
+                            ChangeKind::EnumValuesRemoved { values: removed },
+                            is_breaking(false),
+                        );
+                    }
+                    if !added.is_empty() {
+                        self.push(
+                            location,
+                            ChangeKind::EnumValuesAdded { values: added },
+                            is_breaking(true),
+                        );
+                    }
+                }
+
+                for (name, old_property) in &old.properties {
+                    let property_location = format!("{location}.{name}");
+                    let Some(new_property) = new.properties.get(name) else {
+                        self.push(
+                            &property_location,
+                            ChangeKind::PropertyRemoved,
+                            direction == Direction::Response,
+                        );
+                        continue;
+                    };
+
+                    match (old.required.contains(name), new.required.contains(name)) {
+                        (false, true) => self.push(
+                            &property_location,
+                            ChangeKind::PropertyRequired,
+                            is_breaking(false),
+                        ),
+                        (true, false) => self.push(
+                            &property_location,
+                            ChangeKind::PropertyOptional,
+                            is_breaking(true),
+                        ),
+                        _ => (),
+                    }
+                    self.diff_schema(&property_location, old_property, new_property, direction);
+                }
+
+                for name in new.properties.keys() {
+                    if !old.properties.contains_key(name) {
+                        let required = new.required.contains(name);
+                        self.push(
+                            &format!("{location}.{name}"),
+                            ChangeKind::PropertyAdded { required },
+                            required && direction == Direction::Request,
+                        );
+                    }
+                }
+            }
+            (Schema::Array(old), Schema::Array(new)) => {
+                if let (
+                    super::schema::ArrayItems::RefOrSchema(old_items),
+                    super::schema::ArrayItems::RefOrSchema(new_items),
+                ) = (&old.items, &new.items)
+                {
+                    self.diff_schema(&format!("{location}[]"), old_items, new_items, direction);
+                }
+            }
+            (Schema::OneOf(old), Schema::OneOf(new)) => {
+                self.diff_schema_items(location, &old.items, &new.items, direction)
+            }
+            (Schema::AllOf(old), Schema::AllOf(new)) => {
+                self.diff_schema_items(location, &old.items, &new.items, direction)
+            }
+            (Schema::AnyOf(old), Schema::AnyOf(new)) => {
+                self.diff_schema_items(location, &old.items, &new.items, direction)
+            }
+            (old, new) if std::mem::discriminant(old) != std::mem::discriminant(new) => {
+                self.push(
+                    location,
+                    ChangeKind::TypeChanged {
+                        from: schema_name(old),
+                        to: schema_name(new),
+                    },
+                    true,
+                );
+            }
+            _ => (),
+        }
+    }
+
+    fn diff_schema_items(
+        &mut self,
+        location: &str,
+        old: &'a [RefOr<Schema>],
+        new: &'a [RefOr<Schema>],
+        direction: Direction,
+    ) {
+        for (index, (old, new)) in old.iter().zip(new).enumerate() {
+            self.diff_schema(&format!("{location}[{index}]"), old, new, direction);
+        }
+    }
+}
+
+/// Get set of type names of the [`SchemaType`]. `None` means any type is allowed.
+fn types(schema_type: &SchemaType) -> Option<BTreeSet<String>> {
+    let name = |schema_type| {
+        serde_json::to_value(schema_type)
+            .ok()
+            .and_then(|value| value.as_str().map(ToString::to_string))
+    };
+    match schema_type {
+        SchemaType::Type(schema_type) => Some(name(schema_type).into_iter().collect()),
+        SchemaType::Array(schema_types) => Some(schema_types.iter().filter_map(name).collect()),
+        SchemaType::AnyValue => None,
+    }
+}
+
+fn type_name(schema_type: &SchemaType) -> String {
+    match types(schema_type) {
+        Some(types) if types.len() == 1 => types.into_iter().collect(),
+        Some(types) => format!("[{}]", types.into_iter().collect::<Vec<_>>().join(", ")),
+        None => String::from("any"),
+    }
+}
+
+fn schema_name(schema: &Schema) -> String {
+    match schema {
+        Schema::Array(_) => String::from("array"),
+        Schema::Object(object) => type_name(&object.schema_type),
+        Schema::OneOf(_) => String::from("oneOf"),
+        Schema::AllOf(_) => String::from("allOf"),
+        Schema::AnyOf(_) => String::from("anyOf"),
+        Schema::Not(_) => String::from("not"),
+        Schema::Bool(value) => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::openapi::path::{OperationBuilder, ParameterBuilder};
+    use crate::openapi::{
+        ComponentsBuilder, ContentBuilder, HttpMethod, ObjectBuilder, OpenApiBuilder, PathItem,
+        PathsBuilder, Ref, ResponseBuilder, Type,
+    };
+
+    use super::*;
+
+    fn openapi(pet: ObjectBuilder, limit_required: Required, delete_pet: bool) -> OpenApi {
+        let response = ResponseBuilder::new().description("Pets").content(
+            "application/json",
+            ContentBuilder::new()
+                .schema(Some(Ref::from_schema_name("Pet")))
+                .build(),
+        );
+
+        let mut paths = PathsBuilder::new().path(
+            "/pets",
+            PathItem::new(
+                HttpMethod::Get,
+                OperationBuilder::new()
+                    .parameter(
+                        ParameterBuilder::new()
+                            .name("limit")
+                            .parameter_in(ParameterIn::Query)
+                            .required(limit_required)
+                            .schema(Some(ObjectBuilder::new().schema_type(Type::Integer))),
+                    )
+                    .response("200", response),
+            ),
+        );
+        if delete_pet {
+            paths = paths.path(
+                "/pets/{id}",
+                PathItem::new(HttpMethod::Delete, OperationBuilder::new()),
+            );
+        }
+
+        OpenApiBuilder::new()
+            .paths(paths)
+            .components(Some(ComponentsBuilder::new().schema("Pet", pet).build()))
+            .build()
+    }
+
+    #[test]
+    fn diff_classifies_breaking_changes() {
+        let old = openapi(
+            ObjectBuilder::new()
+                .property("name", ObjectBuilder::new().schema_type(Type::String))
+                .property("age", ObjectBuilder::new().schema_type(Type::Integer))
+                .required("name")
+                .property(
+                    "kind",
+                    ObjectBuilder::new()
+                        .schema_type(Type::String)
+                        .enum_values(Some(["cat", "dog"])),
+                ),
+            Required::False,
+            true,
+        );
+        let new = openapi(
+            ObjectBuilder::new()
+                .property("name", ObjectBuilder::new().schema_type(Type::Integer))
+                .property(
+                    "kind",
+                    ObjectBuilder::new()
+                        .schema_type(Type::String)
+                        .enum_values(Some(["cat", "dog", "bird"])),
+                )
+                .property("color", ObjectBuilder::new().schema_type(Type::String)),
+            Required::True,
+            false,
+        );
+
+        let report = old.diff(&new);
+
+        assert!(report.is_breaking());
+        // order of the changes depends on the order of the properties with `preserve_order`
+        let mut changes = report
+            .to_string()
+            .lines()
+            .map(String::from)
+            .collect::<Vec<_>>();
+        changes.sort();
+        assert_eq!(
+            changes,
+            [
+                "GET /pets responses.200.application/json.color: optional property added",
+                "[breaking] /pets/{id}: path removed",
+                "[breaking] GET /pets parameters.query.limit: parameter became required",
+                "[breaking] GET /pets responses.200.application/json.age: property removed",
+                "[breaking] GET /pets responses.200.application/json.kind: enum values added: \"bird\"",
+                "[breaking] GET /pets responses.200.application/json.name: property became optional",
+                "[breaking] GET /pets responses.200.application/json.name: type changed from string to integer",
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_classifies_webhook_changes() {
+        let old = OpenApiBuilder::new()
+            .webhook(
+                "newPet",
+                PathItem::new(HttpMethod::Post, OperationBuilder::new()),
+            )
+            .webhook(
+                "petRemoved",
+                PathItem::new(HttpMethod::Delete, OperationBuilder::new()),
+            )
+            .build();
+        let new = OpenApiBuilder::new()
+            .webhook(
+                "newPet",
+                PathItem::new(HttpMethod::Put, OperationBuilder::new()),
+            )
+            .webhook(
+                "petSold",
+                PathItem::new(HttpMethod::Post, OperationBuilder::new()),
+            )
+            .build();
+
+        let report = old.diff(&new);
+
+        assert!(report.is_breaking());
+        assert_eq!(
+            report.to_string(),
+            "\
+[breaking] POST webhook newPet: operation removed
+PUT webhook newPet: operation added
+[breaking] webhook petRemoved: webhook removed
+webhook petSold: webhook added
+"
+        );
+    }
+
+    #[test]
+    fn diff_of_same_document_is_empty() {
+        let old = openapi(ObjectBuilder::new(), Required::False, true);
+
+        assert_eq!(old.diff(&old), DiffReport::default());
+    }
+}
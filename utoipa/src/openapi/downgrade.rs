@@ -0,0 +1,796 @@
+//! Implements conversion of OpenAPI 3.1 [`OpenApi`] document to OpenAPI 3.0 document.
+//!
+//! Use [`OpenApi::to_openapi_30`] to convert the document for tools that only support
+//! OpenAPI 3.0.x. The conversion maps the JSON Schema 2020-12 keywords of OpenAPI 3.1 to their
+//! closest OpenAPI 3.0 equivalents and reports everything that cannot be converted as
+//! [`DowngradeWarning`]s.
+use std::fmt::{Display, Formatter};
+
+use serde_json::{json, Map, Value};
+
+use super::validation::escape;
+use super::OpenApi;
+
+/// OpenAPI version of the converted document.
+const OPENAPI_30: &str = "3.0.3";
+
+/// JSON Schema keywords not supported by OpenAPI 3.0 that are removed from schemas.
+const UNSUPPORTED_KEYWORDS: [&str; 16] = [
+    "$id",
+    "$anchor",
+    "$defs",
+    "$schema",
+    "$comment",
+    "if",
+    "then",
+    "else",
+    "dependentRequired",
+    "dependentSchemas",
+    "unevaluatedProperties",
+    "unevaluatedItems",
+    "contains",
+    "minContains",
+    "maxContains",
+    "propertyNames",
+];
+
+/// OpenAPI 3.0 document converted from [`OpenApi`] with [`OpenApi::to_openapi_30`].
+///
+/// The document is represented as [`serde_json::Value`] since OpenAPI 3.0 schemas cannot be
+/// expressed with the OpenAPI 3.1 types of this crate.
+#[non_exhaustive]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct OpenApiV30 {
+    /// The converted OpenAPI 3.0 document.
+    pub document: Value,
+
+    /// Warnings of parts of the document that could not be converted and were either removed or
+    /// converted losing some of their meaning.
+    pub warnings: Vec<DowngradeWarning>,
+}
+
+impl OpenApiV30 {
+    /// Converts the [`OpenApiV30::document`] to JSON String. This method essentially calls [`serde_json::to_string`] method.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(&self.document)
+    }
+
+    /// Converts the [`OpenApiV30::document`] to pretty JSON String. This method essentially calls [`serde_json::to_string_pretty`] method.
+    pub fn to_pretty_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(&self.document)
+    }
+
+    /// Converts the [`OpenApiV30::document`] to YAML String. This method essentially calls [`yaml_serde::to_string`].
+    #[cfg(feature = "yaml")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "yaml")))]
+    pub fn to_yaml(&self) -> Result<String, yaml_serde::Error> {
+        yaml_serde::to_string(&self.document)
+    }
+}
+
+/// Warning of a part of the [`OpenApi`] document that could not be fully converted to
+/// OpenAPI 3.0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DowngradeWarning {
+    /// Location of the element in the original document as [JSON Pointer][json_pointer]
+    /// prefixed with `#`.
+    ///
+    /// [json_pointer]: https://datatracker.ietf.org/doc/html/rfc6901
+    pub location: String,
+
+    /// Description of what could not be converted.
+    pub message: String,
+}
+
+impl Display for DowngradeWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.location, self.message)
+    }
+}
+
+struct Downgrade {
+    warnings: Vec<DowngradeWarning>,
+}
+
+pub(super) fn downgrade(openapi: &OpenApi) -> OpenApiV30 {
+    let mut downgrade = Downgrade {
+        warnings: Vec::new(),
+    };
+    let mut document = serde_json::to_value(openapi).unwrap_or_default();
+
+    if let Value::Object(object) = &mut document {
+        object.insert("openapi".to_string(), Value::from(OPENAPI_30));
+        object.remove("$schema");
+
+        downgrade.remove(object, "#", "webhooks", "webhooks are not supported");
+        downgrade.remove(
+            object,
+            "#",
+            "jsonSchemaDialect",
+            "jsonSchemaDialect is not supported",
+        );
+        if let Some(Value::Object(license)) = object
+            .get_mut("info")
+            .and_then(|info| info.get_mut("license"))
+        {
+            downgrade.remove(
+                license,
+                "#/info/license",
+                "identifier",
+                "license identifier is not supported",
+            );
+        }
+        if let Some(Value::Object(components)) = object.get_mut("components") {
+            downgrade.remove(
+                components,
+                "#/components",
+                "pathItems",
+                "path items components are not supported",
+            );
+            if let Some(Value::Object(security_schemes)) = components.get_mut("securitySchemes") {
+                let mutual_tls = security_schemes
+                    .iter()
+                    .filter(|(_, scheme)| scheme.get("type") == Some(&json!("mutualTLS")))
+                    .map(|(name, _)| name.clone())
+                    .collect::<Vec<_>>();
+                for name in mutual_tls {
+                    downgrade.remove(
+                        security_schemes,
+                        "#/components/securitySchemes",
+                        &name,
+                        "mutualTLS security scheme is not supported",
+                    );
+                }
+                for (name, scheme) in security_schemes.iter_mut() {
+                    if let Some(Value::Object(flows)) = scheme.get_mut("flows") {
+                        downgrade.remove(
+                            flows,
+                            &format!("#/components/securitySchemes/{}/flows", escape(name)),
+                            "deviceAuthorization",
+                            "device authorization flow is not supported",
+                        );
+                    }
+                }
+            }
+        }
+        if let Some(Value::Object(paths)) = object.get_mut("paths") {
+            for (path, item) in paths.iter_mut() {
+                if let Value::Object(item) = item {
+                    let location = format!("#/paths/{}", escape(path));
+                    downgrade.remove(item, &location, "query", "query operation is not supported");
+                    downgrade.remove(
+                        item,
+                        &location,
+                        "additionalOperations",
+                        "additional operations are not supported",
+                    );
+                }
+            }
+        }
+        if let Some(Value::Array(tags)) = object.get_mut("tags") {
+            for (index, tag) in tags.iter_mut().enumerate() {
+                if let Value::Object(tag) = tag {
+                    let location = format!("#/tags/{index}");
+                    downgrade.remove(tag, &location, "parent", "tag parent is not supported");
+                    downgrade.remove(tag, &location, "kind", "tag kind is not supported");
+                }
+            }
+        }
+
+        downgrade.walk(&mut document, &mut String::from("#"), false);
+    }
+
+    OpenApiV30 {
+        document,
+        warnings: downgrade.warnings,
+    }
+}
+
+impl Downgrade {
+    fn warn(&mut self, location: &str, message: impl Into<String>) {
+        self.warnings.push(DowngradeWarning {
+            location: location.to_string(),
+            message: message.into(),
+        });
+    }
+
+    /// Remove `key` from the `object` and warn with given `message` if it existed.
+    fn remove(
+        &mut self,
+        object: &mut Map<String, Value>,
+        location: &str,
+        key: &str,
+        message: &str,
+    ) {
+        if object.remove(key).is_some() {
+            self.warn(&format!("{location}/{}", escape(key)), message);
+        }
+    }
+
+    /// Walk the document outside of schemas and convert every schema found. `schemas` tells
+    /// whether the `value` is a map of schemas e.g. `components.schemas`.
+    fn walk(&mut self, value: &mut Value, location: &mut String, schemas: bool) {
+        let Value::Object(object) = value else {
+            if let Value::Array(array) = value {
+                for (index, value) in array.iter_mut().enumerate() {
+                    let len = location.len();
+                    location.push_str(&format!("/{index}"));
+                    self.walk(value, location, false);
+                    location.truncate(len);
+                }
+            }
+            return;
+        };
+
+        for (key, value) in object.iter_mut() {
+            // examples and extensions are arbitrary user data
+            if key == "example" || key == "examples" || key.starts_with("x-") {
+                continue;
+            }
+
+            let len = location.len();
+            location.push('/');
+            location.push_str(&escape(key));
+            if schemas || key == "schema" {
+                self.schema(value, location);
+            } else {
+                let is_schemas = key == "schemas" && location.as_str() == "#/components/schemas";
+                self.walk(value, location, is_schemas);
+            }
+            location.truncate(len);
+        }
+    }
+
+    /// Convert a JSON Schema 2020-12 `schema` to OpenAPI 3.0 schema.
+    fn schema(&mut self, schema: &mut Value, location: &mut String) {
+        let object = match schema {
+            Value::Bool(true) => {
+                *schema = json!({});
+                return;
+            }
+            Value::Bool(false) => {
+                *schema = json!({ "not": {} });
+                return;
+            }
+            Value::Object(object) => object,
+            _ => return,
+        };
+
+        self.pattern_properties(object, location);
+        for keyword in UNSUPPORTED_KEYWORDS {
+            self.remove(
+                object,
+                location,
+                keyword,
+                &format!("`{keyword}` is not supported"),
+            );
+        }
+        content_format(object);
+
+        self.schema_type(object, location);
+        self.nullable_composite(object);
+        self.exclusive_bound(object, "exclusiveMinimum", "minimum", |bound, other| {
+            bound >= other
+        });
+        self.exclusive_bound(object, "exclusiveMaximum", "maximum", |bound, other| {
+            bound <= other
+        });
+
+        if let Some(value) = object.remove("const") {
+            object.insert("enum".to_string(), Value::Array(vec![value]));
+        }
+
+        if let Some(Value::Array(mut examples)) = object.remove("examples") {
+            if examples.len() > 1 {
+                self.warn(
+                    &format!("{location}/examples"),
+                    "only the first of the `examples` is retained as `example`",
+                );
+            }
+            if !examples.is_empty() {
+                object.insert("example".to_string(), examples.swap_remove(0));
+            }
+        }
+
+        self.prefix_items(object, location);
+
+        // in OpenAPI 3.0 `$ref` siblings are ignored, thus they are moved next to `allOf`
+        if object.contains_key("$ref") && object.len() > 1 {
+            if let Some(reference) = object.remove("$ref") {
+                object.insert("allOf".to_string(), json!([{ "$ref": reference }]));
+            }
+        }
+
+        self.sub_schemas(object, location);
+    }
+
+    /// Convert `type` array e.g. `["string", "null"]` to single `type` and `nullable`.
+    fn schema_type(&mut self, object: &mut Map<String, Value>, location: &str) {
+        let Some(Value::Array(types)) = object.get("type") else {
+            if object.get("type") == Some(&json!("null")) {
+                object.remove("type");
+                object.insert("nullable".to_string(), Value::Bool(true));
+                object.insert("enum".to_string(), json!([null]));
+            }
+            return;
+        };
+
+        let nullable = types.contains(&json!("null"));
+        let types = types
+            .iter()
+            .filter(|schema_type| *schema_type != "null")
+            .cloned()
+            .collect::<Vec<_>>();
+
+        match types.as_slice() {
+            [] => {
+                object.remove("type");
+                object.insert("enum".to_string(), json!([null]));
+            }
+            [schema_type] => {
+                object.insert("type".to_string(), schema_type.clone());
+            }
+            _ => {
+                self.warn(
+                    &format!("{location}/type"),
+                    "multiple types are converted to `anyOf`",
+                );
+                object.remove("type");
+                object.insert(
+                    "anyOf".to_string(),
+                    types
+                        .into_iter()
+                        .map(|schema_type| json!({ "type": schema_type }))
+                        .collect(),
+                );
+            }
+        }
+        if nullable {
+            object.insert("nullable".to_string(), Value::Bool(true));
+        }
+    }
+
+    /// Convert `patternProperties` to `additionalProperties` of `anyOf` the pattern schemas and
+    /// the `additionalProperties` schema. If `additionalProperties` allows any value the
+    /// `patternProperties` are removed since they can only restrict the allowed values.
+    fn pattern_properties(&mut self, object: &mut Map<String, Value>, location: &str) {
+        let Some(Value::Object(pattern_properties)) = object.remove("patternProperties") else {
+            return;
+        };
+
+        let schemas = pattern_properties.into_iter().map(|(_, schema)| schema);
+        let schemas = match object.remove("additionalProperties") {
+            None | Some(Value::Bool(true)) => {
+                self.warn(
+                    &format!("{location}/patternProperties"),
+                    "`patternProperties` is not supported",
+                );
+                return;
+            }
+            Some(Value::Bool(false)) => schemas.collect::<Vec<_>>(),
+            Some(additional) => schemas.chain([additional]).collect(),
+        };
+
+        self.warn(
+            &format!("{location}/patternProperties"),
+            "`patternProperties` is converted to `additionalProperties` losing the patterns of the property names",
+        );
+        let additional = match <[Value; 1]>::try_from(schemas) {
+            Ok([schema]) => schema,
+            Err(schemas) => json!({ "anyOf": schemas }),
+        };
+        object.insert("additionalProperties".to_string(), additional);
+    }
+
+    /// Convert `oneOf` or `anyOf` with `{"type": "null"}` item to `nullable`.
+    fn nullable_composite(&mut self, object: &mut Map<String, Value>) {
+        for keyword in ["oneOf", "anyOf"] {
+            let Some(Value::Array(items)) = object.get_mut(keyword) else {
+                continue;
+            };
+            let len = items.len();
+            items.retain(|item| item != &json!({ "type": "null" }));
+            if items.len() == len {
+                continue;
+            }
+
+            object.insert("nullable".to_string(), Value::Bool(true));
+            if let Some(Value::Array(items)) = object.get(keyword) {
+                if items.len() == 1 {
+                    let items = object.remove(keyword).unwrap_or_default();
+                    object.insert("allOf".to_string(), items);
+                }
+            }
+        }
+    }
+
+    /// Convert numeric `exclusiveMinimum` or `exclusiveMaximum` to `minimum` or `maximum` with
+    /// boolean `exclusiveMinimum` or `exclusiveMaximum`.
+    fn exclusive_bound<F: Fn(f64, f64) -> bool>(
+        &mut self,
+        object: &mut Map<String, Value>,
+        exclusive: &str,
+        inclusive: &str,
+        is_stricter: F,
+    ) {
+        let Some(bound) = object
+            .get(exclusive)
+            .filter(|bound| bound.is_number())
+            .cloned()
+        else {
+            return;
+        };
+        let stricter = match (
+            bound.as_f64(),
+            object.get(inclusive).and_then(Value::as_f64),
+        ) {
+            (Some(bound), Some(other)) => is_stricter(bound, other),
+            _ => true,
+        };
+
+        if stricter {
+            object.insert(inclusive.to_string(), bound);
+            object.insert(exclusive.to_string(), Value::Bool(true));
+        } else {
+            object.remove(exclusive);
+        }
+    }
+
+    /// Convert `prefixItems` to `items` of `anyOf` the prefix items.
+    fn prefix_items(&mut self, object: &mut Map<String, Value>, location: &str) {
+        let Some(Value::Array(prefix_items)) = object.remove("prefixItems") else {
+            if object.get("items") == Some(&Value::Bool(false)) {
+                object.insert("items".to_string(), json!({}));
+                object.insert("maxItems".to_string(), json!(0));
+            }
+            return;
+        };
+
+        self.warn(
+            &format!("{location}/prefixItems"),
+            "`prefixItems` is converted to `items` losing the positions of the items",
+        );
+        let len = prefix_items.len();
+        let mut items = prefix_items;
+        match object.remove("items") {
+            Some(Value::Bool(false)) | None => {
+                object.entry("maxItems").or_insert_with(|| Value::from(len));
+            }
+            Some(additional) => items.push(additional),
+        }
+        let items = if items.len() == 1 {
+            items.swap_remove(0)
+        } else {
+            json!({ "anyOf": items })
+        };
+        object.insert("items".to_string(), items);
+    }
+
+    /// Convert all sub schemas of the schema `object`.
+    fn sub_schemas(&mut self, object: &mut Map<String, Value>, location: &mut String) {
+        for (key, value) in object.iter_mut() {
+            let len = location.len();
+            location.push('/');
+            location.push_str(&escape(key));
+            match (key.as_str(), value) {
+                ("properties", Value::Object(properties)) => {
+                    for (name, property) in properties.iter_mut() {
+                        let len = location.len();
+                        location.push('/');
+                        location.push_str(&escape(name));
+                        self.schema(property, location);
+                        location.truncate(len);
+                    }
+                }
+                ("allOf" | "oneOf" | "anyOf", Value::Array(items)) => {
+                    for (index, item) in items.iter_mut().enumerate() {
+                        let len = location.len();
+                        location.push_str(&format!("/{index}"));
+                        self.schema(item, location);
+                        location.truncate(len);
+                    }
+                }
+                ("items" | "not", value) => self.schema(value, location),
+                ("additionalProperties", value) if value.is_object() => {
+                    self.schema(value, location)
+                }
+                _ => (),
+            }
+            location.truncate(len);
+        }
+    }
+}
+
+/// Convert `contentEncoding` and `contentMediaType` of a string to `format`. Base64 encoded
+/// content is `byte` and other content with media type is `binary`.
+fn content_format(object: &mut Map<String, Value>) {
+    let encoding = object.remove("contentEncoding");
+    let media_type = object.remove("contentMediaType");
+
+    let format = match (encoding, media_type) {
+        (Some(Value::String(encoding)), _) if encoding.starts_with("base64") => "byte",
+        (_, Some(_)) => "binary",
+        _ => return,
+    };
+    object
+        .entry("format")
+        .or_insert_with(|| Value::from(format));
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_json_snapshot;
+
+    use crate::openapi::path::{HttpMethod, OperationBuilder, PathItem, PathsBuilder};
+    use crate::openapi::schema::{AdditionalProperties, ArrayItems, Not, SchemaType};
+    use crate::openapi::tag::TagBuilder;
+    use crate::openapi::{
+        ArrayBuilder, ComponentsBuilder, ObjectBuilder, OneOfBuilder, OpenApiBuilder,
+        OpenApiVersion, Ref, Type,
+    };
+
+    #[test]
+    fn downgrade_schemas_to_openapi_30() {
+        let openapi = OpenApiBuilder::new()
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Pet",
+                        ObjectBuilder::new()
+                            .property(
+                                "name",
+                                ObjectBuilder::new()
+                                    .schema_type(SchemaType::from_iter([Type::String, Type::Null]))
+                                    .examples(["bob", "alice"]),
+                            )
+                            .property(
+                                "age",
+                                ObjectBuilder::new()
+                                    .schema_type(Type::Integer)
+                                    .exclusive_minimum(Some(0))
+                                    .exclusive_maximum(Some(100))
+                                    .maximum(Some(50)),
+                            )
+                            .property(
+                                "owner",
+                                OneOfBuilder::new()
+                                    .item(ObjectBuilder::new().schema_type(Type::Null))
+                                    .item(Ref::from_schema_name("Owner")),
+                            )
+                            .property(
+                                "kind",
+                                ObjectBuilder::new()
+                                    .schema_type(Type::String)
+                                    .const_value(Some("pet")),
+                            )
+                            .property(
+                                "tags",
+                                ArrayBuilder::new()
+                                    .prefix_items([
+                                        ObjectBuilder::new().schema_type(Type::String),
+                                        ObjectBuilder::new().schema_type(Type::Integer),
+                                    ])
+                                    .items(ArrayItems::False),
+                            )
+                            .property("not_cat", Not::new(Ref::from_schema_name("Cat")))
+                            .if_schema(Some(ObjectBuilder::new())),
+                    )
+                    .build(),
+            ))
+            .build();
+
+        let openapi_30 = openapi.to_openapi_30();
+
+        assert_json_snapshot!(openapi_30.document, @r##"
+        {
+          "components": {
+            "schemas": {
+              "Pet": {
+                "properties": {
+                  "age": {
+                    "exclusiveMinimum": true,
+                    "maximum": 50,
+                    "minimum": 0,
+                    "type": "integer"
+                  },
+                  "kind": {
+                    "enum": [
+                      "pet"
+                    ],
+                    "type": "string"
+                  },
+                  "name": {
+                    "example": "bob",
+                    "nullable": true,
+                    "type": "string"
+                  },
+                  "not_cat": {
+                    "not": {
+                      "$ref": "#/components/schemas/Cat"
+                    }
+                  },
+                  "owner": {
+                    "allOf": [
+                      {
+                        "$ref": "#/components/schemas/Owner"
+                      }
+                    ],
+                    "nullable": true
+                  },
+                  "tags": {
+                    "items": {
+                      "anyOf": [
+                        {
+                          "type": "string"
+                        },
+                        {
+                          "type": "integer"
+                        }
+                      ]
+                    },
+                    "maxItems": 2,
+                    "type": "array"
+                  }
+                },
+                "type": "object"
+              }
+            }
+          },
+          "info": {
+            "title": "",
+            "version": ""
+          },
+          "openapi": "3.0.3",
+          "paths": {}
+        }
+        "##);
+        assert_eq!(
+            openapi_30
+                .warnings
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            [
+                "#/components/schemas/Pet/if: `if` is not supported",
+                "#/components/schemas/Pet/properties/name/examples: only the first of the `examples` is retained as `example`",
+                "#/components/schemas/Pet/properties/tags/prefixItems: `prefixItems` is converted to `items` losing the positions of the items",
+            ]
+        );
+    }
+
+    #[test]
+    fn downgrade_schema_keywords_to_openapi_30_equivalents() {
+        let openapi = OpenApiBuilder::new()
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Labels",
+                        ObjectBuilder::new()
+                            .pattern_property("^x-", ObjectBuilder::new().schema_type(Type::String))
+                            .additional_properties(Some(
+                                ObjectBuilder::new().schema_type(Type::Number),
+                            )),
+                    )
+                    .schema(
+                        "Tags",
+                        ObjectBuilder::new()
+                            .pattern_property("^x-", ObjectBuilder::new().schema_type(Type::String))
+                            .additional_properties(Some(AdditionalProperties::FreeForm(false))),
+                    )
+                    .schema(
+                        "File",
+                        ObjectBuilder::new()
+                            .property(
+                                "content",
+                                ObjectBuilder::new()
+                                    .schema_type(Type::String)
+                                    .content_encoding("base64"),
+                            )
+                            .property(
+                                "image",
+                                ObjectBuilder::new()
+                                    .schema_type(Type::String)
+                                    .content_media_type("image/png"),
+                            )
+                            .property(
+                                "nothing",
+                                ObjectBuilder::new()
+                                    .schema_type(SchemaType::from_iter([Type::Null])),
+                            ),
+                    )
+                    .build(),
+            ))
+            .build();
+
+        let openapi_30 = openapi.to_openapi_30();
+
+        assert_json_snapshot!(openapi_30.document.pointer("/components/schemas"), @r##"
+        {
+          "File": {
+            "properties": {
+              "content": {
+                "format": "byte",
+                "type": "string"
+              },
+              "image": {
+                "format": "binary",
+                "type": "string"
+              },
+              "nothing": {
+                "enum": [
+                  null
+                ],
+                "nullable": true
+              }
+            },
+            "type": "object"
+          },
+          "Labels": {
+            "additionalProperties": {
+              "anyOf": [
+                {
+                  "type": "string"
+                },
+                {
+                  "type": "number"
+                }
+              ]
+            },
+            "type": "object"
+          },
+          "Tags": {
+            "additionalProperties": {
+              "type": "string"
+            },
+            "type": "object"
+          }
+        }
+        "##);
+        assert_eq!(
+            openapi_30
+                .warnings
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            [
+                "#/components/schemas/Labels/patternProperties: `patternProperties` is converted to `additionalProperties` losing the patterns of the property names",
+                "#/components/schemas/Tags/patternProperties: `patternProperties` is converted to `additionalProperties` losing the patterns of the property names",
+            ]
+        );
+    }
+
+    #[test]
+    fn downgrade_removes_openapi_32_features() {
+        let openapi = OpenApiBuilder::new()
+            .openapi(OpenApiVersion::Version32)
+            .paths(PathsBuilder::new().path(
+                "/pets",
+                PathItem::from_http_methods(
+                    [HttpMethod::Get, HttpMethod::Query],
+                    OperationBuilder::new(),
+                ),
+            ))
+            .tags(Some([TagBuilder::new()
+                .name("pets")
+                .parent(Some("animals"))
+                .build()]))
+            .build();
+
+        let openapi_30 = openapi.to_openapi_30();
+
+        assert!(openapi_30.document.pointer("/paths/~1pets/get").is_some());
+        assert!(openapi_30.document.pointer("/paths/~1pets/query").is_none());
+        assert_eq!(
+            openapi_30
+                .warnings
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            [
+                "#/paths/~1pets/query: query operation is not supported",
+                "#/tags/0/parent: tag parent is not supported",
+            ]
+        );
+    }
+}
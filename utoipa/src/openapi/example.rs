@@ -6,7 +6,7 @@
 //! [request_body]: request_body/struct.RequestBody.html
 use serde::{Deserialize, Serialize};
 
-use super::{builder, set_value, RefOr};
+use super::{builder, set_value, Ref, RefOr};
 
 builder! {
     /// # Examples
@@ -99,3 +99,9 @@ impl From<ExampleBuilder> for RefOr<Example> {
         Self::T(example_builder.build())
     }
 }
+
+impl From<Ref> for RefOr<Example> {
+    fn from(r: Ref) -> Self {
+        Self::Ref(r)
+    }
+}
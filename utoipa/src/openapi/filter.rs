@@ -0,0 +1,179 @@
+//! Implements filtering of [`OpenApi`] document operations with [`OpenApi::filter`].
+use std::collections::BTreeSet;
+
+use super::path::{HttpMethod, Operation};
+use super::{resolver, OpenApi};
+
+/// Get names of the tags and security schemes used by the operations and global security of the
+/// document.
+fn used_tags_and_security_schemes(openapi: &OpenApi) -> (BTreeSet<String>, BTreeSet<String>) {
+    let mut tags = BTreeSet::new();
+    let mut security_schemes = BTreeSet::new();
+
+    let operations = openapi
+        .paths
+        .paths
+        .values()
+        .flat_map(|item| item.operations().map(|(_, operation)| operation));
+    for operation in operations {
+        tags.extend(operation.tags.iter().flatten().cloned());
+        security_schemes.extend(
+            operation
+                .security
+                .iter()
+                .flatten()
+                .flat_map(|requirement| requirement.names().cloned()),
+        );
+    }
+    security_schemes.extend(
+        openapi
+            .security
+            .iter()
+            .flatten()
+            .flat_map(|requirement| requirement.names().cloned()),
+    );
+
+    (tags, security_schemes)
+}
+
+pub(super) fn filter<F: Fn(&str, &HttpMethod, &Operation) -> bool>(
+    openapi: &OpenApi,
+    predicate: F,
+) -> OpenApi {
+    let reachable = resolver::reachable_components(openapi).ok();
+    let (tags, security_schemes) = used_tags_and_security_schemes(openapi);
+
+    let mut filtered = openapi.clone();
+    for (path, item) in filtered.paths.paths.iter_mut() {
+        let methods = item
+            .operations()
+            .filter(|(method, operation)| !predicate(path, method, operation))
+            .map(|(method, _)| method)
+            .collect::<Vec<_>>();
+        for method in methods {
+            item.take_operation(&method);
+        }
+    }
+    filtered
+        .paths
+        .paths
+        .retain(|_, item| item.operations().next().is_some());
+
+    // only items that became unused are removed, items that were unused already are retained
+    let filtered_reachable = resolver::reachable_components(&filtered).ok();
+    let (filtered_tags, filtered_security_schemes) = used_tags_and_security_schemes(&filtered);
+
+    if let Some(components) = filtered.components.as_mut() {
+        // components are left untouched if the reachability cannot be resolved
+        if let (Some(reachable), Some(filtered_reachable)) = (reachable, filtered_reachable) {
+            resolver::retain_components(components, |location| {
+                !reachable.contains(location) || filtered_reachable.contains(location)
+            });
+        }
+        components.security_schemes.retain(|name, _| {
+            !security_schemes.contains(name) || filtered_security_schemes.contains(name)
+        });
+    }
+    if let Some(defined_tags) = filtered.tags.as_mut() {
+        defined_tags.retain(|tag| !tags.contains(&tag.name) || filtered_tags.contains(&tag.name));
+    }
+
+    filtered
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::openapi::extensions::ExtensionsBuilder;
+    use crate::openapi::path::OperationBuilder;
+    use crate::openapi::security::{ApiKey, ApiKeyValue, SecurityRequirement, SecurityScheme};
+    use crate::openapi::tag::Tag;
+    use crate::openapi::{
+        ComponentsBuilder, ObjectBuilder, OpenApiBuilder, PathItem, PathsBuilder, Ref,
+    };
+
+    use super::*;
+
+    #[test]
+    fn filter_operations_by_extension() {
+        let partner = ExtensionsBuilder::new()
+            .add("x-audience", "partner")
+            .build();
+        let openapi = OpenApiBuilder::new()
+            .paths(
+                PathsBuilder::new()
+                    .path(
+                        "/pets",
+                        PathItem::new(
+                            HttpMethod::Get,
+                            OperationBuilder::new()
+                                .tag("pets")
+                                .response("200", Ref::from_response_name("Pets")),
+                        ),
+                    )
+                    .path(
+                        "/pets",
+                        PathItem::new(
+                            HttpMethod::Delete,
+                            OperationBuilder::new()
+                                .tag("admin")
+                                .security(SecurityRequirement::new("admin_key", [] as [&str; 0]))
+                                .extensions(Some(partner))
+                                .response("200", Ref::from_response_name("Deleted")),
+                        ),
+                    ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .response("Pets", Ref::from_schema_name("Pet"))
+                    .response("Deleted", Ref::from_schema_name("Pet"))
+                    .schema("Pet", ObjectBuilder::new())
+                    .schema("Unused", ObjectBuilder::new())
+                    .security_scheme(
+                        "admin_key",
+                        SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("admin_key"))),
+                    )
+                    .build(),
+            ))
+            .tags(Some([
+                Tag::new("pets"),
+                Tag::new("admin"),
+                Tag::new("other"),
+            ]))
+            .build();
+
+        let public = openapi.filter(|_, _, operation| {
+            operation
+                .extensions
+                .as_ref()
+                .and_then(|extensions| extensions.get("x-audience"))
+                != Some(&json!("partner"))
+        });
+
+        assert!(public
+            .paths
+            .get_path_operation("/pets", HttpMethod::Get)
+            .is_some());
+        assert!(public
+            .paths
+            .get_path_operation("/pets", HttpMethod::Delete)
+            .is_none());
+        let components = public.components.expect("components should exist");
+        assert_eq!(components.responses.keys().collect::<Vec<_>>(), ["Pets"]);
+        assert_eq!(
+            components.schemas.keys().collect::<Vec<_>>(),
+            ["Pet", "Unused"]
+        );
+        assert!(components.security_schemes.is_empty());
+        assert_eq!(
+            public
+                .tags
+                .iter()
+                .flatten()
+                .map(|tag| tag.name.as_str())
+                .collect::<Vec<_>>(),
+            ["pets", "other"]
+        );
+    }
+}
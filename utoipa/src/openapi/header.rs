@@ -8,7 +8,7 @@ use std::collections::BTreeMap;
 
 use super::{
     builder, content::Content, example::Example, extensions::Extensions, path::ParameterStyle,
-    set_value, Deprecated, Object, RefOr, Schema, Type,
+    set_value, Deprecated, Object, Ref, RefOr, Schema, Type,
 };
 
 builder! {
@@ -170,6 +170,18 @@ impl HeaderBuilder {
     }
 }
 
+impl From<HeaderBuilder> for RefOr<Header> {
+    fn from(builder: HeaderBuilder) -> Self {
+        Self::T(builder.build())
+    }
+}
+
+impl From<Ref> for RefOr<Header> {
+    fn from(r: Ref) -> Self {
+        Self::Ref(r)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
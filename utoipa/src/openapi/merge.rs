@@ -0,0 +1,586 @@
+//! Implements conflict aware merging of [`OpenApi`] documents.
+//!
+//! Use [`OpenApi::merge_with_strategy`] or [`Paths::merge_with_strategy`][paths] to merge
+//! documents with given [`MergeStrategy`] and get a [`MergeReport`] of every conflict found
+//! during the merge.
+//!
+//! [paths]: super::path::Paths::merge_with_strategy
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use super::resolver::unescape;
+use super::validation::escape;
+use super::{OpenApi, RefOr};
+
+/// Strategy used to resolve conflicts when merging [`OpenApi`] documents.
+///
+/// Conflict occurs when both documents define a component, webhook or operation with the same
+/// name but with different content. Identical definitions are never considered conflicts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MergeStrategy {
+    /// Keep the existing definition and ignore the incoming one. This is the behavior of
+    /// [`OpenApi::merge`].
+    #[default]
+    KeepLeft,
+    /// Replace the existing definition with the incoming one.
+    KeepRight,
+    /// Do not merge anything and return [`MergeError`] listing all the conflicts.
+    ErrorOnDifference,
+    /// Keep the existing definition and add the incoming one under a new unique name in format
+    /// `{name}_{n}` where `n` starts from `2`. All references to the renamed component within
+    /// the incoming document are updated accordingly.
+    ///
+    /// Paths and operations cannot be renamed, thus for those the existing definition is kept.
+    RenameIncoming,
+}
+
+/// Describes how a [`MergeConflict`] was resolved.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Resolution {
+    /// Existing definition was kept.
+    KeptLeft,
+    /// Existing definition was replaced with the incoming one.
+    KeptRight,
+    /// Incoming definition was added with the given new name.
+    Renamed(String),
+    /// Conflict was not resolved because of [`MergeStrategy::ErrorOnDifference`].
+    Rejected,
+}
+
+/// Conflict between two different definitions with the same name found during merge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeConflict {
+    /// Location of the conflicting definition in the document as [JSON Pointer][json_pointer]
+    /// prefixed with `#` e.g. `#/components/schemas/Error` or `#/paths/~1pets/get`.
+    ///
+    /// [json_pointer]: https://datatracker.ietf.org/doc/html/rfc6901
+    pub location: String,
+    /// How the conflict was resolved.
+    pub resolution: Resolution,
+}
+
+/// Report of a successful merge listing all resolved [`MergeConflict`]s.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MergeReport {
+    /// Conflicts found and resolved during the merge.
+    pub conflicts: Vec<MergeConflict>,
+}
+
+impl MergeReport {
+    /// Check whether the merge was free of conflicts.
+    pub fn is_empty(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+}
+
+/// Error returned by the merge when documents cannot be merged.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeError {
+    /// Conflicting definitions were found with [`MergeStrategy::ErrorOnDifference`].
+    Conflicts(Vec<MergeConflict>),
+    /// References of the renamed components could not be updated because the incoming
+    /// document is not a valid [`OpenApi`] document.
+    InvalidDocument {
+        /// Reason why the document is invalid.
+        reason: String,
+    },
+}
+
+impl Display for MergeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Conflicts(conflicts) => {
+                write!(f, "conflicting definitions found: ")?;
+                for (index, conflict) in conflicts.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", conflict.location)?;
+                }
+                Ok(())
+            }
+            Self::InvalidDocument { reason } => write!(f, "invalid document: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for MergeError {}
+
+/// Check whether `item` and `other_item` serialize to the same document. Comparing serialized
+/// values ignores differences not visible in the document e.g. empty versus missing extensions.
+pub(super) fn is_same<T: Serialize>(item: &T, other_item: &T) -> bool {
+    match (serde_json::to_value(item), serde_json::to_value(other_item)) {
+        (Ok(value), Ok(other_value)) => value == other_value,
+        _ => false,
+    }
+}
+
+/// Component renamed with [`MergeStrategy::RenameIncoming`].
+struct Rename {
+    component: &'static str,
+    from: String,
+    to: String,
+}
+
+/// Find conflicting items of `items` and `other_items` and resolve them according to the
+/// `strategy`. Items of `other_items` are replaced or renamed in place; appending the rest is up
+/// to the caller.
+fn resolve_conflicts<T: Serialize>(
+    component: &'static str,
+    items: &mut BTreeMap<String, T>,
+    other_items: &mut BTreeMap<String, T>,
+    strategy: MergeStrategy,
+    conflicts: &mut Vec<MergeConflict>,
+    renames: &mut Vec<Rename>,
+) {
+    let names = other_items
+        .iter()
+        .filter(|(name, item)| {
+            items
+                .get(*name)
+                .is_some_and(|existing| !is_same(existing, *item))
+        })
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+
+    for name in names {
+        let location = format!("#/{component}/{}", escape(&name));
+        let resolution = match strategy {
+            MergeStrategy::KeepLeft => Resolution::KeptLeft,
+            MergeStrategy::ErrorOnDifference => Resolution::Rejected,
+            MergeStrategy::KeepRight => {
+                if let Some(item) = other_items.remove(&name) {
+                    items.insert(name, item);
+                }
+                Resolution::KeptRight
+            }
+            MergeStrategy::RenameIncoming => {
+                let to = (2..)
+                    .map(|n| format!("{name}_{n}"))
+                    .find(|to| !items.contains_key(to) && !other_items.contains_key(to))
+                    .expect("unique name must be found");
+                if let Some(item) = other_items.remove(&name) {
+                    other_items.insert(to.clone(), item);
+                }
+                renames.push(Rename {
+                    component,
+                    from: name,
+                    to: to.clone(),
+                });
+                Resolution::Renamed(to)
+            }
+        };
+        conflicts.push(MergeConflict {
+            location,
+            resolution,
+        });
+    }
+}
+
+pub(super) fn merge(
+    openapi: &mut OpenApi,
+    mut other: OpenApi,
+    strategy: MergeStrategy,
+) -> Result<MergeReport, MergeError> {
+    let mut conflicts = Vec::new();
+
+    // renaming incoming components changes the references to them which in turn may cause new
+    // conflicts, thus conflicts are resolved until no more components are renamed
+    loop {
+        let mut renames = Vec::new();
+
+        if let (Some(components), Some(other_components)) =
+            (openapi.components.as_mut(), other.components.as_mut())
+        {
+            macro_rules! resolve_components {
+                ( $( $field:ident as $component:literal ),* ) => {
+                    $(
+                        resolve_conflicts(
+                            concat!("components/", $component),
+                            &mut components.$field,
+                            &mut other_components.$field,
+                            strategy,
+                            &mut conflicts,
+                            &mut renames,
+                        );
+                    )*
+                };
+            }
+
+            resolve_components!(
+                schemas as "schemas",
+                responses as "responses",
+                parameters as "parameters",
+                examples as "examples",
+                request_bodies as "requestBodies",
+                headers as "headers",
+                security_schemes as "securitySchemes",
+                links as "links",
+                callbacks as "callbacks",
+                path_items as "pathItems"
+            );
+        }
+
+        resolve_conflicts(
+            "webhooks",
+            &mut openapi.webhooks,
+            &mut other.webhooks,
+            strategy,
+            &mut conflicts,
+            &mut renames,
+        );
+
+        if renames.is_empty() {
+            break;
+        }
+        rename_references(&mut other, &renames)?;
+    }
+
+    // operations are merged last since the `other` paths are consumed by the merge
+    let paths = std::mem::take(&mut other.paths);
+    if strategy == MergeStrategy::ErrorOnDifference {
+        let mut paths_conflicts = openapi
+            .paths
+            .clone()
+            .merge_with_strategy(paths.clone(), strategy)
+            .err()
+            .map(|error| match error {
+                MergeError::Conflicts(conflicts) => conflicts,
+                MergeError::InvalidDocument { .. } => Vec::new(),
+            })
+            .unwrap_or_default();
+        conflicts.append(&mut paths_conflicts);
+
+        if !conflicts.is_empty() {
+            return Err(MergeError::Conflicts(conflicts));
+        }
+    }
+
+    let mut report = openapi.paths.merge_with_strategy(paths, strategy)?;
+    conflicts.append(&mut report.conflicts);
+
+    openapi.merge(other);
+
+    Ok(MergeReport { conflicts })
+}
+
+/// Update references and security requirements of the `other` [`OpenApi`] to point to the
+/// renamed components.
+fn rename_references(other: &mut OpenApi, renames: &[Rename]) -> Result<(), MergeError> {
+    for rename in renames
+        .iter()
+        .filter(|rename| rename.component == "components/securitySchemes")
+    {
+        let operations = other
+            .paths
+            .paths
+            .values_mut()
+            .chain(
+                other
+                    .webhooks
+                    .values_mut()
+                    .filter_map(|webhook| match webhook {
+                        RefOr::T(path_item) => Some(path_item),
+                        RefOr::Ref(_) => None,
+                    }),
+            )
+            .flat_map(|path_item| path_item.operations_mut())
+            .filter_map(|operation| operation.security.as_mut());
+
+        for requirement in other.security.iter_mut().chain(operations).flatten() {
+            requirement.rename(&rename.from, &rename.to);
+        }
+    }
+
+    let references = renames
+        .iter()
+        .map(|rename| {
+            (
+                format!("#/{}/{}", rename.component, escape(&rename.from)),
+                format!("#/{}/{}", rename.component, escape(&rename.to)),
+            )
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    let invalid = |error: serde_json::Error| MergeError::InvalidDocument {
+        reason: error.to_string(),
+    };
+    let mut document = serde_json::to_value(&*other).map_err(invalid)?;
+    rename_refs(&mut document, &references);
+    *other = serde_json::from_value(document).map_err(invalid)?;
+
+    Ok(())
+}
+
+/// Rename `$ref`s and discriminator mappings of a value. Discriminator mappings can either be
+/// references or plain schema names, thus plain names are renamed as plain names.
+fn rename_refs(value: &mut Value, references: &BTreeMap<String, String>) {
+    match value {
+        Value::Object(object) => {
+            if let Some(Value::Object(mapping)) = object
+                .get_mut("discriminator")
+                .and_then(|discriminator| discriminator.get_mut("mapping"))
+            {
+                for reference in mapping.values_mut() {
+                    if let Value::String(reference) = reference {
+                        rename_mapping(reference, references);
+                    }
+                }
+            }
+            for (key, value) in object.iter_mut() {
+                match value {
+                    Value::String(reference) if key == "$ref" => {
+                        if let Some(renamed) = references.get(reference) {
+                            *reference = renamed.clone();
+                        }
+                    }
+                    value => rename_refs(value, references),
+                }
+            }
+        }
+        Value::Array(array) => array
+            .iter_mut()
+            .for_each(|value| rename_refs(value, references)),
+        _ => (),
+    }
+}
+
+fn rename_mapping(reference: &mut String, references: &BTreeMap<String, String>) {
+    if let Some(renamed) = references.get(reference) {
+        *reference = renamed.clone();
+    } else if let Some(renamed) = references
+        .get(&format!("#/components/schemas/{}", escape(reference)))
+        .and_then(|renamed| renamed.strip_prefix("#/components/schemas/"))
+    {
+        *reference = unescape(renamed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_json_snapshot;
+
+    use crate::openapi::path::OperationBuilder;
+    use crate::openapi::security::{ApiKey, ApiKeyValue, SecurityRequirement, SecurityScheme};
+    use crate::openapi::{
+        ComponentsBuilder, Content, Discriminator, HttpMethod, ObjectBuilder, OneOfBuilder,
+        OpenApiBuilder, PathItem, PathsBuilder, Ref, ResponseBuilder, Type,
+    };
+
+    use super::*;
+
+    fn error_api(error_type: Type, operation_id: &str) -> OpenApi {
+        OpenApiBuilder::new()
+            .paths(
+                PathsBuilder::new().path(
+                    "/pets",
+                    PathItem::new(
+                        HttpMethod::Get,
+                        OperationBuilder::new()
+                            .operation_id(Some(operation_id))
+                            .security(SecurityRequirement::new("api_key", [] as [&str; 0]))
+                            .response("500", Ref::from_response_name("Error")),
+                    ),
+                ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema("Error", ObjectBuilder::new().schema_type(error_type))
+                    .schema("Pet", ObjectBuilder::new())
+                    .response(
+                        "Error",
+                        ResponseBuilder::new().description("Error").content(
+                            "application/json",
+                            Content::new(Some(Ref::from_schema_name("Error"))),
+                        ),
+                    )
+                    .security_scheme(
+                        "api_key",
+                        SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new(operation_id))),
+                    )
+                    .build(),
+            ))
+            .build()
+    }
+
+    #[test]
+    fn merge_keep_left_reports_conflicts() {
+        let mut api = error_api(Type::Object, "get_pets");
+
+        let report = api
+            .merge_with_strategy(
+                error_api(Type::String, "list_pets"),
+                MergeStrategy::KeepLeft,
+            )
+            .expect("keep left should merge");
+
+        assert_eq!(
+            report
+                .conflicts
+                .iter()
+                .map(|conflict| conflict.location.as_str())
+                .collect::<Vec<_>>(),
+            [
+                "#/components/schemas/Error",
+                "#/components/securitySchemes/api_key",
+                "#/paths/~1pets/get",
+            ]
+        );
+        assert!(report
+            .conflicts
+            .iter()
+            .all(|conflict| conflict.resolution == Resolution::KeptLeft));
+        assert_eq!(api, error_api(Type::Object, "get_pets"));
+    }
+
+    #[test]
+    fn merge_keep_right_replaces_conflicts() {
+        let mut api = error_api(Type::Object, "get_pets");
+
+        api.merge_with_strategy(
+            error_api(Type::String, "list_pets"),
+            MergeStrategy::KeepRight,
+        )
+        .expect("keep right should merge");
+
+        assert_eq!(api, error_api(Type::String, "list_pets"));
+    }
+
+    #[test]
+    fn merge_error_on_difference_does_not_merge() {
+        let mut api = error_api(Type::Object, "get_pets");
+
+        let error = api
+            .merge_with_strategy(
+                error_api(Type::String, "list_pets"),
+                MergeStrategy::ErrorOnDifference,
+            )
+            .expect_err("conflicts should fail the merge");
+
+        assert!(matches!(error, MergeError::Conflicts(conflicts) if conflicts.len() == 3));
+        assert_eq!(api, error_api(Type::Object, "get_pets"));
+
+        let report = api
+            .merge_with_strategy(
+                error_api(Type::Object, "get_pets"),
+                MergeStrategy::ErrorOnDifference,
+            )
+            .expect("identical documents should merge");
+        assert!(report.is_empty());
+    }
+
+    #[test]
+    fn merge_rename_incoming_updates_references() {
+        let mut api = error_api(Type::Object, "get_pets");
+        let mut other = error_api(Type::String, "list_pets");
+        other.paths = PathsBuilder::new()
+            .path(
+                "/owners",
+                PathItem::new(
+                    HttpMethod::Get,
+                    OperationBuilder::new()
+                        .security(SecurityRequirement::new("api_key", [] as [&str; 0]))
+                        .response("500", Ref::from_response_name("Error")),
+                ),
+            )
+            .build();
+
+        let report = api
+            .merge_with_strategy(other, MergeStrategy::RenameIncoming)
+            .expect("rename incoming should merge");
+
+        assert_eq!(
+            report
+                .conflicts
+                .iter()
+                .map(|conflict| &conflict.resolution)
+                .collect::<Vec<_>>(),
+            [
+                &Resolution::Renamed("Error_2".to_string()),
+                &Resolution::Renamed("api_key_2".to_string()),
+                &Resolution::Renamed("Error_2".to_string()),
+            ]
+        );
+        assert_json_snapshot!(api.components.as_ref().map(|components| &components.responses), @r##"
+        {
+          "Error": {
+            "description": "Error",
+            "content": {
+              "application/json": {
+                "schema": {
+                  "$ref": "#/components/schemas/Error"
+                }
+              }
+            }
+          },
+          "Error_2": {
+            "description": "Error",
+            "content": {
+              "application/json": {
+                "schema": {
+                  "$ref": "#/components/schemas/Error_2"
+                }
+              }
+            }
+          }
+        }
+        "##);
+        assert_json_snapshot!(api.paths.paths["/owners"], @r##"
+        {
+          "get": {
+            "responses": {
+              "500": {
+                "$ref": "#/components/responses/Error_2"
+              }
+            },
+            "security": [
+              {
+                "api_key_2": []
+              }
+            ]
+          }
+        }
+        "##);
+    }
+
+    #[test]
+    fn merge_rename_incoming_updates_discriminator_mapping() {
+        let mut api = error_api(Type::Object, "get_pets");
+        let mut other = error_api(Type::String, "list_pets");
+        other.components.as_mut().unwrap().schemas.insert(
+            "Failure".to_string(),
+            OneOfBuilder::new()
+                .item(Ref::from_schema_name("Error"))
+                .discriminator(Some(Discriminator::with_mapping(
+                    "kind",
+                    [("error", "#/components/schemas/Error"), ("plain", "Error")],
+                )))
+                .into(),
+        );
+
+        api.merge_with_strategy(other, MergeStrategy::RenameIncoming)
+            .expect("rename incoming should merge");
+
+        assert_json_snapshot!(api.components.as_ref().map(|components| &components.schemas["Failure"]), @r##"
+        {
+          "oneOf": [
+            {
+              "$ref": "#/components/schemas/Error_2"
+            }
+          ],
+          "discriminator": {
+            "propertyName": "kind",
+            "mapping": {
+              "error": "#/components/schemas/Error_2",
+              "plain": "Error_2"
+            }
+          }
+        }
+        "##);
+    }
+}
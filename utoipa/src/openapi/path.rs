@@ -1,6 +1,10 @@
 //! Implements [OpenAPI Path Object][paths] types.
 //!
 //! [paths]: https://spec.openapis.org/oas/latest.html#paths-object
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+
 use crate::Path;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -8,10 +12,13 @@ use serde_json::Value;
 use super::{
     builder,
     extensions::Extensions,
+    merge::{is_same, MergeConflict, MergeError, MergeReport, MergeStrategy, Resolution},
     request_body::RequestBody,
     response::{Response, Responses},
     security::SecurityRequirement,
-    set_value, Deprecated, ExternalDocs, RefOr, Required, Schema, Server,
+    set_value,
+    validation::escape,
+    Deprecated, ExternalDocs, Ref, RefOr, Required, Schema, Server,
 };
 
 #[cfg(not(feature = "preserve_path_order"))]
@@ -88,16 +95,7 @@ impl Paths {
     ) -> Option<&Operation> {
         self.paths
             .get(path.as_ref())
-            .and_then(|path| match http_method {
-                HttpMethod::Get => path.get.as_ref(),
-                HttpMethod::Put => path.put.as_ref(),
-                HttpMethod::Post => path.post.as_ref(),
-                HttpMethod::Delete => path.delete.as_ref(),
-                HttpMethod::Options => path.options.as_ref(),
-                HttpMethod::Head => path.head.as_ref(),
-                HttpMethod::Patch => path.patch.as_ref(),
-                HttpMethod::Trace => path.trace.as_ref(),
-            })
+            .and_then(|path| path.operation(&http_method))
     }
 
     /// Append path operation to the list of paths.
@@ -117,16 +115,7 @@ impl Paths {
         let operation = operation.into();
         if let Some(existing_item) = self.paths.get_mut(path) {
             for http_method in http_methods {
-                match http_method {
-                    HttpMethod::Get => existing_item.get = Some(operation.clone()),
-                    HttpMethod::Put => existing_item.put = Some(operation.clone()),
-                    HttpMethod::Post => existing_item.post = Some(operation.clone()),
-                    HttpMethod::Delete => existing_item.delete = Some(operation.clone()),
-                    HttpMethod::Options => existing_item.options = Some(operation.clone()),
-                    HttpMethod::Head => existing_item.head = Some(operation.clone()),
-                    HttpMethod::Patch => existing_item.patch = Some(operation.clone()),
-                    HttpMethod::Trace => existing_item.trace = Some(operation.clone()),
-                };
+                existing_item.set_operation(http_method, operation.clone());
             }
         } else {
             self.paths.insert(
@@ -153,6 +142,62 @@ impl Paths {
             paths_extensions.merge(other_paths_extensions);
         }
     }
+
+    /// Merge _`other_paths`_ into `self` resolving conflicting [`Operation`]s with given
+    /// [`MergeStrategy`].
+    ///
+    /// Works the same way as [`Paths::merge`] but an [`Operation`] of _`other_paths`_ that is
+    /// different from the existing [`Operation`] of the same path and [`HttpMethod`] is reported
+    /// as [`MergeConflict`] and resolved according to the `strategy`. Operations cannot be renamed
+    /// thus [`MergeStrategy::RenameIncoming`] keeps the existing [`Operation`].
+    ///
+    /// Returns [`MergeReport`] of the resolved conflicts or [`MergeError`] without merging
+    /// anything if [`MergeStrategy::ErrorOnDifference`] is used and conflicts are found.
+    pub fn merge_with_strategy(
+        &mut self,
+        mut other_paths: Paths,
+        strategy: MergeStrategy,
+    ) -> Result<MergeReport, MergeError> {
+        let mut conflicts = Vec::new();
+        for (path, that) in &mut other_paths.paths {
+            let Some(this) = self.paths.get_mut(path) else {
+                continue;
+            };
+            let methods = that
+                .operations()
+                .filter(|(method, operation)| {
+                    this.operation(method)
+                        .is_some_and(|existing| !is_same(existing, *operation))
+                })
+                .map(|(method, _)| method)
+                .collect::<Vec<_>>();
+
+            for method in methods {
+                let location = format!("#/paths/{}/{}", escape(path), method.pointer());
+                let resolution = match strategy {
+                    MergeStrategy::ErrorOnDifference => Resolution::Rejected,
+                    MergeStrategy::KeepRight => {
+                        if let Some(operation) = that.take_operation(&method) {
+                            this.set_operation(method, operation);
+                        }
+                        Resolution::KeptRight
+                    }
+                    MergeStrategy::KeepLeft | MergeStrategy::RenameIncoming => Resolution::KeptLeft,
+                };
+                conflicts.push(MergeConflict {
+                    location,
+                    resolution,
+                });
+            }
+        }
+
+        if strategy == MergeStrategy::ErrorOnDifference && !conflicts.is_empty() {
+            return Err(MergeError::Conflicts(conflicts));
+        }
+
+        self.merge(other_paths);
+        Ok(MergeReport { conflicts })
+    }
 }
 
 impl PathsBuilder {
@@ -193,28 +238,7 @@ impl PathsBuilder {
     /// let _ = paths.path_from::<MyPath>();
     /// ```
     pub fn path_from<P: Path>(self) -> Self {
-        let methods = P::methods();
-        let operation = P::operation();
-
-        // for one operation method avoid clone
-        let path_item = if methods.len() == 1 {
-            PathItem::new(
-                methods
-                    .into_iter()
-                    .next()
-                    .expect("must have one operation method"),
-                operation,
-            )
-        } else {
-            methods
-                .into_iter()
-                .fold(PathItemBuilder::new(), |path_item, method| {
-                    path_item.operation(method, operation.clone())
-                })
-                .build()
-        };
-
-        self.path(P::path(), path_item)
+        self.path(P::path(), PathItem::from_path::<P>())
     }
 }
 
@@ -248,7 +272,7 @@ builder! {
         /// contain duplicate parameters. They can be overridden in [`Operation`] level but cannot be
         /// removed there.
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub parameters: Option<Vec<Parameter>>,
+        pub parameters: Option<Vec<RefOr<Parameter>>>,
 
         /// Get [`Operation`] for the [`PathItem`].
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -282,6 +306,19 @@ builder! {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub trace: Option<Operation>,
 
+        /// Query [`Operation`] for the [`PathItem`]. The _`query`_ field is supported from
+        /// OpenAPI 3.2 onwards.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub query: Option<Operation>,
+
+        /// Map of [`Operation`]s for HTTP methods not having a dedicated field in the
+        /// [`PathItem`], keyed by the method name with the same capitalization used in the
+        /// request. The _`additionalOperations`_ field is supported from OpenAPI 3.2 onwards.
+        ///
+        /// See [`HttpMethod::Custom`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub additional_operations: Option<BTreeMap<String, Operation>>,
+
         /// Optional extensions "x-something".
         #[serde(skip_serializing_if = "Option::is_none", flatten)]
         pub extensions: Option<Extensions>,
@@ -292,16 +329,7 @@ impl PathItem {
     /// Construct a new [`PathItem`] with provided [`Operation`] mapped to given [`HttpMethod`].
     pub fn new<O: Into<Operation>>(http_method: HttpMethod, operation: O) -> Self {
         let mut path_item = Self::default();
-        match http_method {
-            HttpMethod::Get => path_item.get = Some(operation.into()),
-            HttpMethod::Put => path_item.put = Some(operation.into()),
-            HttpMethod::Post => path_item.post = Some(operation.into()),
-            HttpMethod::Delete => path_item.delete = Some(operation.into()),
-            HttpMethod::Options => path_item.options = Some(operation.into()),
-            HttpMethod::Head => path_item.head = Some(operation.into()),
-            HttpMethod::Patch => path_item.patch = Some(operation.into()),
-            HttpMethod::Trace => path_item.trace = Some(operation.into()),
-        };
+        path_item.set_operation(http_method, operation.into());
 
         path_item
     }
@@ -314,47 +342,156 @@ impl PathItem {
         let mut path_item = Self::default();
         let operation = operation.into();
         for method in http_methods {
-            match method {
-                HttpMethod::Get => path_item.get = Some(operation.clone()),
-                HttpMethod::Put => path_item.put = Some(operation.clone()),
-                HttpMethod::Post => path_item.post = Some(operation.clone()),
-                HttpMethod::Delete => path_item.delete = Some(operation.clone()),
-                HttpMethod::Options => path_item.options = Some(operation.clone()),
-                HttpMethod::Head => path_item.head = Some(operation.clone()),
-                HttpMethod::Patch => path_item.patch = Some(operation.clone()),
-                HttpMethod::Trace => path_item.trace = Some(operation.clone()),
-            };
+            path_item.set_operation(method, operation.clone());
         }
 
         path_item
     }
 
-    /// Merge all defined [`Operation`]s from given [`PathItem`] to `self` if `self` does not have
-    /// existing operation.
-    pub fn merge_operations(&mut self, path_item: PathItem) {
-        if path_item.get.is_some() && self.get.is_none() {
-            self.get = path_item.get;
-        }
-        if path_item.put.is_some() && self.put.is_none() {
-            self.put = path_item.put;
-        }
-        if path_item.post.is_some() && self.post.is_none() {
-            self.post = path_item.post;
-        }
-        if path_item.delete.is_some() && self.delete.is_none() {
-            self.delete = path_item.delete;
-        }
-        if path_item.options.is_some() && self.options.is_none() {
-            self.options = path_item.options;
+    /// Constructs a new [`PathItem`] from a type implementing [`trait@Path`] trait. The
+    /// [`Operation`] of the [`trait@Path`] will be set for all of its [`HttpMethod`]s.
+    pub fn from_path<P: Path>() -> Self {
+        let methods = P::methods();
+        let operation = P::operation();
+
+        // for one operation method avoid clone
+        if methods.len() == 1 {
+            PathItem::new(
+                methods
+                    .into_iter()
+                    .next()
+                    .expect("must have one operation method"),
+                operation,
+            )
+        } else {
+            methods
+                .into_iter()
+                .fold(PathItemBuilder::new(), |path_item, method| {
+                    path_item.operation(method, operation.clone())
+                })
+                .build()
         }
-        if path_item.head.is_some() && self.head.is_none() {
-            self.head = path_item.head;
+    }
+
+    /// Get iterator over all defined [`Operation`]s of the [`PathItem`] with their [`HttpMethod`].
+    pub(crate) fn operations(&self) -> impl Iterator<Item = (HttpMethod, &Operation)> {
+        [
+            (HttpMethod::Get, &self.get),
+            (HttpMethod::Put, &self.put),
+            (HttpMethod::Post, &self.post),
+            (HttpMethod::Delete, &self.delete),
+            (HttpMethod::Options, &self.options),
+            (HttpMethod::Head, &self.head),
+            (HttpMethod::Patch, &self.patch),
+            (HttpMethod::Trace, &self.trace),
+            (HttpMethod::Query, &self.query),
+        ]
+        .into_iter()
+        .filter_map(|(method, operation)| operation.as_ref().map(|operation| (method, operation)))
+        .chain(
+            self.additional_operations
+                .iter()
+                .flatten()
+                .map(|(method, operation)| (HttpMethod::Custom(method.clone()), operation)),
+        )
+    }
+
+    /// Get reference to the [`Operation`] of given [`HttpMethod`] if one exists.
+    pub(crate) fn operation(&self, http_method: &HttpMethod) -> Option<&Operation> {
+        match &http_method.normalized() {
+            HttpMethod::Get => self.get.as_ref(),
+            HttpMethod::Put => self.put.as_ref(),
+            HttpMethod::Post => self.post.as_ref(),
+            HttpMethod::Delete => self.delete.as_ref(),
+            HttpMethod::Options => self.options.as_ref(),
+            HttpMethod::Head => self.head.as_ref(),
+            HttpMethod::Patch => self.patch.as_ref(),
+            HttpMethod::Trace => self.trace.as_ref(),
+            HttpMethod::Query => self.query.as_ref(),
+            HttpMethod::Custom(method) => self
+                .additional_operations
+                .as_ref()
+                .and_then(|operations| operations.get(method)),
         }
-        if path_item.patch.is_some() && self.patch.is_none() {
-            self.patch = path_item.patch;
+    }
+
+    /// Set [`Operation`] for given [`HttpMethod`] replacing the existing one.
+    pub(crate) fn set_operation(&mut self, http_method: HttpMethod, operation: Operation) {
+        match http_method.normalized() {
+            HttpMethod::Get => self.get = Some(operation),
+            HttpMethod::Put => self.put = Some(operation),
+            HttpMethod::Post => self.post = Some(operation),
+            HttpMethod::Delete => self.delete = Some(operation),
+            HttpMethod::Options => self.options = Some(operation),
+            HttpMethod::Head => self.head = Some(operation),
+            HttpMethod::Patch => self.patch = Some(operation),
+            HttpMethod::Trace => self.trace = Some(operation),
+            HttpMethod::Query => self.query = Some(operation),
+            HttpMethod::Custom(method) => {
+                self.additional_operations
+                    .get_or_insert_with(BTreeMap::new)
+                    .insert(method, operation);
+            }
+        };
+    }
+
+    /// Remove and return the [`Operation`] of given [`HttpMethod`] if one exists.
+    pub(crate) fn take_operation(&mut self, http_method: &HttpMethod) -> Option<Operation> {
+        match &http_method.normalized() {
+            HttpMethod::Get => self.get.take(),
+            HttpMethod::Put => self.put.take(),
+            HttpMethod::Post => self.post.take(),
+            HttpMethod::Delete => self.delete.take(),
+            HttpMethod::Options => self.options.take(),
+            HttpMethod::Head => self.head.take(),
+            HttpMethod::Patch => self.patch.take(),
+            HttpMethod::Trace => self.trace.take(),
+            HttpMethod::Query => self.query.take(),
+            HttpMethod::Custom(method) => {
+                let operations = self.additional_operations.as_mut()?;
+                let operation = operations.remove(method);
+                if operations.is_empty() {
+                    self.additional_operations = None;
+                }
+                operation
+            }
         }
-        if path_item.trace.is_some() && self.trace.is_none() {
-            self.trace = path_item.trace;
+    }
+
+    /// Get mutable iterator over all defined [`Operation`]s of the [`PathItem`].
+    pub(crate) fn operations_mut(&mut self) -> impl Iterator<Item = &mut Operation> {
+        [
+            &mut self.get,
+            &mut self.put,
+            &mut self.post,
+            &mut self.delete,
+            &mut self.options,
+            &mut self.head,
+            &mut self.patch,
+            &mut self.trace,
+            &mut self.query,
+        ]
+        .into_iter()
+        .flatten()
+        .chain(
+            self.additional_operations
+                .iter_mut()
+                .flat_map(|operations| operations.values_mut()),
+        )
+    }
+
+    /// Merge all defined [`Operation`]s from given [`PathItem`] to `self` if `self` does not have
+    /// existing operation.
+    pub fn merge_operations(&mut self, mut path_item: PathItem) {
+        let methods = path_item
+            .operations()
+            .map(|(method, _)| method)
+            .filter(|method| self.operation(method).is_none())
+            .collect::<Vec<_>>();
+        for method in methods {
+            if let Some(operation) = path_item.take_operation(&method) {
+                self.set_operation(method, operation);
+            }
         }
     }
 }
@@ -362,19 +499,11 @@ impl PathItem {
 impl PathItemBuilder {
     /// Append a new [`Operation`] by [`HttpMethod`] to this [`PathItem`]. Operations can
     /// hold only one operation per [`HttpMethod`].
-    pub fn operation<O: Into<Operation>>(mut self, http_method: HttpMethod, operation: O) -> Self {
-        match http_method {
-            HttpMethod::Get => self.get = Some(operation.into()),
-            HttpMethod::Put => self.put = Some(operation.into()),
-            HttpMethod::Post => self.post = Some(operation.into()),
-            HttpMethod::Delete => self.delete = Some(operation.into()),
-            HttpMethod::Options => self.options = Some(operation.into()),
-            HttpMethod::Head => self.head = Some(operation.into()),
-            HttpMethod::Patch => self.patch = Some(operation.into()),
-            HttpMethod::Trace => self.trace = Some(operation.into()),
-        };
+    pub fn operation<O: Into<Operation>>(self, http_method: HttpMethod, operation: O) -> Self {
+        let mut path_item = PathItem::from(self);
+        path_item.set_operation(http_method, operation.into());
 
-        self
+        path_item.into()
     }
 
     /// Add or change summary intended to apply all operations in this [`PathItem`].
@@ -395,8 +524,11 @@ impl PathItemBuilder {
     }
 
     /// Append list of [`Parameter`]s common to all [`Operation`]s to this [`PathItem`].
-    pub fn parameters<I: IntoIterator<Item = Parameter>>(mut self, parameters: Option<I>) -> Self {
-        set_value!(self parameters parameters.map(|parameters| parameters.into_iter().collect()))
+    pub fn parameters<I: IntoIterator<Item = P>, P: Into<RefOr<Parameter>>>(
+        mut self,
+        parameters: Option<I>,
+    ) -> Self {
+        set_value!(self parameters parameters.map(|parameters| parameters.into_iter().map(Into::into).collect()))
     }
 
     /// Add openapi extensions (x-something) to this [`PathItem`].
@@ -408,9 +540,9 @@ impl PathItemBuilder {
 /// HTTP method of the operation.
 ///
 /// List of supported HTTP methods <https://spec.openapis.org/oas/latest.html#path-item-object>
-#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord, Clone)]
-#[serde(rename_all = "lowercase")]
+#[derive(Clone)]
 #[cfg_attr(feature = "debug", derive(Debug))]
+#[non_exhaustive]
 pub enum HttpMethod {
     /// Type mapping for HTTP _GET_ request.
     Get,
@@ -428,6 +560,135 @@ pub enum HttpMethod {
     Patch,
     /// Type mapping for HTTP _TRACE_ request.
     Trace,
+    /// Type mapping for HTTP _QUERY_ request. Supported from OpenAPI 3.2 onwards.
+    Query,
+    /// Type mapping for any other HTTP method e.g. _COPY_ or _LINK_. The [`Operation`]s of
+    /// custom methods are defined in [`PathItem::additional_operations`] supported from
+    /// OpenAPI 3.2 onwards.
+    ///
+    /// The method name is used as is thus it should have the same capitalization used in the
+    /// request. Name of a predefined method is not allowed for custom method since the
+    /// predefined methods have their own fields in [`PathItem`]. Custom method named after
+    /// predefined method case insensitively e.g. _`GET`_ is treated as the predefined method.
+    Custom(String),
+}
+
+/// Names of the predefined [`HttpMethod`]s in the declaration order.
+const PREDEFINED_METHODS: [&str; 9] = [
+    "get", "post", "put", "delete", "options", "head", "patch", "trace", "query",
+];
+
+impl HttpMethod {
+    /// Get name of the [`HttpMethod`] as used in the OpenAPI document. Name of the predefined
+    /// methods is lowercase and name of [`HttpMethod::Custom`] is returned as is unless it is a
+    /// name of predefined method.
+    pub(crate) fn as_str(&self) -> &str {
+        match self {
+            Self::Get => "get",
+            Self::Post => "post",
+            Self::Put => "put",
+            Self::Delete => "delete",
+            Self::Options => "options",
+            Self::Head => "head",
+            Self::Patch => "patch",
+            Self::Trace => "trace",
+            Self::Query => "query",
+            Self::Custom(method) => PREDEFINED_METHODS
+                .into_iter()
+                .find(|predefined| predefined.eq_ignore_ascii_case(method))
+                .unwrap_or(method),
+        }
+    }
+
+    /// Get this [`HttpMethod`] with [`HttpMethod::Custom`] named after a predefined method
+    /// converted to the predefined method.
+    fn normalized(&self) -> Self {
+        match self {
+            Self::Custom(method) => Self::from(method.as_str()),
+            method => method.clone(),
+        }
+    }
+
+    /// Get position of the [`HttpMethod`] in the declaration order and the name of the method
+    /// for comparing methods.
+    fn order(&self) -> (usize, &str) {
+        let name = self.as_str();
+        let position = PREDEFINED_METHODS
+            .into_iter()
+            .position(|predefined| predefined == name)
+            .unwrap_or(PREDEFINED_METHODS.len());
+
+        (position, name)
+    }
+
+    /// Get JSON pointer of the [`Operation`] of this [`HttpMethod`] relative to the [`PathItem`].
+    pub(crate) fn pointer(&self) -> String {
+        match &self.normalized() {
+            Self::Custom(method) => format!("additionalOperations/{}", escape(method)),
+            _ => self.as_str().to_string(),
+        }
+    }
+}
+
+impl PartialEq for HttpMethod {
+    fn eq(&self, other: &Self) -> bool {
+        self.order() == other.order()
+    }
+}
+
+impl Eq for HttpMethod {}
+
+impl Hash for HttpMethod {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.order().hash(state)
+    }
+}
+
+impl PartialOrd for HttpMethod {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for HttpMethod {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.order().cmp(&other.order())
+    }
+}
+
+/// Name of the predefined method is matched case insensitively e.g. both _`get`_ and _`GET`_
+/// are [`HttpMethod::Get`]. Other names are [`HttpMethod::Custom`] methods.
+impl From<&str> for HttpMethod {
+    fn from(value: &str) -> Self {
+        let predefined = PREDEFINED_METHODS
+            .into_iter()
+            .find(|predefined| predefined.eq_ignore_ascii_case(value));
+
+        match predefined.unwrap_or(value) {
+            "get" => Self::Get,
+            "post" => Self::Post,
+            "put" => Self::Put,
+            "delete" => Self::Delete,
+            "options" => Self::Options,
+            "head" => Self::Head,
+            "patch" => Self::Patch,
+            "trace" => Self::Trace,
+            "query" => Self::Query,
+            custom => Self::Custom(custom.to_string()),
+        }
+    }
+}
+
+impl Serialize for HttpMethod {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for HttpMethod {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(|method| HttpMethod::from(method.as_str()))
+    }
 }
 
 builder! {
@@ -486,19 +747,19 @@ builder! {
 
         /// List of applicable parameters for this [`Operation`].
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub parameters: Option<Vec<Parameter>>,
+        pub parameters: Option<Vec<RefOr<Parameter>>>,
 
         /// Optional request body for this [`Operation`].
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub request_body: Option<RequestBody>,
+        pub request_body: Option<RefOr<RequestBody>>,
 
         /// List of possible responses returned by the [`Operation`].
         pub responses: Responses,
 
-        // TODO
-        #[allow(missing_docs)]
+        /// Map of possible out-of-band [`Callback`]s related to the [`Operation`]. The key is
+        /// unique name of the [`Callback`] within the [`Operation`].
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub callbacks: Option<String>,
+        pub callbacks: Option<BTreeMap<String, RefOr<Callback>>>,
 
         /// Define whether the operation is deprecated or not and thus should be avoided consuming.
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -564,7 +825,7 @@ impl OperationBuilder {
     }
 
     /// Add or change parameters of the [`Operation`].
-    pub fn parameters<I: IntoIterator<Item = P>, P: Into<Parameter>>(
+    pub fn parameters<I: IntoIterator<Item = P>, P: Into<RefOr<Parameter>>>(
         mut self,
         parameters: Option<I>,
     ) -> Self {
@@ -584,7 +845,7 @@ impl OperationBuilder {
     }
 
     /// Append parameter to [`Operation`] parameters.
-    pub fn parameter<P: Into<Parameter>>(mut self, parameter: P) -> Self {
+    pub fn parameter<P: Into<RefOr<Parameter>>>(mut self, parameter: P) -> Self {
         match self.parameters {
             Some(ref mut parameters) => parameters.push(parameter.into()),
             None => {
@@ -596,8 +857,8 @@ impl OperationBuilder {
     }
 
     /// Add or change request body of the [`Operation`].
-    pub fn request_body(mut self, request_body: Option<RequestBody>) -> Self {
-        set_value!(self request_body request_body)
+    pub fn request_body<R: Into<RefOr<RequestBody>>>(mut self, request_body: Option<R>) -> Self {
+        set_value!(self request_body request_body.map(Into::into))
     }
 
     /// Add or change responses of the [`Operation`].
@@ -621,6 +882,31 @@ impl OperationBuilder {
         self
     }
 
+    /// Add or change map of [`Callback`]s of the [`Operation`].
+    pub fn callbacks<I: IntoIterator<Item = (N, C)>, N: Into<String>, C: Into<RefOr<Callback>>>(
+        mut self,
+        callbacks: Option<I>,
+    ) -> Self {
+        set_value!(self callbacks callbacks.map(|callbacks| callbacks
+            .into_iter()
+            .map(|(name, callback)| (name.into(), callback.into()))
+            .collect()))
+    }
+
+    /// Append named [`Callback`] to the [`Operation`] callbacks map. If callback with same
+    /// name already exists it will be replaced.
+    pub fn callback<N: Into<String>, C: Into<RefOr<Callback>>>(
+        mut self,
+        name: N,
+        callback: C,
+    ) -> Self {
+        self.callbacks
+            .get_or_insert_with(BTreeMap::new)
+            .insert(name.into(), callback.into());
+
+        self
+    }
+
     /// Add or change deprecated status of the [`Operation`].
     pub fn deprecated(mut self, deprecated: Option<Deprecated>) -> Self {
         set_value!(self deprecated deprecated)
@@ -667,6 +953,103 @@ impl OperationBuilder {
     }
 }
 
+builder! {
+    /// # Examples
+    ///
+    /// _**Create a callback requested with `POST` to the URL given in the request body.**_
+    /// ```rust
+    /// # use utoipa::openapi::path::{CallbackBuilder, HttpMethod, OperationBuilder, PathItem};
+    /// # use utoipa::openapi::Response;
+    /// let callback = CallbackBuilder::new()
+    ///     .path(
+    ///         "{$request.body#/callbackUrl}",
+    ///         PathItem::new(
+    ///             HttpMethod::Post,
+    ///             OperationBuilder::new().response("200", Response::new("Callback received")),
+    ///         ),
+    ///     )
+    ///     .build();
+    /// ```
+    CallbackBuilder;
+
+    /// Implements [OpenAPI Callback Object][callback].
+    ///
+    /// Callback is a map of [runtime expressions][expression] to [`PathItem`]s describing
+    /// out-of-band requests the API provider may initiate in relation to the parent [`Operation`].
+    /// The expression is evaluated at runtime to resolve the URL of the callback request e.g.
+    /// `{$request.body#/callbackUrl}`.
+    ///
+    /// [callback]: https://spec.openapis.org/oas/latest.html#callback-object
+    /// [expression]: https://spec.openapis.org/oas/latest.html#runtime-expressions
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct Callback {
+        /// Map of runtime expressions with [`PathItem`]s describing the callback requests.
+        #[serde(flatten)]
+        pub paths: PathsMap<String, PathItem>,
+
+        /// Optional extensions "x-something".
+        #[serde(skip_serializing_if = "Option::is_none", flatten)]
+        pub extensions: Option<Extensions>,
+    }
+}
+
+impl Callback {
+    /// Construct a new empty [`Callback`].
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl CallbackBuilder {
+    /// Append [`PathItem`] with runtime expression to the [`Callback`]. If expression already
+    /// exists the [`Operation`]s of the [`PathItem`] will be merged with the existing one.
+    pub fn path<E: Into<String>>(mut self, expression: E, item: PathItem) -> Self {
+        let expression = expression.into();
+        if let Some(existing_item) = self.paths.get_mut(&expression) {
+            existing_item.merge_operations(item);
+        } else {
+            self.paths.insert(expression, item);
+        }
+
+        self
+    }
+
+    /// Appends a [`Path`] to the [`Callback`]. [`Path::path`] of the implementing type is used
+    /// as the runtime expression of the callback request.
+    ///
+    /// # Examples
+    ///
+    /// _**Append `OnEvent` path to the callback.**_
+    /// ```rust
+    /// # struct OnEvent;
+    /// # impl utoipa::Path for OnEvent {
+    /// #   fn methods() -> Vec<utoipa::openapi::path::HttpMethod> { vec![] }
+    /// #   fn path() -> String { String::new() }
+    /// #   fn operation() -> utoipa::openapi::path::Operation {
+    /// #        utoipa::openapi::path::Operation::new()
+    /// #   }
+    /// # }
+    /// let callback = utoipa::openapi::path::CallbackBuilder::new();
+    /// let _ = callback.path_from::<OnEvent>();
+    /// ```
+    pub fn path_from<P: Path>(self) -> Self {
+        self.path(P::path(), PathItem::from_path::<P>())
+    }
+
+    /// Add openapi extensions (x-something) to the [`Callback`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
+}
+
+impl From<CallbackBuilder> for RefOr<Callback> {
+    fn from(builder: CallbackBuilder) -> Self {
+        Self::T(builder.build())
+    }
+}
+
 builder! {
     ParameterBuilder;
 
@@ -817,6 +1200,18 @@ impl ParameterBuilder {
     }
 }
 
+impl From<ParameterBuilder> for RefOr<Parameter> {
+    fn from(builder: ParameterBuilder) -> Self {
+        Self::T(builder.build())
+    }
+}
+
+impl From<Ref> for RefOr<Parameter> {
+    fn from(r: Ref) -> Self {
+        Self::Ref(r)
+    }
+}
+
 /// In definition of [`Parameter`].
 #[derive(Serialize, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "lowercase")]
@@ -871,8 +1266,12 @@ pub enum ParameterStyle {
 
 #[cfg(test)]
 mod tests {
-    use super::{HttpMethod, Operation, OperationBuilder};
-    use crate::openapi::{security::SecurityRequirement, server::Server, PathItem, PathsBuilder};
+    use insta::assert_json_snapshot;
+
+    use super::{CallbackBuilder, HttpMethod, Operation, OperationBuilder, PathItemBuilder};
+    use crate::openapi::{
+        security::SecurityRequirement, server::Server, PathItem, PathsBuilder, Response,
+    };
 
     #[test]
     fn test_path_order() {
@@ -1012,4 +1411,112 @@ mod tests {
 
         assert!(operation.servers.is_some());
     }
+
+    #[test]
+    fn operation_builder_callback() {
+        let callback = CallbackBuilder::new().path(
+            "{$request.body#/callbackUrl}",
+            PathItem::new(
+                HttpMethod::Post,
+                OperationBuilder::new().response("200", Response::new("Callback received")),
+            ),
+        );
+        let operation = OperationBuilder::new()
+            .callback("onEvent", callback)
+            .build();
+
+        assert_json_snapshot!(operation, @r###"
+        {
+          "responses": {},
+          "callbacks": {
+            "onEvent": {
+              "{$request.body#/callbackUrl}": {
+                "post": {
+                  "responses": {
+                    "200": {
+                      "description": "Callback received"
+                    }
+                  }
+                }
+              }
+            }
+          }
+        }
+        "###);
+    }
+
+    #[test]
+    fn path_item_query_and_additional_operations() {
+        let path_item = PathItem::from_http_methods(
+            [HttpMethod::Query, HttpMethod::Custom("COPY".to_string())],
+            OperationBuilder::new().operation_id(Some("search")),
+        );
+
+        assert_json_snapshot!(path_item, @r###"
+        {
+          "query": {
+            "operationId": "search",
+            "responses": {}
+          },
+          "additionalOperations": {
+            "COPY": {
+              "operationId": "search",
+              "responses": {}
+            }
+          }
+        }
+        "###);
+
+        let deserialized: PathItem =
+            serde_json::from_value(serde_json::to_value(&path_item).unwrap()).unwrap();
+        assert_eq!(
+            deserialized
+                .operations()
+                .map(|(method, _)| method)
+                .collect::<Vec<_>>(),
+            [HttpMethod::Query, HttpMethod::Custom("COPY".to_string())]
+        );
+        assert_eq!(
+            serde_json::to_value([HttpMethod::Query, HttpMethod::Custom("COPY".to_string())])
+                .unwrap(),
+            serde_json::json!(["query", "COPY"])
+        );
+    }
+
+    #[test]
+    fn custom_http_method_named_after_predefined_method() {
+        assert_eq!(HttpMethod::from("POST"), HttpMethod::Post);
+        assert_eq!(HttpMethod::Custom("get".to_string()), HttpMethod::Get);
+        assert_ne!(
+            HttpMethod::Custom("COPY".to_string()),
+            HttpMethod::Custom("copy".to_string())
+        );
+
+        let path_item = PathItemBuilder::new()
+            .operation(
+                HttpMethod::from("POST"),
+                OperationBuilder::new().operation_id(Some("create")),
+            )
+            .operation(
+                HttpMethod::Custom("Get".to_string()),
+                OperationBuilder::new().operation_id(Some("get")),
+            )
+            .build();
+
+        assert_json_snapshot!(path_item, @r###"
+        {
+          "get": {
+            "operationId": "get",
+            "responses": {}
+          },
+          "post": {
+            "operationId": "create",
+            "responses": {}
+          }
+        }
+        "###);
+        assert!(path_item
+            .operation(&HttpMethod::Custom("GET".to_string()))
+            .is_some());
+    }
 }
@@ -6,7 +6,7 @@ use std::collections::BTreeMap;
 use serde::{Deserialize, Serialize};
 
 use super::extensions::Extensions;
-use super::{builder, set_value, Content, Required};
+use super::{builder, set_value, Content, Ref, RefOr, Required};
 
 builder! {
     RequestBodyBuilder;
@@ -67,6 +67,18 @@ impl RequestBodyBuilder {
     }
 }
 
+impl From<RequestBodyBuilder> for RefOr<RequestBody> {
+    fn from(builder: RequestBodyBuilder) -> Self {
+        Self::T(builder.build())
+    }
+}
+
+impl From<Ref> for RefOr<RequestBody> {
+    fn from(r: Ref) -> Self {
+        Self::Ref(r)
+    }
+}
+
 /// Trait with convenience functions for documenting request bodies.
 ///
 /// With a single method call we can add [`Content`] to our [`RequestBodyBuilder`] and
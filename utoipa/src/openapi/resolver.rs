@@ -0,0 +1,459 @@
+//! Implements resolving of [`Ref`]s to the [`Components`] of the [`OpenApi`] document.
+//!
+//! Use [`OpenApi::resolve`] or [`OpenApi::resolve_ref`] to get the concrete component of a
+//! reference and [`OpenApi::dereference`] to create a copy of the document with all the
+//! references replaced with the components they point to.
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+
+use serde_json::Value;
+
+use super::example::Example;
+use super::header::Header;
+use super::link::Link;
+use super::path::{Callback, Parameter, PathItem};
+use super::request_body::RequestBody;
+use super::{Components, OpenApi, Ref, RefOr, Response, Schema};
+
+const COMPONENTS: &str = "#/components/";
+
+/// Type of reusable object that can be referenced from [`Components`] with a [`Ref`].
+pub trait Referenceable: Sized {
+    /// Name of the [`Components`] field holding this type of components e.g. `schemas`.
+    const COMPONENT: &'static str;
+
+    /// Get the components of this type from the given [`Components`].
+    fn components(components: &Components) -> &BTreeMap<String, RefOr<Self>>;
+}
+
+macro_rules! impl_referenceable {
+    ( $( $ty:ty => $component:ident as $name:literal ),* ) => {
+        $(
+            impl Referenceable for $ty {
+                const COMPONENT: &'static str = $name;
+
+                fn components(components: &Components) -> &BTreeMap<String, RefOr<Self>> {
+                    &components.$component
+                }
+            }
+        )*
+    };
+}
+
+impl_referenceable!(
+    Schema => schemas as "schemas",
+    Response => responses as "responses",
+    Parameter => parameters as "parameters",
+    Example => examples as "examples",
+    RequestBody => request_bodies as "requestBodies",
+    Header => headers as "headers",
+    Link => links as "links",
+    Callback => callbacks as "callbacks",
+    PathItem => path_items as "pathItems"
+);
+
+/// Error returned when [`Ref`] cannot be resolved.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolveError {
+    /// Referenced component does not exist in [`Components`].
+    NotFound {
+        /// The reference that cannot be resolved.
+        reference: String,
+    },
+    /// Reference does not point to the expected type of component in [`Components`], e.g.
+    /// reference to an external document or a `Response` reference used in place of a `Schema`.
+    Unsupported {
+        /// The unsupported reference.
+        reference: String,
+    },
+    /// References refer to each other without ever reaching a concrete component.
+    Cycle {
+        /// The reference that closes the cycle.
+        reference: String,
+    },
+    /// Dereferenced document is not a valid [`OpenApi`] document.
+    InvalidDocument {
+        /// Reason why the document is invalid.
+        reason: String,
+    },
+}
+
+impl Display for ResolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound { reference } => write!(f, "reference `{reference}` does not exist"),
+            Self::Unsupported { reference } => {
+                write!(f, "reference `{reference}` is not supported")
+            }
+            Self::Cycle { reference } => write!(f, "reference `{reference}` is cyclic"),
+            Self::InvalidDocument { reason } => {
+                write!(f, "dereferenced document is invalid: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ResolveError {}
+
+/// Unescape JSON Pointer reference token according to RFC 6901.
+pub(super) fn unescape(token: &str) -> String {
+    token.replace("~1", "/").replace("~0", "~")
+}
+
+pub(super) fn resolve_ref<'a, T: Referenceable>(
+    openapi: &'a OpenApi,
+    reference: &Ref,
+) -> Result<&'a T, ResolveError> {
+    let prefix = format!("{COMPONENTS}{}/", T::COMPONENT);
+    let mut location = reference.ref_location.clone();
+    let mut visited = Vec::new();
+
+    loop {
+        let name = location
+            .strip_prefix(&prefix)
+            .map(unescape)
+            .ok_or_else(|| ResolveError::Unsupported {
+                reference: location.clone(),
+            })?;
+        if visited.contains(&location) {
+            return Err(ResolveError::Cycle {
+                reference: location,
+            });
+        }
+
+        match openapi
+            .components
+            .as_ref()
+            .and_then(|components| T::components(components).get(&name))
+        {
+            Some(RefOr::T(component)) => return Ok(component),
+            Some(RefOr::Ref(next)) => {
+                visited.push(std::mem::replace(&mut location, next.ref_location.clone()));
+            }
+            None => {
+                return Err(ResolveError::NotFound {
+                    reference: location,
+                })
+            }
+        }
+    }
+}
+
+pub(super) fn dereference(openapi: &OpenApi) -> Result<OpenApi, ResolveError> {
+    let invalid = |error: serde_json::Error| ResolveError::InvalidDocument {
+        reason: error.to_string(),
+    };
+
+    let document = serde_json::to_value(openapi).map_err(invalid)?;
+    let mut dereferenced = document.clone();
+    inline_refs(&document, &mut dereferenced, &mut Vec::new())?;
+
+    serde_json::from_value(dereferenced).map_err(invalid)
+}
+
+/// Recursively replace every `$ref` to `#/components/` with a copy of the referenced component.
+/// References that are already being inlined higher up in the `stack` are cyclic and are left as
+/// is.
+fn inline_refs(
+    document: &Value,
+    value: &mut Value,
+    stack: &mut Vec<String>,
+) -> Result<(), ResolveError> {
+    match value {
+        Value::Object(object) => {
+            if let Some(Value::String(reference)) = object.get("$ref") {
+                if !reference.starts_with(COMPONENTS) || stack.contains(reference) {
+                    return Ok(());
+                }
+
+                let reference = reference.clone();
+                let mut component =
+                    document.pointer(&reference[1..]).cloned().ok_or_else(|| {
+                        ResolveError::NotFound {
+                            reference: reference.clone(),
+                        }
+                    })?;
+
+                stack.push(reference);
+                inline_refs(document, &mut component, stack)?;
+                stack.pop();
+
+                // siblings of the `$ref` e.g. `description` override the values of the component
+                if let Value::Object(component) = &mut component {
+                    object.remove("$ref");
+                    component.append(object);
+                }
+                *value = component;
+            } else {
+                for value in object.values_mut() {
+                    inline_refs(document, value, stack)?;
+                }
+            }
+        }
+        Value::Array(array) => {
+            for value in array {
+                inline_refs(document, value, stack)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
+/// Get locations of all the components reachable from the paths, webhooks and other parts of
+/// the document outside of [`Components`], following the references of the reachable
+/// components transitively. Locations are in format `#/components/{component}/{name}`.
+///
+/// Returns error if the document cannot be serialized in which case reachability is unknown.
+pub(super) fn reachable_components(
+    openapi: &OpenApi,
+) -> Result<BTreeSet<String>, serde_json::Error> {
+    let mut document = serde_json::to_value(openapi)?;
+    let components = document
+        .as_object_mut()
+        .and_then(|document| document.remove("components"))
+        .map(|components| serde_json::json!({ "components": components }))
+        .unwrap_or_default();
+
+    let mut pending = Vec::new();
+    collect_refs(&document, &mut pending);
+
+    let mut reachable = BTreeSet::new();
+    while let Some(reference) = pending.pop() {
+        if !reference.starts_with(COMPONENTS) || reachable.contains(&reference) {
+            continue;
+        }
+        if let Some(component) = components.pointer(&reference[1..]) {
+            collect_refs(component, &mut pending);
+        }
+        reachable.insert(reference);
+    }
+
+    Ok(reachable)
+}
+
+/// Collect all `$ref`s and [`Discriminator`][discriminator] mapping references of the `value`.
+///
+/// [discriminator]: super::Discriminator
+fn collect_refs(value: &Value, references: &mut Vec<String>) {
+    match value {
+        Value::Object(object) => {
+            if let Some(Value::String(reference)) = object.get("$ref") {
+                references.push(reference.clone());
+            }
+            if let Some(Value::Object(mapping)) = object
+                .get("discriminator")
+                .and_then(|discriminator| discriminator.get("mapping"))
+            {
+                references.extend(
+                    mapping
+                        .values()
+                        .filter_map(Value::as_str)
+                        .map(ToString::to_string),
+                );
+            }
+            object
+                .values()
+                .for_each(|value| collect_refs(value, references));
+        }
+        Value::Array(array) => array
+            .iter()
+            .for_each(|value| collect_refs(value, references)),
+        _ => (),
+    }
+}
+
+/// Retain only the referenceable components whose location `#/components/{component}/{name}`
+/// matches the given predicate. [`Components::security_schemes`] are not referenceable and are
+/// retained as is.
+pub(super) fn retain_components<F: Fn(&str) -> bool>(components: &mut Components, f: F) {
+    macro_rules! retain {
+        ( $( $field:ident as $component:literal ),* ) => {
+            $(
+                components.$field.retain(|name, _| {
+                    f(&format!("{COMPONENTS}{}/{}", $component, super::validation::escape(name)))
+                });
+            )*
+        };
+    }
+
+    retain!(
+        schemas as "schemas",
+        responses as "responses",
+        parameters as "parameters",
+        examples as "examples",
+        request_bodies as "requestBodies",
+        headers as "headers",
+        links as "links",
+        callbacks as "callbacks",
+        path_items as "pathItems"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_json_snapshot;
+
+    use crate::openapi::path::{OperationBuilder, ParameterBuilder, ParameterIn};
+    use crate::openapi::{
+        ArrayBuilder, ComponentsBuilder, Content, HttpMethod, Info, ObjectBuilder, OpenApiBuilder,
+        PathItem, PathsBuilder, ResponseBuilder, Type,
+    };
+
+    use super::*;
+
+    fn openapi() -> OpenApi {
+        OpenApiBuilder::new()
+            .info(Info::new("api", "1.0.0"))
+            .paths(
+                PathsBuilder::new().path(
+                    "/pets",
+                    PathItem::new(
+                        HttpMethod::Get,
+                        OperationBuilder::new()
+                            .parameter(Ref::new("#/components/parameters/Limit"))
+                            .response("200", Ref::from_response_name("Pets")),
+                    ),
+                ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Pet",
+                        ObjectBuilder::new()
+                            .property("name", ObjectBuilder::new().schema_type(Type::String))
+                            .property(
+                                "children",
+                                ArrayBuilder::new().items(Ref::from_schema_name("Pet")),
+                            ),
+                    )
+                    .schema("Animal", Ref::from_schema_name("Pet"))
+                    .schema("Loop", Ref::from_schema_name("Loop"))
+                    .response(
+                        "Pets",
+                        ResponseBuilder::new().description("Pets").content(
+                            "application/json",
+                            Content::new(Some(
+                                ArrayBuilder::new().items(Ref::from_schema_name("Animal")),
+                            )),
+                        ),
+                    )
+                    .parameter(
+                        "Limit",
+                        ParameterBuilder::new()
+                            .name("limit")
+                            .parameter_in(ParameterIn::Query),
+                    )
+                    .build(),
+            ))
+            .build()
+    }
+
+    #[test]
+    fn resolve_refs_to_components() {
+        let openapi = openapi();
+
+        let animal = RefOr::Ref(Ref::from_schema_name("Animal"));
+        let schema = openapi
+            .resolve::<Schema>(&animal)
+            .expect("Animal should resolve to Pet");
+        assert!(matches!(schema, Schema::Object(_)));
+
+        let parameter = openapi
+            .resolve_ref::<Parameter>(&Ref::new("#/components/parameters/Limit"))
+            .expect("Limit should resolve");
+        assert_eq!(parameter.name, "limit");
+
+        assert_eq!(
+            openapi.resolve_ref::<Schema>(&Ref::from_schema_name("Owner")),
+            Err(ResolveError::NotFound {
+                reference: "#/components/schemas/Owner".to_string()
+            })
+        );
+        assert_eq!(
+            openapi.resolve_ref::<Schema>(&Ref::from_response_name("Pets")),
+            Err(ResolveError::Unsupported {
+                reference: "#/components/responses/Pets".to_string()
+            })
+        );
+        assert_eq!(
+            openapi.resolve_ref::<Schema>(&Ref::from_schema_name("Loop")),
+            Err(ResolveError::Cycle {
+                reference: "#/components/schemas/Loop".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn remove_unused_components() {
+        let mut openapi = openapi();
+        if let Some(components) = openapi.components.as_mut() {
+            components
+                .schemas
+                .insert("Unused".to_string(), Ref::from_schema_name("Pet").into());
+            components.responses.insert(
+                "Unused".to_string(),
+                ResponseBuilder::new().description("Unused").build().into(),
+            );
+        }
+
+        openapi.remove_unused_components();
+
+        let components = openapi.components.expect("components should exist");
+        assert_eq!(
+            components.schemas.keys().collect::<Vec<_>>(),
+            ["Animal", "Pet"]
+        );
+        assert_eq!(components.responses.keys().collect::<Vec<_>>(), ["Pets"]);
+        assert_eq!(components.parameters.keys().collect::<Vec<_>>(), ["Limit"]);
+    }
+
+    #[test]
+    fn dereference_openapi_with_recursive_schema() {
+        let dereferenced = openapi().dereference().expect("should dereference");
+
+        assert_json_snapshot!(dereferenced.paths, @r##"
+        {
+          "/pets": {
+            "get": {
+              "parameters": [
+                {
+                  "name": "limit",
+                  "in": "query",
+                  "required": false
+                }
+              ],
+              "responses": {
+                "200": {
+                  "description": "Pets",
+                  "content": {
+                    "application/json": {
+                      "schema": {
+                        "type": "array",
+                        "items": {
+                          "type": "object",
+                          "properties": {
+                            "children": {
+                              "type": "array",
+                              "items": {
+                                "$ref": "#/components/schemas/Pet"
+                              }
+                            },
+                            "name": {
+                              "type": "string"
+                            }
+                          }
+                        }
+                      }
+                    }
+                  }
+                }
+              }
+            }
+          }
+        }
+        "##);
+    }
+}
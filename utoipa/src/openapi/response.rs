@@ -123,7 +123,7 @@ builder! {
 
         /// Map of headers identified by their name. `Content-Type` header will be ignored.
         #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
-        pub headers: BTreeMap<String, Header>,
+        pub headers: BTreeMap<String, RefOr<Header>>,
 
         /// Map of response [`Content`] objects identified by response body content type e.g `application/json`.
         ///
@@ -169,8 +169,8 @@ impl ResponseBuilder {
     }
 
     /// Add response [`Header`].
-    pub fn header<S: Into<String>>(mut self, name: S, header: Header) -> Self {
-        self.headers.insert(name.into(), header);
+    pub fn header<S: Into<String>, H: Into<RefOr<Header>>>(mut self, name: S, header: H) -> Self {
+        self.headers.insert(name.into(), header.into());
 
         self
     }
@@ -7,10 +7,15 @@ use std::collections::BTreeMap;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use super::example::Example;
 use super::extensions::Extensions;
+use super::header::Header;
+use super::link::Link;
+use super::path::{Callback, Parameter, PathItem};
+use super::request_body::RequestBody;
 use super::RefOr;
 use super::{builder, security::SecurityScheme, set_value, xml::Xml, Deprecated, Response};
-use crate::{ToResponse, ToSchema};
+use crate::{IntoParams, ToResponse, ToSchema};
 
 macro_rules! component_from_builder {
     ( $name:ident ) => {
@@ -73,12 +78,54 @@ builder! {
         #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
         pub responses: BTreeMap<String, RefOr<Response>>,
 
+        /// Map of reusable [OpenAPI Parameter Object][parameter]s.
+        ///
+        /// [parameter]: https://spec.openapis.org/oas/latest.html#parameter-object
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub parameters: BTreeMap<String, RefOr<Parameter>>,
+
+        /// Map of reusable [OpenAPI Example Object][example]s.
+        ///
+        /// [example]: https://spec.openapis.org/oas/latest.html#example-object
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub examples: BTreeMap<String, RefOr<Example>>,
+
+        /// Map of reusable [OpenAPI Request Body Object][request_body]s.
+        ///
+        /// [request_body]: https://spec.openapis.org/oas/latest.html#request-body-object
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub request_bodies: BTreeMap<String, RefOr<RequestBody>>,
+
+        /// Map of reusable [OpenAPI Header Object][header]s.
+        ///
+        /// [header]: https://spec.openapis.org/oas/latest.html#header-object
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub headers: BTreeMap<String, RefOr<Header>>,
+
         /// Map of reusable [OpenAPI Security Scheme Object][security_scheme]s.
         ///
         /// [security_scheme]: https://spec.openapis.org/oas/latest.html#security-scheme-object
         #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
         pub security_schemes: BTreeMap<String, SecurityScheme>,
 
+        /// Map of reusable [OpenAPI Link Object][link]s.
+        ///
+        /// [link]: https://spec.openapis.org/oas/latest.html#link-object
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub links: BTreeMap<String, RefOr<Link>>,
+
+        /// Map of reusable [OpenAPI Callback Object][callback]s.
+        ///
+        /// [callback]: https://spec.openapis.org/oas/latest.html#callback-object
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub callbacks: BTreeMap<String, RefOr<Callback>>,
+
+        /// Map of reusable [OpenAPI Path Item Object][path_item]s.
+        ///
+        /// [path_item]: https://spec.openapis.org/oas/latest.html#path-item-object
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub path_items: BTreeMap<String, RefOr<PathItem>>,
+
         /// Optional extensions "x-something".
         #[serde(skip_serializing_if = "Option::is_none", flatten)]
         pub extensions: Option<Extensions>,
@@ -259,6 +306,128 @@ impl ComponentsBuilder {
         self
     }
 
+    /// Add [`Parameter`] to [`Components`].
+    ///
+    /// Method accepts two arguments; `name` of the reusable parameter and `parameter` which is the
+    /// reusable parameter itself.
+    pub fn parameter<S: Into<String>, P: Into<RefOr<Parameter>>>(
+        mut self,
+        name: S,
+        parameter: P,
+    ) -> Self {
+        self.parameters.insert(name.into(), parameter.into());
+
+        self
+    }
+
+    /// Add [`Parameter`]s to [`Components`] from type implementing [`trait@IntoParams`] trait.
+    ///
+    /// Each parameter will be added with the name of the parameter. `parameter_in_provider` is
+    /// passed to the [`IntoParams::into_params`] for resolving the location of the parameters.
+    ///
+    /// # Examples
+    ///
+    /// _**Add reusable pagination parameters.**_
+    /// ```rust
+    /// # use utoipa::{IntoParams, openapi::schema::ComponentsBuilder};
+    /// #[derive(IntoParams)]
+    /// #[into_params(parameter_in = Query)]
+    /// struct Pagination {
+    ///     page: u64,
+    ///     per_page: u64,
+    /// }
+    ///
+    /// let _ = ComponentsBuilder::new().parameters_from::<Pagination>(|| None).build();
+    /// ```
+    pub fn parameters_from<I: IntoParams>(
+        mut self,
+        parameter_in_provider: impl Fn() -> Option<super::path::ParameterIn>,
+    ) -> Self {
+        self.parameters.extend(
+            I::into_params(parameter_in_provider)
+                .into_iter()
+                .map(|parameter| (parameter.name.clone(), parameter.into())),
+        );
+
+        self
+    }
+
+    /// Add [`Example`] to [`Components`].
+    ///
+    /// Method accepts two arguments; `name` of the reusable example and `example` which is the
+    /// reusable example itself.
+    pub fn example<S: Into<String>, E: Into<RefOr<Example>>>(
+        mut self,
+        name: S,
+        example: E,
+    ) -> Self {
+        self.examples.insert(name.into(), example.into());
+
+        self
+    }
+
+    /// Add [`RequestBody`] to [`Components`].
+    ///
+    /// Method accepts two arguments; `name` of the reusable request body and `request_body` which
+    /// is the reusable request body itself.
+    pub fn request_body<S: Into<String>, R: Into<RefOr<RequestBody>>>(
+        mut self,
+        name: S,
+        request_body: R,
+    ) -> Self {
+        self.request_bodies.insert(name.into(), request_body.into());
+
+        self
+    }
+
+    /// Add [`Header`] to [`Components`].
+    ///
+    /// Method accepts two arguments; `name` of the reusable header and `header` which is the
+    /// reusable header itself.
+    pub fn header<S: Into<String>, H: Into<RefOr<Header>>>(mut self, name: S, header: H) -> Self {
+        self.headers.insert(name.into(), header.into());
+
+        self
+    }
+
+    /// Add [`Link`] to [`Components`].
+    ///
+    /// Method accepts two arguments; `name` of the reusable link and `link` which is the
+    /// reusable link itself.
+    pub fn link<S: Into<String>, L: Into<RefOr<Link>>>(mut self, name: S, link: L) -> Self {
+        self.links.insert(name.into(), link.into());
+
+        self
+    }
+
+    /// Add [`Callback`] to [`Components`].
+    ///
+    /// Method accepts two arguments; `name` of the reusable callback and `callback` which is the
+    /// reusable callback itself.
+    pub fn callback<S: Into<String>, C: Into<RefOr<Callback>>>(
+        mut self,
+        name: S,
+        callback: C,
+    ) -> Self {
+        self.callbacks.insert(name.into(), callback.into());
+
+        self
+    }
+
+    /// Add [`PathItem`] to [`Components`].
+    ///
+    /// Method accepts two arguments; `name` of the reusable path item and `path_item` which is
+    /// the reusable path item itself.
+    pub fn path_item<S: Into<String>, P: Into<RefOr<PathItem>>>(
+        mut self,
+        name: S,
+        path_item: P,
+    ) -> Self {
+        self.path_items.insert(name.into(), path_item.into());
+
+        self
+    }
+
     /// Add openapi extensions (x-something) of the API.
     pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
         set_value!(self extensions extensions)
@@ -296,6 +465,27 @@ pub enum Schema {
     ///
     /// [composite]: https://spec.openapis.org/oas/latest.html#components-object
     AnyOf(AnyOf),
+
+    /// Creates a _Not_ type [composite Object][composite] schema. Value is valid against this
+    /// schema if it is **not** valid against the given schema.
+    ///
+    /// [composite]: https://spec.openapis.org/oas/latest.html#components-object
+    Not(Not),
+
+    /// Creates a [boolean schema][boolean]. _`true`_ schema accepts any value and _`false`_
+    /// schema accepts no value at all. Boolean schema can be used anywhere a [`Schema`] is
+    /// accepted e.g. as [`Object::properties`] value to disallow a property.
+    ///
+    /// # Examples
+    ///
+    /// _**Create an object which does not allow `id` property.**_
+    /// ```rust
+    /// # use utoipa::openapi::schema::{ObjectBuilder, Schema};
+    /// let object = ObjectBuilder::new().property("id", Schema::Bool(false));
+    /// ```
+    ///
+    /// [boolean]: https://json-schema.org/draft/2020-12/json-schema-core#name-boolean-json-schemas
+    Bool(bool),
 }
 
 impl Default for Schema {
@@ -304,6 +494,296 @@ impl Default for Schema {
     }
 }
 
+impl From<bool> for Schema {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl Schema {
+    /// Get mutable reference to the local `$defs` of the [`Schema`]. Returns `None` for
+    /// [`Schema::Bool`] which cannot have definitions.
+    fn defs_mut(&mut self) -> Option<&mut BTreeMap<String, RefOr<Schema>>> {
+        match self {
+            Self::Array(array) => Some(&mut array.defs),
+            Self::Object(object) => Some(&mut object.defs),
+            Self::OneOf(one_of) => Some(&mut one_of.defs),
+            Self::AllOf(all_of) => Some(&mut all_of.defs),
+            Self::AnyOf(any_of) => Some(&mut any_of.defs),
+            Self::Not(not) => Some(&mut not.defs),
+            Self::Bool(_) => None,
+        }
+    }
+
+    /// Call given function with every reference location found within this [`Schema`] and all of
+    /// its sub schemas including the `$ref`s of [`RefOr::Ref`]s and [`Discriminator`] mapping
+    /// values.
+    fn for_each_ref_location_mut<F: FnMut(&mut String)>(&mut self, f: &mut F) {
+        fn ref_or<F: FnMut(&mut String)>(ref_or: &mut RefOr<Schema>, f: &mut F) {
+            match ref_or {
+                RefOr::Ref(reference) => f(&mut reference.ref_location),
+                RefOr::T(schema) => schema.for_each_ref_location_mut(f),
+            }
+        }
+        fn additional_properties<F: FnMut(&mut String)>(
+            additional_properties: &mut AdditionalProperties<Schema>,
+            f: &mut F,
+        ) {
+            if let AdditionalProperties::RefOr(schema) = additional_properties {
+                ref_or(schema, f)
+            }
+        }
+        fn discriminator<F: FnMut(&mut String)>(discriminator: &mut Discriminator, f: &mut F) {
+            discriminator.mapping.values_mut().for_each(f);
+        }
+
+        match self {
+            Self::Array(array) => {
+                if let ArrayItems::RefOrSchema(items) = &mut array.items {
+                    ref_or(items, f);
+                }
+                array
+                    .prefix_items
+                    .iter_mut()
+                    .for_each(|schema| schema.for_each_ref_location_mut(f));
+                if let Some(contains) = &mut array.contains {
+                    ref_or(contains, f);
+                }
+                if let Some(ArrayItems::RefOrSchema(items)) = array.unevaluated_items.as_deref_mut()
+                {
+                    ref_or(items, f);
+                }
+                array.defs.values_mut().for_each(|schema| ref_or(schema, f));
+            }
+            Self::Object(object) => {
+                object
+                    .properties
+                    .values_mut()
+                    .chain(object.pattern_properties.values_mut())
+                    .chain(object.dependent_schemas.values_mut())
+                    .chain(object.defs.values_mut())
+                    .for_each(|schema| ref_or(schema, f));
+                [
+                    &mut object.if_schema,
+                    &mut object.then_schema,
+                    &mut object.else_schema,
+                    &mut object.not_schema,
+                ]
+                .into_iter()
+                .flatten()
+                .for_each(|schema| ref_or(schema, f));
+                [
+                    &mut object.additional_properties,
+                    &mut object.unevaluated_properties,
+                ]
+                .into_iter()
+                .flatten()
+                .for_each(|schema| additional_properties(schema, f));
+                if let Some(property_names) = &mut object.property_names {
+                    property_names.for_each_ref_location_mut(f);
+                }
+            }
+            Self::OneOf(one_of) => {
+                one_of
+                    .items
+                    .iter_mut()
+                    .chain(one_of.defs.values_mut())
+                    .for_each(|schema| ref_or(schema, f));
+                if let Some(mapping) = &mut one_of.discriminator {
+                    discriminator(mapping, f);
+                }
+            }
+            Self::AllOf(all_of) => {
+                all_of
+                    .items
+                    .iter_mut()
+                    .chain(all_of.defs.values_mut())
+                    .for_each(|schema| ref_or(schema, f));
+                if let Some(unevaluated_properties) = &mut all_of.unevaluated_properties {
+                    additional_properties(unevaluated_properties, f);
+                }
+                if let Some(mapping) = &mut all_of.discriminator {
+                    discriminator(mapping, f);
+                }
+            }
+            Self::AnyOf(any_of) => {
+                any_of
+                    .items
+                    .iter_mut()
+                    .chain(any_of.defs.values_mut())
+                    .for_each(|schema| ref_or(schema, f));
+                if let Some(mapping) = &mut any_of.discriminator {
+                    discriminator(mapping, f);
+                }
+            }
+            Self::Not(not) => {
+                ref_or(&mut not.item, f);
+                not.defs.values_mut().for_each(|schema| ref_or(schema, f));
+            }
+            Self::Bool(_) => (),
+        }
+    }
+}
+
+/// Create a self-contained [`Schema`] of given [`ToSchema`] type.
+///
+/// All the schemas the type depends on are collected with [`ToSchema::schemas`] to the `$defs`
+/// of the returned [`Schema`] and every `#/components/schemas/{name}` reference is rewritten to
+/// `#/$defs/{name}`. References to the type itself are rewritten to `#` referring to the root of
+/// the schema. The returned [`Schema`] can be used as a plain JSON Schema without an
+/// [`OpenApi`][openapi] document around it.
+///
+/// # Examples
+///
+/// _**Create standalone schema of a `Pet`.**_
+/// ```rust
+/// # use utoipa::ToSchema;
+/// #[derive(ToSchema)]
+/// struct Owner {
+///     name: String,
+/// }
+///
+/// #[derive(ToSchema)]
+/// struct Pet {
+///     name: String,
+///     owner: Owner,
+/// }
+///
+/// let schema = utoipa::openapi::schema::standalone::<Pet>();
+/// let json = serde_json::to_value(&schema).unwrap();
+///
+/// assert_eq!(json["properties"]["owner"]["$ref"], "#/$defs/Owner");
+/// assert_eq!(json["$defs"]["Owner"]["type"], "object");
+/// ```
+///
+/// [openapi]: crate::openapi::OpenApi
+pub fn standalone<T: ToSchema>() -> Schema {
+    let mut schemas = Vec::new();
+    T::schemas(&mut schemas);
+
+    standalone_from(&T::name(), T::schema(), schemas)
+}
+
+/// Create a self-contained [`Schema`] from the given root `schema` named `name` and its
+/// `dependencies`. See [`standalone`] for more details.
+pub(crate) fn standalone_from<I: IntoIterator<Item = (String, RefOr<Schema>)>>(
+    name: &str,
+    schema: RefOr<Schema>,
+    dependencies: I,
+) -> Schema {
+    const COMPONENTS_SCHEMAS: &str = "#/components/schemas/";
+
+    let mut schema = match schema {
+        RefOr::T(schema) => schema,
+        RefOr::Ref(reference) => AllOfBuilder::new().item(reference).build().into(),
+    };
+
+    if let Some(defs) = schema.defs_mut() {
+        defs.extend(dependencies.into_iter().filter(|(def, _)| def != name));
+    }
+
+    schema.for_each_ref_location_mut(&mut |location| {
+        if let Some(def) = location.strip_prefix(COMPONENTS_SCHEMAS) {
+            *location = if def == name {
+                String::from("#")
+            } else {
+                format!("#/$defs/{def}")
+            };
+        }
+    });
+
+    schema
+}
+
+/// Dialect of [JSON Schema 2020-12][json_schema] used as the `$schema` of [`JsonSchema`] documents.
+///
+/// [json_schema]: https://json-schema.org/draft/2020-12/json-schema-core
+pub const JSON_SCHEMA_2020_12: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Standalone [JSON Schema][json_schema] document of a single [`Schema`].
+///
+/// Unlike [`Schema`] the document declares its `$schema` dialect and it is meant to be used
+/// without an [`OpenApi`][openapi] document around it. Use [`JsonSchema::from_schema`] or
+/// [`utoipa::json_schema`][json_schema_fn] to create a document of a [`ToSchema`] type.
+///
+/// [json_schema]: https://json-schema.org/draft/2020-12/json-schema-core
+/// [openapi]: crate::openapi::OpenApi
+/// [json_schema_fn]: crate::json_schema
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct JsonSchema {
+    /// JSON Schema dialect of the document. By default [`JSON_SCHEMA_2020_12`].
+    #[serde(rename = "$schema")]
+    pub schema: String,
+
+    /// Root [`Schema`] of the document.
+    #[serde(flatten)]
+    pub root: Schema,
+}
+
+impl JsonSchema {
+    /// Construct a new [`JsonSchema`] document with given root [`Schema`] and the default
+    /// [`JSON_SCHEMA_2020_12`] dialect.
+    ///
+    /// [`Schema::Bool`] root is wrapped in [`AllOf`] since boolean schema cannot declare its
+    /// dialect.
+    pub fn new<S: Into<Schema>>(root: S) -> Self {
+        let root = match root.into() {
+            Schema::Bool(value) => AllOfBuilder::new().item(Schema::Bool(value)).into(),
+            root => root,
+        };
+
+        Self {
+            schema: JSON_SCHEMA_2020_12.to_string(),
+            root,
+        }
+    }
+
+    /// Construct a new [`JsonSchema`] document of given [`ToSchema`] type.
+    ///
+    /// All the schemas the type depends on are added to the `$defs` of the root schema and the
+    /// references are rewritten to point to them. See [`standalone`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::schema::JsonSchema;
+    /// #[derive(utoipa::ToSchema)]
+    /// struct Pet {
+    ///     name: String,
+    /// }
+    ///
+    /// let json_schema = JsonSchema::from_schema::<Pet>();
+    /// ```
+    pub fn from_schema<T: ToSchema>() -> Self {
+        Self::new(standalone::<T>())
+    }
+
+    /// Override the default [`JSON_SCHEMA_2020_12`] dialect of the document.
+    pub fn dialect<S: Into<String>>(mut self, schema: S) -> Self {
+        self.schema = schema.into();
+        self
+    }
+
+    /// Converts this [`JsonSchema`] to JSON String. This method essentially calls [`serde_json::to_string`] method.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Converts this [`JsonSchema`] to pretty JSON String. This method essentially calls [`serde_json::to_string_pretty`] method.
+    pub fn to_pretty_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Converts this [`JsonSchema`] to YAML String. This method essentially calls [`yaml_serde::to_string`].
+    #[cfg(feature = "yaml")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "yaml")))]
+    pub fn to_yaml(&self) -> Result<String, yaml_serde::Error> {
+        yaml_serde::to_string(self)
+    }
+}
+
 /// OpenAPI [Discriminator][discriminator] object which can be optionally used together with
 /// [`OneOf`] composite object.
 ///
@@ -394,6 +874,16 @@ builder! {
     #[derive(Serialize, Deserialize, Clone, PartialEq)]
     #[cfg_attr(feature = "debug", derive(Debug))]
     pub struct OneOf {
+        /// Identifier of the [`OneOf`] used as base URI when resolving relative references within
+        /// this schema. See more details <https://json-schema.org/draft/2020-12/json-schema-core#name-the-id-keyword>
+        #[serde(rename = "$id", skip_serializing_if = "Option::is_none")]
+        pub id: Option<String>,
+
+        /// Plain name fragment which can be used to reference this [`OneOf`] e.g. `#foo`. See
+        /// more details <https://json-schema.org/draft/2020-12/json-schema-core#name-defining-location-independe>
+        #[serde(rename = "$anchor", skip_serializing_if = "Option::is_none")]
+        pub anchor: Option<String>,
+
         /// Components of _OneOf_ component.
         #[serde(rename = "oneOf")]
         pub items: Vec<RefOr<Schema>>,
@@ -432,6 +922,11 @@ builder! {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub discriminator: Option<Discriminator>,
 
+        /// Local definitions of reusable [`Schema`]s which can be referenced within this
+        /// [`OneOf`] with `#/$defs/{name}`.
+        #[serde(rename = "$defs", skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub defs: BTreeMap<String, RefOr<Schema>>,
+
         /// Optional extensions `x-something`.
         #[serde(skip_serializing_if = "Option::is_none", flatten)]
         pub extensions: Option<Extensions>,
@@ -477,6 +972,8 @@ impl OneOf {
 impl Default for OneOf {
     fn default() -> Self {
         Self {
+            id: Default::default(),
+            anchor: Default::default(),
             items: Default::default(),
             schema_type: SchemaType::AnyValue,
             title: Default::default(),
@@ -485,6 +982,7 @@ impl Default for OneOf {
             example: Default::default(),
             examples: Default::default(),
             discriminator: Default::default(),
+            defs: Default::default(),
             extensions: Default::default(),
             read_only: Default::default(),
             write_only: Default::default(),
@@ -546,6 +1044,40 @@ impl OneOfBuilder {
         set_value!(self extensions extensions)
     }
 
+    /// Set or change the `$id` of the [`OneOf`].
+    pub fn id<I: Into<String>>(mut self, id: Option<I>) -> Self {
+        set_value!(self id id.map(|id| id.into()))
+    }
+
+    /// Set or change the `$anchor` of the [`OneOf`].
+    pub fn anchor<I: Into<String>>(mut self, anchor: Option<I>) -> Self {
+        set_value!(self anchor anchor.map(|anchor| anchor.into()))
+    }
+
+    /// Add a local [`Schema`] definition to `$defs` of the [`OneOf`].
+    pub fn def<S: Into<String>, I: Into<RefOr<Schema>>>(mut self, name: S, schema: I) -> Self {
+        self.defs.insert(name.into(), schema.into());
+
+        self
+    }
+
+    /// Add multiple local [`Schema`] definitions to `$defs` of the [`OneOf`] from iterator.
+    pub fn defs_from_iter<
+        I: IntoIterator<Item = (S, C)>,
+        C: Into<RefOr<Schema>>,
+        S: Into<String>,
+    >(
+        mut self,
+        defs: I,
+    ) -> Self {
+        self.defs.extend(
+            defs.into_iter()
+                .map(|(name, schema)| (name.into(), schema.into())),
+        );
+
+        self
+    }
+
     /// Add or change read only flag for [`OneOf`].
     pub fn read_only(mut self, read_only: bool) -> Self {
         set_value!(self read_only Some(read_only))
@@ -591,6 +1123,16 @@ builder! {
     #[derive(Serialize, Deserialize, Clone, PartialEq)]
     #[cfg_attr(feature = "debug", derive(Debug))]
     pub struct AllOf {
+        /// Identifier of the [`AllOf`] used as base URI when resolving relative references within
+        /// this schema. See more details <https://json-schema.org/draft/2020-12/json-schema-core#name-the-id-keyword>
+        #[serde(rename = "$id", skip_serializing_if = "Option::is_none")]
+        pub id: Option<String>,
+
+        /// Plain name fragment which can be used to reference this [`AllOf`] e.g. `#foo`. See
+        /// more details <https://json-schema.org/draft/2020-12/json-schema-core#name-defining-location-independe>
+        #[serde(rename = "$anchor", skip_serializing_if = "Option::is_none")]
+        pub anchor: Option<String>,
+
         /// Components of _AllOf_ component.
         #[serde(rename = "allOf")]
         pub items: Vec<RefOr<Schema>>,
@@ -629,6 +1171,17 @@ builder! {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub discriminator: Option<Discriminator>,
 
+        /// [`Schema`] for properties which are not evaluated by any of the [`AllOf`] items. Use
+        /// _`AdditionalProperties::FreeForm(false)`_ to disallow any properties not defined by
+        /// the items.
+        #[serde(rename = "unevaluatedProperties", skip_serializing_if = "Option::is_none")]
+        pub unevaluated_properties: Option<Box<AdditionalProperties<Schema>>>,
+
+        /// Local definitions of reusable [`Schema`]s which can be referenced within this
+        /// [`AllOf`] with `#/$defs/{name}`.
+        #[serde(rename = "$defs", skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub defs: BTreeMap<String, RefOr<Schema>>,
+
         /// Optional extensions `x-something`.
         #[serde(skip_serializing_if = "Option::is_none", flatten)]
         pub extensions: Option<Extensions>,
@@ -666,6 +1219,8 @@ impl AllOf {
 impl Default for AllOf {
     fn default() -> Self {
         Self {
+            id: Default::default(),
+            anchor: Default::default(),
             items: Default::default(),
             schema_type: SchemaType::AnyValue,
             title: Default::default(),
@@ -674,6 +1229,8 @@ impl Default for AllOf {
             example: Default::default(),
             examples: Default::default(),
             discriminator: Default::default(),
+            unevaluated_properties: Default::default(),
+            defs: Default::default(),
             extensions: Default::default(),
         }
     }
@@ -728,11 +1285,64 @@ impl AllOfBuilder {
         set_value!(self discriminator discriminator)
     }
 
+    /// Add or change [`Schema`] for properties not evaluated by any of the [`AllOf`] items.
+    ///
+    /// # Examples
+    ///
+    /// _**Disallow any properties not defined by the items.**_
+    /// ```rust
+    /// # use utoipa::openapi::schema::{AllOfBuilder, AdditionalProperties, ObjectBuilder, Type};
+    /// let all_of = AllOfBuilder::new()
+    ///     .item(ObjectBuilder::new().property("id", ObjectBuilder::new().schema_type(Type::Integer)))
+    ///     .item(ObjectBuilder::new().property("name", ObjectBuilder::new().schema_type(Type::String)))
+    ///     .unevaluated_properties(Some(AdditionalProperties::FreeForm(false)));
+    /// ```
+    pub fn unevaluated_properties<I: Into<AdditionalProperties<Schema>>>(
+        mut self,
+        unevaluated_properties: Option<I>,
+    ) -> Self {
+        set_value!(self unevaluated_properties unevaluated_properties.map(|unevaluated_properties| Box::new(unevaluated_properties.into())))
+    }
+
     /// Add openapi extensions (`x-something`) for [`AllOf`].
     pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
         set_value!(self extensions extensions)
     }
 
+    /// Set or change the `$id` of the [`AllOf`].
+    pub fn id<I: Into<String>>(mut self, id: Option<I>) -> Self {
+        set_value!(self id id.map(|id| id.into()))
+    }
+
+    /// Set or change the `$anchor` of the [`AllOf`].
+    pub fn anchor<I: Into<String>>(mut self, anchor: Option<I>) -> Self {
+        set_value!(self anchor anchor.map(|anchor| anchor.into()))
+    }
+
+    /// Add a local [`Schema`] definition to `$defs` of the [`AllOf`].
+    pub fn def<S: Into<String>, I: Into<RefOr<Schema>>>(mut self, name: S, schema: I) -> Self {
+        self.defs.insert(name.into(), schema.into());
+
+        self
+    }
+
+    /// Add multiple local [`Schema`] definitions to `$defs` of the [`AllOf`] from iterator.
+    pub fn defs_from_iter<
+        I: IntoIterator<Item = (S, C)>,
+        C: Into<RefOr<Schema>>,
+        S: Into<String>,
+    >(
+        mut self,
+        defs: I,
+    ) -> Self {
+        self.defs.extend(
+            defs.into_iter()
+                .map(|(name, schema)| (name.into(), schema.into())),
+        );
+
+        self
+    }
+
     to_array_builder!();
 }
 
@@ -768,6 +1378,16 @@ builder! {
     #[derive(Serialize, Deserialize, Clone, PartialEq)]
     #[cfg_attr(feature = "debug", derive(Debug))]
     pub struct AnyOf {
+        /// Identifier of the [`AnyOf`] used as base URI when resolving relative references within
+        /// this schema. See more details <https://json-schema.org/draft/2020-12/json-schema-core#name-the-id-keyword>
+        #[serde(rename = "$id", skip_serializing_if = "Option::is_none")]
+        pub id: Option<String>,
+
+        /// Plain name fragment which can be used to reference this [`AnyOf`] e.g. `#foo`. See
+        /// more details <https://json-schema.org/draft/2020-12/json-schema-core#name-defining-location-independe>
+        #[serde(rename = "$anchor", skip_serializing_if = "Option::is_none")]
+        pub anchor: Option<String>,
+
         /// Components of _AnyOf component.
         #[serde(rename = "anyOf")]
         pub items: Vec<RefOr<Schema>>,
@@ -802,6 +1422,11 @@ builder! {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub discriminator: Option<Discriminator>,
 
+        /// Local definitions of reusable [`Schema`]s which can be referenced within this
+        /// [`AnyOf`] with `#/$defs/{name}`.
+        #[serde(rename = "$defs", skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub defs: BTreeMap<String, RefOr<Schema>>,
+
         /// Optional extensions `x-something`.
         #[serde(skip_serializing_if = "Option::is_none", flatten)]
         pub extensions: Option<Extensions>,
@@ -839,6 +1464,8 @@ impl AnyOf {
 impl Default for AnyOf {
     fn default() -> Self {
         Self {
+            id: Default::default(),
+            anchor: Default::default(),
             items: Default::default(),
             schema_type: SchemaType::AnyValue,
             description: Default::default(),
@@ -846,6 +1473,7 @@ impl Default for AnyOf {
             example: Default::default(),
             examples: Default::default(),
             discriminator: Default::default(),
+            defs: Default::default(),
             extensions: Default::default(),
         }
     }
@@ -895,33 +1523,205 @@ impl AnyOfBuilder {
         set_value!(self discriminator discriminator)
     }
 
-    /// Add openapi extensions (`x-something`) for [`AnyOf`].
-    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
-        set_value!(self extensions extensions)
+    /// Add openapi extensions (`x-something`) for [`AnyOf`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
+
+    /// Set or change the `$id` of the [`AnyOf`].
+    pub fn id<I: Into<String>>(mut self, id: Option<I>) -> Self {
+        set_value!(self id id.map(|id| id.into()))
+    }
+
+    /// Set or change the `$anchor` of the [`AnyOf`].
+    pub fn anchor<I: Into<String>>(mut self, anchor: Option<I>) -> Self {
+        set_value!(self anchor anchor.map(|anchor| anchor.into()))
+    }
+
+    /// Add a local [`Schema`] definition to `$defs` of the [`AnyOf`].
+    pub fn def<S: Into<String>, I: Into<RefOr<Schema>>>(mut self, name: S, schema: I) -> Self {
+        self.defs.insert(name.into(), schema.into());
+
+        self
+    }
+
+    /// Add multiple local [`Schema`] definitions to `$defs` of the [`AnyOf`] from iterator.
+    pub fn defs_from_iter<
+        I: IntoIterator<Item = (S, C)>,
+        C: Into<RefOr<Schema>>,
+        S: Into<String>,
+    >(
+        mut self,
+        defs: I,
+    ) -> Self {
+        self.defs.extend(
+            defs.into_iter()
+                .map(|(name, schema)| (name.into(), schema.into())),
+        );
+
+        self
+    }
+
+    to_array_builder!();
+}
+
+impl From<AnyOf> for Schema {
+    fn from(any_of: AnyOf) -> Self {
+        Self::AnyOf(any_of)
+    }
+}
+
+impl From<AnyOfBuilder> for RefOr<Schema> {
+    fn from(any_of: AnyOfBuilder) -> Self {
+        Self::T(Schema::AnyOf(any_of.build()))
+    }
+}
+
+impl From<AnyOfBuilder> for ArrayItems {
+    fn from(value: AnyOfBuilder) -> Self {
+        Self::RefOrSchema(Box::new(value.into()))
+    }
+}
+
+component_from_builder!(AnyOfBuilder);
+
+builder! {
+    NotBuilder;
+
+    /// Not [Composite Object][not] component holds a single schema which the value must **not**
+    /// be valid against.
+    ///
+    /// See [`Schema::Not`] for more details.
+    ///
+    /// [not]: https://json-schema.org/draft/2020-12/json-schema-core#name-not
+    #[derive(Serialize, Deserialize, Clone, Default, PartialEq)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct Not {
+        /// Identifier of the [`Not`] used as base URI when resolving relative references within
+        /// this schema. See more details <https://json-schema.org/draft/2020-12/json-schema-core#name-the-id-keyword>
+        #[serde(rename = "$id", skip_serializing_if = "Option::is_none")]
+        pub id: Option<String>,
+
+        /// Plain name fragment which can be used to reference this [`Not`] e.g. `#foo`. See
+        /// more details <https://json-schema.org/draft/2020-12/json-schema-core#name-defining-location-independe>
+        #[serde(rename = "$anchor", skip_serializing_if = "Option::is_none")]
+        pub anchor: Option<String>,
+
+        /// Schema that the value must not be valid against.
+        #[serde(rename = "not")]
+        pub item: Box<RefOr<Schema>>,
+
+        /// Description of the [`Not`]. Markdown syntax is supported.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+
+        /// Local definitions of reusable [`Schema`]s which can be referenced within this
+        /// [`Not`] with `#/$defs/{name}`.
+        #[serde(rename = "$defs", skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub defs: BTreeMap<String, RefOr<Schema>>,
+
+        /// Optional extensions `x-something`.
+        #[serde(skip_serializing_if = "Option::is_none", flatten)]
+        pub extensions: Option<Extensions>,
+    }
+}
+
+impl Not {
+    /// Construct a new [`Not`] component of given schema.
+    ///
+    /// # Examples
+    ///
+    /// _**Create [`Not`] which does not allow `string` values.**_
+    /// ```rust
+    /// # use utoipa::openapi::schema::{Not, ObjectBuilder, Type};
+    /// let not = Not::new(ObjectBuilder::new().schema_type(Type::String));
+    /// ```
+    pub fn new<I: Into<RefOr<Schema>>>(item: I) -> Self {
+        Self {
+            item: Box::new(item.into()),
+            ..Default::default()
+        }
+    }
+}
+
+impl NotBuilder {
+    /// Set the [`Schema`] that the value must **not** be valid against.
+    pub fn item<I: Into<RefOr<Schema>>>(mut self, component: I) -> Self {
+        set_value!(self item Box::new(component.into()))
+    }
+
+    /// Add or change optional description for `Not` component.
+    pub fn description<I: Into<String>>(mut self, description: Option<I>) -> Self {
+        set_value!(self description description.map(|description| description.into()))
+    }
+
+    /// Add openapi extensions (`x-something`) for [`Not`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
+
+    /// Set or change the `$id` of the [`Not`].
+    pub fn id<I: Into<String>>(mut self, id: Option<I>) -> Self {
+        set_value!(self id id.map(|id| id.into()))
+    }
+
+    /// Set or change the `$anchor` of the [`Not`].
+    pub fn anchor<I: Into<String>>(mut self, anchor: Option<I>) -> Self {
+        set_value!(self anchor anchor.map(|anchor| anchor.into()))
+    }
+
+    /// Add a local [`Schema`] definition to `$defs` of the [`Not`].
+    pub fn def<S: Into<String>, I: Into<RefOr<Schema>>>(mut self, name: S, schema: I) -> Self {
+        self.defs.insert(name.into(), schema.into());
+
+        self
+    }
+
+    /// Add multiple local [`Schema`] definitions to `$defs` of the [`Not`] from iterator.
+    pub fn defs_from_iter<
+        I: IntoIterator<Item = (S, C)>,
+        C: Into<RefOr<Schema>>,
+        S: Into<String>,
+    >(
+        mut self,
+        defs: I,
+    ) -> Self {
+        self.defs.extend(
+            defs.into_iter()
+                .map(|(name, schema)| (name.into(), schema.into())),
+        );
+
+        self
     }
 
     to_array_builder!();
 }
 
-impl From<AnyOf> for Schema {
-    fn from(any_of: AnyOf) -> Self {
-        Self::AnyOf(any_of)
+impl From<Not> for Schema {
+    fn from(not: Not) -> Self {
+        Self::Not(not)
     }
 }
 
-impl From<AnyOfBuilder> for RefOr<Schema> {
-    fn from(any_of: AnyOfBuilder) -> Self {
-        Self::T(Schema::AnyOf(any_of.build()))
+impl From<Not> for RefOr<Schema> {
+    fn from(not: Not) -> Self {
+        Self::T(Schema::Not(not))
     }
 }
 
-impl From<AnyOfBuilder> for ArrayItems {
-    fn from(value: AnyOfBuilder) -> Self {
+impl From<NotBuilder> for RefOr<Schema> {
+    fn from(not: NotBuilder) -> Self {
+        Self::T(Schema::Not(not.build()))
+    }
+}
+
+impl From<NotBuilder> for ArrayItems {
+    fn from(value: NotBuilder) -> Self {
         Self::RefOrSchema(Box::new(value.into()))
     }
 }
 
-component_from_builder!(AnyOfBuilder);
+component_from_builder!(NotBuilder);
 
 #[cfg(not(feature = "preserve_order"))]
 type ObjectPropertiesMap<K, V> = BTreeMap<K, V>;
@@ -942,6 +1742,16 @@ builder! {
     #[cfg_attr(feature = "debug", derive(Debug))]
     #[serde(rename_all = "camelCase")]
     pub struct Object {
+        /// Identifier of the [`Object`] used as base URI when resolving relative references within
+        /// this schema. See more details <https://json-schema.org/draft/2020-12/json-schema-core#name-the-id-keyword>
+        #[serde(rename = "$id", skip_serializing_if = "Option::is_none")]
+        pub id: Option<String>,
+
+        /// Plain name fragment which can be used to reference this [`Object`] e.g. `#foo`. See
+        /// more details <https://json-schema.org/draft/2020-12/json-schema-core#name-defining-location-independe>
+        #[serde(rename = "$anchor", skip_serializing_if = "Option::is_none")]
+        pub anchor: Option<String>,
+
         /// Type of [`Object`] e.g. [`Type::Object`] for `object` and [`Type::String`] for
         /// `string` types.
         #[serde(rename = "type", skip_serializing_if="SchemaType::is_any_value")]
@@ -990,6 +1800,18 @@ builder! {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub property_names: Option<Box<Schema>>,
 
+        /// Map of regular expressions to [`Schema`]s. Each property which name matches the regular
+        /// expression must be valid against the [`Schema`]. See more details
+        /// <https://json-schema.org/draft/2020-12/json-schema-core#name-patternproperties>
+        #[serde(skip_serializing_if = "ObjectPropertiesMap::is_empty", default = "ObjectPropertiesMap::new")]
+        pub pattern_properties: ObjectPropertiesMap<String, RefOr<Schema>>,
+
+        /// [`Schema`] for properties which are not evaluated by the [`Object`] itself or by any of
+        /// its subschemas e.g. by `allOf` composition. See more details
+        /// <https://json-schema.org/draft/2020-12/json-schema-core#name-unevaluatedproperties>
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub unevaluated_properties: Option<Box<AdditionalProperties<Schema>>>,
+
         /// Changes the [`Object`] deprecated status.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub deprecated: Option<Deprecated>,
@@ -1066,6 +1888,47 @@ builder! {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub min_properties: Option<usize>,
 
+        /// Restrict the value of the [`Object`] to a single constant value.
+        #[serde(rename = "const", skip_serializing_if = "Option::is_none")]
+        pub const_value: Option<Value>,
+
+        /// Conditional [`Schema`]. When the value is valid against _`if`_ schema it must also be
+        /// valid against [`Object::then_schema`], otherwise it must be valid against
+        /// [`Object::else_schema`].
+        #[serde(rename = "if", skip_serializing_if = "Option::is_none")]
+        pub if_schema: Option<Box<RefOr<Schema>>>,
+
+        /// [`Schema`] that the value must be valid against when it is valid against
+        /// [`Object::if_schema`].
+        #[serde(rename = "then", skip_serializing_if = "Option::is_none")]
+        pub then_schema: Option<Box<RefOr<Schema>>>,
+
+        /// [`Schema`] that the value must be valid against when it is not valid against
+        /// [`Object::if_schema`].
+        #[serde(rename = "else", skip_serializing_if = "Option::is_none")]
+        pub else_schema: Option<Box<RefOr<Schema>>>,
+
+        /// [`Schema`] that the value must **not** be valid against. This can be used together
+        /// with [`Object::schema_type`] e.g. to allow any `string` except the given values. See
+        /// [`Schema::Not`] for standalone _`not`_ schema.
+        #[serde(rename = "not", skip_serializing_if = "Option::is_none")]
+        pub not_schema: Option<Box<RefOr<Schema>>>,
+
+        /// Map of property names to the names of properties which are required when the property
+        /// is present in the object.
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub dependent_required: BTreeMap<String, Vec<String>>,
+
+        /// Map of property names to [`Schema`]s which the whole object must be valid against when
+        /// the property is present in the object.
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub dependent_schemas: BTreeMap<String, RefOr<Schema>>,
+
+        /// Local definitions of reusable [`Schema`]s which can be referenced within this
+        /// [`Object`] with `#/$defs/{name}`.
+        #[serde(rename = "$defs", skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub defs: BTreeMap<String, RefOr<Schema>>,
+
         /// Optional extensions `x-something`.
         #[serde(skip_serializing_if = "Option::is_none", flatten)]
         pub extensions: Option<Extensions>,
@@ -1158,6 +2021,38 @@ impl ObjectBuilder {
         self
     }
 
+    /// Add new pattern property to the [`Object`]. Each property which name matches the given
+    /// regular expression `pattern` must be valid against the given `component`.
+    ///
+    /// # Examples
+    ///
+    /// _**Create a map of metric names to numbers.**_
+    /// ```rust
+    /// # use utoipa::openapi::schema::{AdditionalProperties, ObjectBuilder, Type};
+    /// let metrics = ObjectBuilder::new()
+    ///     .pattern_property("^[a-z_]+$", ObjectBuilder::new().schema_type(Type::Number))
+    ///     .additional_properties(Some(AdditionalProperties::FreeForm(false)));
+    /// ```
+    pub fn pattern_property<S: Into<String>, I: Into<RefOr<Schema>>>(
+        mut self,
+        pattern: S,
+        component: I,
+    ) -> Self {
+        self.pattern_properties
+            .insert(pattern.into(), component.into());
+
+        self
+    }
+
+    /// Add or change [`Schema`] for properties which are not evaluated by the [`Object`] or by
+    /// any of its subschemas.
+    pub fn unevaluated_properties<I: Into<AdditionalProperties<Schema>>>(
+        mut self,
+        unevaluated_properties: Option<I>,
+    ) -> Self {
+        set_value!(self unevaluated_properties unevaluated_properties.map(|unevaluated_properties| Box::new(unevaluated_properties.into())))
+    }
+
     /// Add additional [`Schema`] for non specified fields (Useful for typed maps).
     pub fn additional_properties<I: Into<AdditionalProperties<Schema>>>(
         mut self,
@@ -1292,11 +2187,130 @@ impl ObjectBuilder {
         set_value!(self min_properties min_properties)
     }
 
+    /// Set or change the constant value the [`Object`] value must be equal to.
+    pub fn const_value<V: Into<Value>>(mut self, const_value: Option<V>) -> Self {
+        set_value!(self const_value const_value.map(|const_value| const_value.into()))
+    }
+
+    /// Set or change the conditional _`if`_ [`Schema`] of the [`Object`].
+    ///
+    /// # Examples
+    ///
+    /// _**Require `postal_code` only when `country` is `FI`.**_
+    /// ```rust
+    /// # use utoipa::openapi::schema::{ObjectBuilder, Type};
+    /// # use serde_json::json;
+    /// let object = ObjectBuilder::new()
+    ///     .property("country", ObjectBuilder::new().schema_type(Type::String))
+    ///     .property("postal_code", ObjectBuilder::new().schema_type(Type::String))
+    ///     .if_schema(Some(ObjectBuilder::new().property(
+    ///         "country",
+    ///         ObjectBuilder::new()
+    ///             .schema_type(Type::String)
+    ///             .const_value(Some(json!("FI"))),
+    ///     )))
+    ///     .then_schema(Some(ObjectBuilder::new().required("postal_code")));
+    /// ```
+    pub fn if_schema<I: Into<RefOr<Schema>>>(mut self, if_schema: Option<I>) -> Self {
+        set_value!(self if_schema if_schema.map(|if_schema| Box::new(if_schema.into())))
+    }
+
+    /// Set or change the _`then`_ [`Schema`] applied when [`Object::if_schema`] is valid.
+    pub fn then_schema<I: Into<RefOr<Schema>>>(mut self, then_schema: Option<I>) -> Self {
+        set_value!(self then_schema then_schema.map(|then_schema| Box::new(then_schema.into())))
+    }
+
+    /// Set or change the _`else`_ [`Schema`] applied when [`Object::if_schema`] is not valid.
+    pub fn else_schema<I: Into<RefOr<Schema>>>(mut self, else_schema: Option<I>) -> Self {
+        set_value!(self else_schema else_schema.map(|else_schema| Box::new(else_schema.into())))
+    }
+
+    /// Set or change the _`not`_ [`Schema`] which the value must **not** be valid against.
+    ///
+    /// # Examples
+    ///
+    /// _**Allow any `string` except `cat` and `dog`.**_
+    /// ```rust
+    /// # use utoipa::openapi::schema::{ObjectBuilder, Type};
+    /// let object = ObjectBuilder::new()
+    ///     .schema_type(Type::String)
+    ///     .not_schema(Some(
+    ///         ObjectBuilder::new()
+    ///             .schema_type(Type::String)
+    ///             .enum_values(Some(["cat", "dog"])),
+    ///     ));
+    /// ```
+    pub fn not_schema<I: Into<RefOr<Schema>>>(mut self, not_schema: Option<I>) -> Self {
+        set_value!(self not_schema not_schema.map(|not_schema| Box::new(not_schema.into())))
+    }
+
+    /// Add names of properties which are required when given `property_name` is present in the
+    /// [`Object`].
+    pub fn dependent_required<S: Into<String>, I: IntoIterator<Item = R>, R: Into<String>>(
+        mut self,
+        property_name: S,
+        required: I,
+    ) -> Self {
+        self.dependent_required.insert(
+            property_name.into(),
+            required.into_iter().map(Into::into).collect(),
+        );
+
+        self
+    }
+
+    /// Add [`Schema`] which the [`Object`] must be valid against when given `property_name` is
+    /// present in the [`Object`].
+    pub fn dependent_schema<S: Into<String>, I: Into<RefOr<Schema>>>(
+        mut self,
+        property_name: S,
+        schema: I,
+    ) -> Self {
+        self.dependent_schemas
+            .insert(property_name.into(), schema.into());
+
+        self
+    }
+
     /// Add openapi extensions (`x-something`) for [`Object`].
     pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
         set_value!(self extensions extensions)
     }
 
+    /// Set or change the `$id` of the [`Object`].
+    pub fn id<I: Into<String>>(mut self, id: Option<I>) -> Self {
+        set_value!(self id id.map(|id| id.into()))
+    }
+
+    /// Set or change the `$anchor` of the [`Object`].
+    pub fn anchor<I: Into<String>>(mut self, anchor: Option<I>) -> Self {
+        set_value!(self anchor anchor.map(|anchor| anchor.into()))
+    }
+
+    /// Add a local [`Schema`] definition to `$defs` of the [`Object`].
+    pub fn def<S: Into<String>, I: Into<RefOr<Schema>>>(mut self, name: S, schema: I) -> Self {
+        self.defs.insert(name.into(), schema.into());
+
+        self
+    }
+
+    /// Add multiple local [`Schema`] definitions to `$defs` of the [`Object`] from iterator.
+    pub fn defs_from_iter<
+        I: IntoIterator<Item = (S, C)>,
+        C: Into<RefOr<Schema>>,
+        S: Into<String>,
+    >(
+        mut self,
+        defs: I,
+    ) -> Self {
+        self.defs.extend(
+            defs.into_iter()
+                .map(|(name, schema)| (name.into(), schema.into())),
+        );
+
+        self
+    }
+
     /// Set of change [`Object::content_encoding`]. Typically left empty but could be `base64` for
     /// example.
     pub fn content_encoding<S: Into<String>>(mut self, content_encoding: S) -> Self {
@@ -1339,15 +2353,16 @@ impl From<ObjectBuilder> for ArrayItems {
 
 /// AdditionalProperties is used to define values of map fields of the [`Schema`].
 ///
-/// The value can either be [`RefOr`] or _`bool`_.
+/// The value can either be [`RefOr`] or _`bool`_. Boolean values are always deserialized as
+/// [`AdditionalProperties::FreeForm`].
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[serde(untagged)]
 pub enum AdditionalProperties<T> {
-    /// Use when value type of the map is a known [`Schema`] or [`Ref`] to the [`Schema`].
-    RefOr(RefOr<T>),
     /// Use _`AdditionalProperties::FreeForm(true)`_ when any value is allowed in the map.
     FreeForm(bool),
+    /// Use when value type of the map is a known [`Schema`] or [`Ref`] to the [`Schema`].
+    RefOr(RefOr<T>),
 }
 
 impl<T> From<RefOr<T>> for AdditionalProperties<T> {
@@ -1464,6 +2479,33 @@ impl Ref {
         Self::new(format!("#/components/responses/{}", response_name.into()))
     }
 
+    /// Construct a new [`Ref`] from provided parameter name. This will create a [`Ref`] that
+    /// references the reusable parameter.
+    pub fn from_parameter_name<I: Into<String>>(parameter_name: I) -> Self {
+        Self::new(format!("#/components/parameters/{}", parameter_name.into()))
+    }
+
+    /// Construct a new [`Ref`] from provided example name. This will create a [`Ref`] that
+    /// references the reusable example.
+    pub fn from_example_name<I: Into<String>>(example_name: I) -> Self {
+        Self::new(format!("#/components/examples/{}", example_name.into()))
+    }
+
+    /// Construct a new [`Ref`] from provided request body name. This will create a [`Ref`] that
+    /// references the reusable request body.
+    pub fn from_request_body_name<I: Into<String>>(request_body_name: I) -> Self {
+        Self::new(format!(
+            "#/components/requestBodies/{}",
+            request_body_name.into()
+        ))
+    }
+
+    /// Construct a new [`Ref`] from provided header name. This will create a [`Ref`] that
+    /// references the reusable header.
+    pub fn from_header_name<I: Into<String>>(header_name: I) -> Self {
+        Self::new(format!("#/components/headers/{}", header_name.into()))
+    }
+
     to_array_builder!();
 }
 
@@ -1593,15 +2635,17 @@ where
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[serde(untagged)]
 pub enum ArrayItems {
-    /// Defines [`Array::items`] as [`RefOr::T(Schema)`]. This is the default for [`Array`].
-    RefOrSchema(Box<RefOr<Schema>>),
     /// Defines [`Array::items`] as `false` indicating that no extra items are allowed to the
     /// [`Array`]. This can be used together with [`Array::prefix_items`] to disallow [additional
     /// items][additional_items] in [`Array`].
     ///
+    /// Boolean `false` items are always deserialized as [`ArrayItems::False`].
+    ///
     /// [additional_items]: <https://json-schema.org/understanding-json-schema/reference/array#additionalitems>
     #[serde(with = "array_items_false")]
     False,
+    /// Defines [`Array::items`] as [`RefOr::T(Schema)`]. This is the default for [`Array`].
+    RefOrSchema(Box<RefOr<Schema>>),
 }
 
 mod array_items_false {
@@ -1650,6 +2694,12 @@ impl From<RefOr<Schema>> for ArrayItems {
     }
 }
 
+impl From<Schema> for ArrayItems {
+    fn from(value: Schema) -> Self {
+        Self::RefOrSchema(Box::new(value.into()))
+    }
+}
+
 builder! {
     ArrayBuilder;
 
@@ -1661,6 +2711,16 @@ builder! {
     #[cfg_attr(feature = "debug", derive(Debug))]
     #[serde(rename_all = "camelCase")]
     pub struct Array {
+        /// Identifier of the [`Array`] used as base URI when resolving relative references within
+        /// this schema. See more details <https://json-schema.org/draft/2020-12/json-schema-core#name-the-id-keyword>
+        #[serde(rename = "$id", skip_serializing_if = "Option::is_none")]
+        pub id: Option<String>,
+
+        /// Plain name fragment which can be used to reference this [`Array`] e.g. `#foo`. See
+        /// more details <https://json-schema.org/draft/2020-12/json-schema-core#name-defining-location-independe>
+        #[serde(rename = "$anchor", skip_serializing_if = "Option::is_none")]
+        pub anchor: Option<String>,
+
         /// Type will always be [`SchemaType::Array`].
         #[serde(rename = "type")]
         pub schema_type: SchemaType,
@@ -1714,6 +2774,25 @@ builder! {
         #[serde(default, skip_serializing_if = "is_false")]
         pub unique_items: bool,
 
+        /// [`Schema`] which at least one item of the [`Array`] must be valid against. The number
+        /// of matching items can be restricted with [`Array::min_contains`] and
+        /// [`Array::max_contains`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub contains: Option<Box<RefOr<Schema>>>,
+
+        /// Minimum number of items which must be valid against [`Array::contains`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub min_contains: Option<usize>,
+
+        /// Maximum number of items which may be valid against [`Array::contains`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub max_contains: Option<usize>,
+
+        /// [`Schema`] for items which are not evaluated by the [`Array`] itself or by any of its
+        /// subschemas. Use [`ArrayItems::False`] to disallow any unevaluated items.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub unevaluated_items: Option<Box<ArrayItems>>,
+
         /// Xml format of the array.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub xml: Option<Xml>,
@@ -1736,6 +2815,11 @@ builder! {
         #[serde(skip_serializing_if = "String::is_empty", default)]
         pub content_media_type: String,
 
+        /// Local definitions of reusable [`Schema`]s which can be referenced within this
+        /// [`Array`] with `#/$defs/{name}`.
+        #[serde(rename = "$defs", skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub defs: BTreeMap<String, RefOr<Schema>>,
+
         /// Optional extensions `x-something`.
         #[serde(skip_serializing_if = "Option::is_none", flatten)]
         pub extensions: Option<Extensions>,
@@ -1745,9 +2829,15 @@ builder! {
 impl Default for Array {
     fn default() -> Self {
         Self {
+            id: Default::default(),
+            anchor: Default::default(),
             title: Default::default(),
             schema_type: Type::Array.into(),
             unique_items: bool::default(),
+            contains: Default::default(),
+            min_contains: Default::default(),
+            max_contains: Default::default(),
+            unevaluated_items: Default::default(),
             items: Default::default(),
             prefix_items: Vec::default(),
             description: Default::default(),
@@ -1758,6 +2848,7 @@ impl Default for Array {
             max_items: Default::default(),
             min_items: Default::default(),
             xml: Default::default(),
+            defs: Default::default(),
             extensions: Default::default(),
             content_encoding: Default::default(),
             content_media_type: Default::default(),
@@ -1884,6 +2975,43 @@ impl ArrayBuilder {
         set_value!(self unique_items unique_items)
     }
 
+    /// Set or change [`Schema`] which at least one item of the [`Array`] must be valid against.
+    ///
+    /// # Examples
+    ///
+    /// _**Create an array of strings which contains one to three `"admin"` values.**_
+    /// ```rust
+    /// # use utoipa::openapi::schema::{ArrayBuilder, ObjectBuilder, Type};
+    /// let roles = ArrayBuilder::new()
+    ///     .items(ObjectBuilder::new().schema_type(Type::String))
+    ///     .contains(Some(
+    ///         ObjectBuilder::new()
+    ///             .schema_type(Type::String)
+    ///             .const_value(Some("admin")),
+    ///     ))
+    ///     .min_contains(Some(1))
+    ///     .max_contains(Some(3));
+    /// ```
+    pub fn contains<I: Into<RefOr<Schema>>>(mut self, contains: Option<I>) -> Self {
+        set_value!(self contains contains.map(|contains| Box::new(contains.into())))
+    }
+
+    /// Set or change minimum number of items which must be valid against [`Array::contains`].
+    pub fn min_contains(mut self, min_contains: Option<usize>) -> Self {
+        set_value!(self min_contains min_contains)
+    }
+
+    /// Set or change maximum number of items which may be valid against [`Array::contains`].
+    pub fn max_contains(mut self, max_contains: Option<usize>) -> Self {
+        set_value!(self max_contains max_contains)
+    }
+
+    /// Set or change [`Schema`] for items which are not evaluated by the [`Array`] or by any of
+    /// its subschemas.
+    pub fn unevaluated_items<I: Into<ArrayItems>>(mut self, unevaluated_items: Option<I>) -> Self {
+        set_value!(self unevaluated_items unevaluated_items.map(|unevaluated_items| Box::new(unevaluated_items.into())))
+    }
+
     /// Set [`Xml`] formatting for [`Array`].
     pub fn xml(mut self, xml: Option<Xml>) -> Self {
         set_value!(self xml xml)
@@ -1906,6 +3034,40 @@ impl ArrayBuilder {
         set_value!(self extensions extensions)
     }
 
+    /// Set or change the `$id` of the [`Array`].
+    pub fn id<I: Into<String>>(mut self, id: Option<I>) -> Self {
+        set_value!(self id id.map(|id| id.into()))
+    }
+
+    /// Set or change the `$anchor` of the [`Array`].
+    pub fn anchor<I: Into<String>>(mut self, anchor: Option<I>) -> Self {
+        set_value!(self anchor anchor.map(|anchor| anchor.into()))
+    }
+
+    /// Add a local [`Schema`] definition to `$defs` of the [`Array`].
+    pub fn def<S: Into<String>, I: Into<RefOr<Schema>>>(mut self, name: S, schema: I) -> Self {
+        self.defs.insert(name.into(), schema.into());
+
+        self
+    }
+
+    /// Add multiple local [`Schema`] definitions to `$defs` of the [`Array`] from iterator.
+    pub fn defs_from_iter<
+        I: IntoIterator<Item = (S, C)>,
+        C: Into<RefOr<Schema>>,
+        S: Into<String>,
+    >(
+        mut self,
+        defs: I,
+    ) -> Self {
+        self.defs.extend(
+            defs.into_iter()
+                .map(|(name, schema)| (name.into(), schema.into())),
+        );
+
+        self
+    }
+
     to_array_builder!();
 }
 
@@ -2427,6 +3589,313 @@ mod tests {
         "#);
     }
 
+    #[test]
+    fn object_with_conditional_keywords() {
+        // properties are in alphabetical order to have same order with `preserve_order`
+        let json_value = ObjectBuilder::new()
+            .property(
+                "card_number",
+                ObjectBuilder::new().schema_type(Type::String),
+            )
+            .property("iban", ObjectBuilder::new().schema_type(Type::String))
+            .property(
+                "kind",
+                ObjectBuilder::new()
+                    .schema_type(Type::String)
+                    .const_value(Some("card")),
+            )
+            .if_schema(Some(ObjectBuilder::new().required("card_number")))
+            .then_schema(Some(
+                NotBuilder::new().item(ObjectBuilder::new().required("iban")),
+            ))
+            .else_schema(Some(ObjectBuilder::new().required("iban")))
+            .dependent_required("card_number", ["kind"])
+            .dependent_schema(
+                "iban",
+                Not::new(ObjectBuilder::new().required("card_number")),
+            )
+            .build();
+        assert_json_snapshot!(json_value, @r#"
+        {
+          "type": "object",
+          "properties": {
+            "card_number": {
+              "type": "string"
+            },
+            "iban": {
+              "type": "string"
+            },
+            "kind": {
+              "type": "string",
+              "const": "card"
+            }
+          },
+          "if": {
+            "type": "object",
+            "required": [
+              "card_number"
+            ]
+          },
+          "then": {
+            "not": {
+              "type": "object",
+              "required": [
+                "iban"
+              ]
+            }
+          },
+          "else": {
+            "type": "object",
+            "required": [
+              "iban"
+            ]
+          },
+          "dependentRequired": {
+            "card_number": [
+              "kind"
+            ]
+          },
+          "dependentSchemas": {
+            "iban": {
+              "not": {
+                "type": "object",
+                "required": [
+                  "card_number"
+                ]
+              }
+            }
+          }
+        }
+        "#);
+    }
+
+    #[test]
+    fn deserialize_reserialize_not() {
+        let not = Schema::from(
+            NotBuilder::new()
+                .item(ObjectBuilder::new().schema_type(Type::String))
+                .description(Some("Anything but a string")),
+        );
+
+        let serialized_json = serde_json::to_string(&not).expect("should serialize to json");
+        let deserialized: Schema =
+            serde_json::from_str(&serialized_json).expect("should deserialize Not");
+
+        let reserialized_json =
+            serde_json::to_string(&deserialized).expect("should reserialize to json");
+
+        assert!(matches!(deserialized, Schema::Not(_)));
+        assert_eq!(serialized_json, reserialized_json);
+    }
+
+    #[test]
+    fn deserialize_reserialize_object_with_not() {
+        let json_str = r#"{"type":"string","not":{"type":"string","enum":["cat","dog"]}}"#;
+
+        let deserialized: Schema =
+            serde_json::from_str(json_str).expect("should deserialize object with not");
+        let Schema::Object(object) = &deserialized else {
+            panic!("expected Schema::Object");
+        };
+        assert!(object.not_schema.is_some());
+
+        let reserialized = serde_json::to_string(&deserialized).expect("should reserialize");
+        assert_eq!(json_str, reserialized);
+    }
+
+    #[test]
+    fn deserialize_reserialize_bool_schemas() {
+        let json_str = r#"{"type":"object","properties":{"any":true,"id":false,"tags":{"type":"array","items":false,"prefixItems":[true]}},"additionalProperties":false,"if":true,"then":{"not":false}}"#;
+
+        let deserialized: Schema =
+            serde_json::from_str(json_str).expect("should deserialize bool schemas");
+        let Schema::Object(object) = &deserialized else {
+            panic!("expected Schema::Object");
+        };
+        assert!(matches!(
+            object.properties["id"],
+            RefOr::T(Schema::Bool(false))
+        ));
+        assert!(matches!(
+            object.additional_properties.as_deref(),
+            Some(AdditionalProperties::FreeForm(false))
+        ));
+
+        let reserialized = serde_json::to_string(&deserialized).expect("should reserialize");
+        assert_eq!(json_str, reserialized);
+    }
+
+    #[test]
+    fn deserialize_reserialize_additional_properties() {
+        type Check = fn(&AdditionalProperties<Schema>) -> bool;
+        let cases: [(&str, Check); 4] = [
+            (
+                r#"{"type":"object","additionalProperties":true}"#,
+                |value| matches!(value, AdditionalProperties::FreeForm(true)),
+            ),
+            (
+                r#"{"type":"object","additionalProperties":false}"#,
+                |value| matches!(value, AdditionalProperties::FreeForm(false)),
+            ),
+            (
+                r#"{"type":"object","additionalProperties":{"type":"string"}}"#,
+                |value| {
+                    matches!(
+                        value,
+                        AdditionalProperties::RefOr(RefOr::T(Schema::Object(_)))
+                    )
+                },
+            ),
+            (
+                r##"{"type":"object","additionalProperties":{"$ref":"#/components/schemas/Pet"}}"##,
+                |value| matches!(value, AdditionalProperties::RefOr(RefOr::Ref(_))),
+            ),
+        ];
+
+        for (json_str, check) in cases {
+            let deserialized: Object =
+                serde_json::from_str(json_str).expect("should deserialize additional properties");
+            let additional_properties = deserialized
+                .additional_properties
+                .as_deref()
+                .expect("should have additional properties");
+            assert!(
+                check(additional_properties),
+                "unexpected variant for {json_str}"
+            );
+
+            let reserialized = serde_json::to_string(&deserialized).expect("should reserialize");
+            assert_eq!(json_str, reserialized);
+        }
+    }
+
+    #[test]
+    fn deserialize_reserialize_array_items() {
+        type Check = fn(&ArrayItems) -> bool;
+        let cases: [(&str, Check); 4] = [
+            (r#"{"type":"array","items":false}"#, |items| {
+                matches!(items, ArrayItems::False)
+            }),
+            (
+                r#"{"type":"array","items":true}"#,
+                |items| matches!(items, ArrayItems::RefOrSchema(items) if matches!(**items, RefOr::T(Schema::Bool(true)))),
+            ),
+            (
+                r#"{"type":"array","items":{"type":"string"}}"#,
+                |items| matches!(items, ArrayItems::RefOrSchema(items) if matches!(**items, RefOr::T(Schema::Object(_)))),
+            ),
+            (
+                r##"{"type":"array","items":{"$ref":"#/components/schemas/Pet"}}"##,
+                |items| matches!(items, ArrayItems::RefOrSchema(items) if matches!(**items, RefOr::Ref(_))),
+            ),
+        ];
+
+        for (json_str, check) in cases {
+            let deserialized: Array =
+                serde_json::from_str(json_str).expect("should deserialize array items");
+            assert!(
+                check(&deserialized.items),
+                "unexpected variant for {json_str}"
+            );
+
+            let reserialized = serde_json::to_string(&deserialized).expect("should reserialize");
+            assert_eq!(json_str, reserialized);
+        }
+    }
+
+    #[test]
+    fn standalone_schema_with_defs() {
+        // names are in alphabetical order to have same order with `preserve_order`
+        let root = ObjectBuilder::new()
+            .id(Some("https://example.com/schemas/pet.json"))
+            .property(
+                "children",
+                ArrayBuilder::new().items(Ref::from_schema_name("Pet")),
+            )
+            .property("owner", Ref::from_schema_name("Owner"))
+            .build();
+        let owner = ObjectBuilder::new()
+            .anchor(Some("owner"))
+            .property("address", Ref::from_schema_name("Address"))
+            .property("name", Object::with_type(Type::String))
+            .into();
+        let address = ObjectBuilder::new()
+            .property("street", Object::with_type(Type::String))
+            .into();
+
+        let schema = standalone_from(
+            "Pet",
+            root.into(),
+            [
+                (String::from("Address"), address),
+                (String::from("Owner"), owner),
+            ],
+        );
+
+        assert_json_snapshot!(schema, @r##"
+        {
+          "$id": "https://example.com/schemas/pet.json",
+          "type": "object",
+          "properties": {
+            "children": {
+              "type": "array",
+              "items": {
+                "$ref": "#"
+              }
+            },
+            "owner": {
+              "$ref": "#/$defs/Owner"
+            }
+          },
+          "$defs": {
+            "Address": {
+              "type": "object",
+              "properties": {
+                "street": {
+                  "type": "string"
+                }
+              }
+            },
+            "Owner": {
+              "$anchor": "owner",
+              "type": "object",
+              "properties": {
+                "address": {
+                  "$ref": "#/$defs/Address"
+                },
+                "name": {
+                  "type": "string"
+                }
+              }
+            }
+          }
+        }
+        "##);
+    }
+
+    #[test]
+    fn json_schema_document_declares_dialect() {
+        let document = JsonSchema::new(Object::with_type(Type::String));
+
+        assert_json_snapshot!(document, @r#"
+        {
+          "$schema": "https://json-schema.org/draft/2020-12/schema",
+          "type": "string"
+        }
+        "#);
+
+        let document = JsonSchema::new(true).dialect("https://example.com/dialect");
+
+        assert_json_snapshot!(document, @r#"
+        {
+          "$schema": "https://example.com/dialect",
+          "allOf": [
+            true
+          ]
+        }
+        "#);
+    }
+
     fn get_json_path<'a>(value: &'a Value, path: &str) -> &'a Value {
         path.split('.').fold(value, |acc, fragment| {
             acc.get(fragment).unwrap_or(&serde_json::value::Value::Null)
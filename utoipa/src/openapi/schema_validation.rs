@@ -0,0 +1,1009 @@
+//! Implements validation of JSON values against the [`Schema`]s of the [`OpenApi`] document.
+//!
+//! Use [`SchemaValidator`] to validate payloads against a schema resolving the references
+//! through the [`Components`] and [`OpenApi::validate_examples`] to check that every `example`
+//! and `examples` value of the document matches the schema it is an example of.
+//!
+//! The validator supports the JSON Schema keywords generated by `utoipa`: `$ref`, `type`,
+//! `format`, `enum`, `const`, numeric and length limits, `pattern`, `required`, `properties`,
+//! `patternProperties`, `additionalProperties`, `propertyNames`, `dependentRequired`,
+//! `dependentSchemas`, `items`, `prefixItems`, `contains`, `uniqueItems`, `allOf`, `anyOf`,
+//! `oneOf`, `not` and `if`, `then` and `else`.
+//!
+//! `unevaluatedProperties` and `unevaluatedItems` are not supported since they depend on the
+//! annotations collected from the other keywords. They are ignored together with unknown formats
+//! and keywords.
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::sync::{Arc, LazyLock, Mutex, PoisonError};
+
+use regex::Regex;
+use serde_json::{Map, Value};
+
+use super::validation::escape;
+use super::{Components, OpenApi, RefOr, Schema};
+
+/// Regular expressions of the supported string formats.
+static FORMATS: LazyLock<BTreeMap<&'static str, Regex>> = LazyLock::new(|| {
+    const DATE: &str = r"\d{4}-(0[1-9]|1[0-2])-(0[1-9]|[12]\d|3[01])";
+    const TIME: &str =
+        r"([01]\d|2[0-3]):[0-5]\d:([0-5]\d|60)(\.\d+)?([Zz]|[+-]([01]\d|2[0-3]):[0-5]\d)";
+
+    [
+        ("date", format!("^{DATE}$")),
+        ("time", format!("^{TIME}$")),
+        ("date-time", format!("^{DATE}[Tt ]{TIME}$")),
+        (
+            "uuid",
+            "^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$"
+                .to_string(),
+        ),
+        (
+            "ulid",
+            "^[0-7][0-9A-HJKMNP-TV-Za-hjkmnp-tv-z]{25}$".to_string(),
+        ),
+        ("email", r"^[^@\s]+@[^@\s]+$".to_string()),
+        ("uri", r"^[A-Za-z][A-Za-z0-9+.-]*:\S*$".to_string()),
+        (
+            "byte",
+            "^([A-Za-z0-9+/]{4})*([A-Za-z0-9+/]{2}==|[A-Za-z0-9+/]{3}=)?$".to_string(),
+        ),
+    ]
+    .into_iter()
+    .map(|(format, pattern)| {
+        (
+            format,
+            Regex::new(&pattern).expect("format must be valid regex"),
+        )
+    })
+    .collect()
+});
+
+/// Error of a value not matching its schema.
+///
+/// The `location` is a [JSON Pointer][json_pointer] prefixed with `#` to the invalid value. For
+/// [`SchemaValidator::validate`] it is relative to the validated value and for
+/// [`OpenApi::validate_examples`] it is relative to the document, e.g.
+/// `#/components/schemas/Pet/examples/0/name`.
+///
+/// [json_pointer]: https://datatracker.ietf.org/doc/html/rfc6901
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaValidationError {
+    /// Location of the invalid value.
+    pub location: String,
+
+    /// Description of why the value is invalid.
+    pub message: String,
+}
+
+impl Display for SchemaValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.location, self.message)
+    }
+}
+
+impl std::error::Error for SchemaValidationError {}
+
+/// Compiled regular expressions of `pattern` and `patternProperties` by the pattern. Patterns
+/// which are not valid regular expressions are stored as [`None`].
+type Regexes = Mutex<BTreeMap<String, Option<Regex>>>;
+
+/// Validates [`serde_json::Value`]s against [`Schema`]s.
+///
+/// Regular expressions of the schemas are compiled once and shared between the validations and
+/// the clones of the validator.
+///
+/// # Examples
+///
+/// _**Validate payload against schema of a type.**_
+/// ```rust
+/// # use utoipa::openapi::schema_validation::SchemaValidator;
+/// # use utoipa::{PartialSchema, ToSchema};
+/// #[derive(ToSchema)]
+/// struct Pet {
+///     name: String,
+///     age: Option<u8>,
+/// }
+///
+/// let validator = SchemaValidator::new(None);
+/// let errors = validator
+///     .validate(&Pet::schema(), &serde_json::json!({"age": -1}))
+///     .unwrap_err();
+///
+/// assert_eq!(errors.len(), 2);
+/// ```
+#[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct SchemaValidator {
+    document: Value,
+    regexes: Arc<Regexes>,
+}
+
+impl SchemaValidator {
+    /// Construct a new [`SchemaValidator`] resolving references of validated schemas through the
+    /// given [`Components`].
+    pub fn new(components: Option<&Components>) -> Self {
+        let mut document = Map::new();
+        if let Some(components) = components {
+            document.insert(
+                "components".to_string(),
+                serde_json::to_value(components).unwrap_or_default(),
+            );
+        }
+
+        Self {
+            document: Value::Object(document),
+            regexes: Arc::default(),
+        }
+    }
+
+    /// Validate `value` against the `schema` returning all the errors found.
+    pub fn validate(
+        &self,
+        schema: &RefOr<Schema>,
+        value: &Value,
+    ) -> Result<(), Vec<SchemaValidationError>> {
+        let schema = serde_json::to_value(schema).unwrap_or_default();
+        let mut validator = Validator::new(&self.document, &self.regexes);
+        validator.validate(&schema, value, "#");
+
+        validator.into_result()
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(number) if number.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn is_type(schema_type: &str, value: &Value) -> bool {
+    match schema_type {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "number" => value.is_number(),
+        "integer" => {
+            value.is_i64()
+                || value.is_u64()
+                || value.as_f64().is_some_and(|number| number.fract() == 0.0)
+        }
+        "string" => value.is_string(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        _ => true,
+    }
+}
+
+fn is_format(format: &str, value: &Value) -> bool {
+    let integer = value
+        .as_i64()
+        .map(i128::from)
+        .or_else(|| value.as_u64().map(i128::from));
+    let in_range =
+        |min: i128, max: i128| integer.is_none_or(|number| (min..=max).contains(&number));
+
+    match (format, value) {
+        ("int8", _) => in_range(i8::MIN.into(), i8::MAX.into()),
+        ("int16", _) => in_range(i16::MIN.into(), i16::MAX.into()),
+        ("int32", _) => in_range(i32::MIN.into(), i32::MAX.into()),
+        ("int64", _) => in_range(i64::MIN.into(), i64::MAX.into()),
+        ("uint8", _) => in_range(0, u8::MAX.into()),
+        ("uint16", _) => in_range(0, u16::MAX.into()),
+        ("uint32", _) => in_range(0, u32::MAX.into()),
+        ("uint64", _) => in_range(0, u64::MAX.into()),
+        ("ipv4", Value::String(value)) => value.parse::<Ipv4Addr>().is_ok(),
+        ("ipv6", Value::String(value)) => value.parse::<Ipv6Addr>().is_ok(),
+        (format, Value::String(value)) => FORMATS
+            .get(format)
+            .is_none_or(|regex| regex.is_match(value)),
+        _ => true,
+    }
+}
+
+struct Validator<'a> {
+    document: &'a Value,
+    regexes: &'a Regexes,
+    errors: Vec<SchemaValidationError>,
+    /// References being validated with their instance locations, used to stop cyclic references.
+    references: Vec<(&'a str, String)>,
+}
+
+impl<'a> Validator<'a> {
+    fn new(document: &'a Value, regexes: &'a Regexes) -> Self {
+        Self {
+            document,
+            regexes,
+            errors: Vec::new(),
+            references: Vec::new(),
+        }
+    }
+
+    fn into_result(self) -> Result<(), Vec<SchemaValidationError>> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(self.errors)
+        }
+    }
+
+    fn error<M: Into<String>>(&mut self, location: &str, message: M) {
+        self.errors.push(SchemaValidationError {
+            location: location.to_string(),
+            message: message.into(),
+        });
+    }
+
+    /// Check whether `value` is valid against `schema` without recording the errors.
+    fn is_valid(&mut self, schema: &'a Value, value: &Value, location: &str) -> bool {
+        let errors = std::mem::take(&mut self.errors);
+        self.validate(schema, value, location);
+        let is_valid = self.errors.is_empty();
+        self.errors = errors;
+
+        is_valid
+    }
+
+    fn regex(&mut self, pattern: &str, location: &str) -> Option<Regex> {
+        let regex = self
+            .regexes
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(pattern.to_string())
+            .or_insert_with(|| Regex::new(pattern).ok())
+            .clone();
+        if regex.is_none() {
+            self.error(
+                location,
+                format!("pattern `{pattern}` is not a valid regular expression"),
+            );
+        }
+
+        regex
+    }
+
+    fn validate(&mut self, schema: &'a Value, value: &Value, location: &str) {
+        let schema = match schema {
+            Value::Bool(false) => return self.error(location, "no value is allowed"),
+            Value::Object(schema) => schema,
+            _ => return,
+        };
+
+        if let Some(Value::String(reference)) = schema.get("$ref") {
+            self.reference(reference, value, location);
+        }
+        self.schema_type(schema, value, location);
+        if let Some(Value::Array(values)) = schema.get("enum") {
+            if !values.contains(value) {
+                self.error(
+                    location,
+                    format!("value {value} is not one of the `enum` values"),
+                );
+            }
+        }
+        if let Some(constant) = schema.get("const") {
+            if constant != value {
+                self.error(
+                    location,
+                    format!("value {value} does not equal to {constant}"),
+                );
+            }
+        }
+        if let Some(Value::String(format)) = schema.get("format") {
+            if !is_format(format, value) {
+                self.error(location, format!("value {value} is not valid `{format}`"));
+            }
+        }
+
+        match value {
+            Value::Number(_) => self.number(schema, value, location),
+            Value::String(string) => self.string(schema, string, location),
+            Value::Array(values) => self.array(schema, values, location),
+            Value::Object(object) => self.object(schema, object, location),
+            _ => (),
+        }
+        self.composite(schema, value, location);
+    }
+
+    fn reference(&mut self, reference: &'a str, value: &Value, location: &str) {
+        let Some(schema) = reference
+            .strip_prefix('#')
+            .and_then(|pointer| self.document.pointer(pointer))
+        else {
+            return self.error(
+                location,
+                format!("reference `{reference}` cannot be resolved"),
+            );
+        };
+
+        let key = (reference, location.to_string());
+        if self.references.contains(&key) {
+            return;
+        }
+        self.references.push(key);
+        self.validate(schema, value, location);
+        self.references.pop();
+    }
+
+    fn schema_type(&mut self, schema: &Map<String, Value>, value: &Value, location: &str) {
+        let types = match schema.get("type") {
+            Some(Value::String(schema_type)) => vec![schema_type.as_str()],
+            Some(Value::Array(types)) => types.iter().filter_map(Value::as_str).collect(),
+            _ => return,
+        };
+
+        if !types.iter().any(|schema_type| is_type(schema_type, value)) {
+            self.error(
+                location,
+                format!(
+                    "expected type `{}` but found `{}`",
+                    types.join("` or `"),
+                    type_name(value)
+                ),
+            );
+        }
+    }
+
+    fn number(&mut self, schema: &Map<String, Value>, value: &Value, location: &str) {
+        let Some(number) = value.as_f64() else {
+            return;
+        };
+        let limit = |keyword: &str| schema.get(keyword).and_then(Value::as_f64);
+
+        if let Some(minimum) = limit("minimum").filter(|minimum| number < *minimum) {
+            self.error(
+                location,
+                format!("value {value} is less than minimum {minimum}"),
+            );
+        }
+        if let Some(minimum) = limit("exclusiveMinimum").filter(|minimum| number <= *minimum) {
+            self.error(
+                location,
+                format!("value {value} is less than or equal to exclusive minimum {minimum}"),
+            );
+        }
+        if let Some(maximum) = limit("maximum").filter(|maximum| number > *maximum) {
+            self.error(
+                location,
+                format!("value {value} is greater than maximum {maximum}"),
+            );
+        }
+        if let Some(maximum) = limit("exclusiveMaximum").filter(|maximum| number >= *maximum) {
+            self.error(
+                location,
+                format!("value {value} is greater than or equal to exclusive maximum {maximum}"),
+            );
+        }
+        if let Some(multiple_of) = limit("multipleOf").filter(|multiple_of| *multiple_of > 0.0) {
+            let quotient = number / multiple_of;
+            if (quotient - quotient.round()).abs() > f64::EPSILON * quotient.abs().max(1.0) {
+                self.error(
+                    location,
+                    format!("value {value} is not multiple of {multiple_of}"),
+                );
+            }
+        }
+    }
+
+    fn string(&mut self, schema: &Map<String, Value>, string: &str, location: &str) {
+        let length = string.chars().count() as u64;
+
+        if let Some(min) = schema.get("minLength").and_then(Value::as_u64) {
+            if length < min {
+                self.error(
+                    location,
+                    format!("length {length} is less than minLength {min}"),
+                );
+            }
+        }
+        if let Some(max) = schema.get("maxLength").and_then(Value::as_u64) {
+            if length > max {
+                self.error(
+                    location,
+                    format!("length {length} is greater than maxLength {max}"),
+                );
+            }
+        }
+        if let Some(Value::String(pattern)) = schema.get("pattern") {
+            if let Some(regex) = self.regex(pattern, location) {
+                if !regex.is_match(string) {
+                    self.error(
+                        location,
+                        format!("value \"{string}\" does not match pattern `{pattern}`"),
+                    );
+                }
+            }
+        }
+    }
+
+    fn array(&mut self, schema: &'a Map<String, Value>, values: &[Value], location: &str) {
+        let length = values.len() as u64;
+
+        if let Some(min) = schema.get("minItems").and_then(Value::as_u64) {
+            if length < min {
+                self.error(
+                    location,
+                    format!("array has {length} items which is less than minItems {min}"),
+                );
+            }
+        }
+        if let Some(max) = schema.get("maxItems").and_then(Value::as_u64) {
+            if length > max {
+                self.error(
+                    location,
+                    format!("array has {length} items which is more than maxItems {max}"),
+                );
+            }
+        }
+        if schema.get("uniqueItems") == Some(&Value::Bool(true)) {
+            let has_duplicates = values
+                .iter()
+                .enumerate()
+                .any(|(index, value)| values[..index].contains(value));
+            if has_duplicates {
+                self.error(location, "array items are not unique");
+            }
+        }
+
+        let prefix_items = match schema.get("prefixItems") {
+            Some(Value::Array(prefix_items)) => prefix_items.as_slice(),
+            _ => &[],
+        };
+        for (index, value) in values.iter().enumerate() {
+            let item_location = format!("{location}/{index}");
+            if let Some(item) = prefix_items.get(index).or_else(|| schema.get("items")) {
+                self.validate(item, value, &item_location);
+            }
+        }
+
+        if let Some(contains) = schema.get("contains") {
+            let count = values
+                .iter()
+                .enumerate()
+                .filter(|(index, value)| {
+                    self.is_valid(contains, value, &format!("{location}/{index}"))
+                })
+                .count() as u64;
+            let min = schema
+                .get("minContains")
+                .and_then(Value::as_u64)
+                .unwrap_or(1);
+            if count < min {
+                self.error(
+                    location,
+                    format!("array contains {count} items matching `contains` but at least {min} is required"),
+                );
+            }
+            if let Some(max) = schema.get("maxContains").and_then(Value::as_u64) {
+                if count > max {
+                    self.error(
+                        location,
+                        format!("array contains {count} items matching `contains` but at most {max} is allowed"),
+                    );
+                }
+            }
+        }
+    }
+
+    fn object(
+        &mut self,
+        schema: &'a Map<String, Value>,
+        object: &Map<String, Value>,
+        location: &str,
+    ) {
+        let length = object.len() as u64;
+
+        if let Some(min) = schema.get("minProperties").and_then(Value::as_u64) {
+            if length < min {
+                self.error(
+                    location,
+                    format!(
+                        "object has {length} properties which is less than minProperties {min}"
+                    ),
+                );
+            }
+        }
+        if let Some(max) = schema.get("maxProperties").and_then(Value::as_u64) {
+            if length > max {
+                self.error(
+                    location,
+                    format!(
+                        "object has {length} properties which is more than maxProperties {max}"
+                    ),
+                );
+            }
+        }
+        if let Some(Value::Array(required)) = schema.get("required") {
+            for name in required.iter().filter_map(Value::as_str) {
+                if !object.contains_key(name) {
+                    self.error(location, format!("property `{name}` is required"));
+                }
+            }
+        }
+        for (name, required) in schema
+            .get("dependentRequired")
+            .and_then(Value::as_object)
+            .into_iter()
+            .flatten()
+            .filter(|(name, _)| object.contains_key(*name))
+        {
+            for required in required
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+            {
+                if !object.contains_key(required) {
+                    self.error(
+                        location,
+                        format!("property `{required}` is required when `{name}` is present"),
+                    );
+                }
+            }
+        }
+
+        let properties = schema.get("properties").and_then(Value::as_object);
+        let pattern_properties = schema
+            .get("patternProperties")
+            .and_then(Value::as_object)
+            .into_iter()
+            .flatten()
+            .filter_map(|(pattern, property)| {
+                self.regex(pattern, location).map(|regex| (regex, property))
+            })
+            .collect::<Vec<_>>();
+
+        for (name, value) in object {
+            let property_location = format!("{location}/{}", escape(name));
+            let mut is_additional = true;
+
+            if let Some(property_names) = schema.get("propertyNames") {
+                if !self.is_valid(property_names, &Value::String(name.clone()), location) {
+                    self.error(
+                        &property_location,
+                        format!("property name `{name}` does not match `propertyNames` schema"),
+                    );
+                }
+            }
+
+            if let Some(property) = properties.and_then(|properties| properties.get(name)) {
+                is_additional = false;
+                self.validate(property, value, &property_location);
+            }
+            for (_, property) in pattern_properties
+                .iter()
+                .filter(|(regex, _)| regex.is_match(name))
+            {
+                is_additional = false;
+                self.validate(property, value, &property_location);
+            }
+
+            match schema.get("additionalProperties") {
+                Some(Value::Bool(false)) if is_additional => self.error(
+                    &property_location,
+                    format!("additional property `{name}` is not allowed"),
+                ),
+                Some(additional) if is_additional => {
+                    self.validate(additional, value, &property_location)
+                }
+                _ => (),
+            }
+        }
+    }
+
+    fn composite(&mut self, schema: &'a Map<String, Value>, value: &Value, location: &str) {
+        let schemas = |keyword: &str| {
+            schema
+                .get(keyword)
+                .and_then(Value::as_array)
+                .map(Vec::as_slice)
+        };
+
+        for all_of in schemas("allOf").into_iter().flatten() {
+            self.validate(all_of, value, location);
+        }
+        if let Some(any_of) = schemas("anyOf") {
+            if !any_of
+                .iter()
+                .any(|any_of| self.is_valid(any_of, value, location))
+            {
+                self.error(location, "value does not match any of the `anyOf` schemas");
+            }
+        }
+        if let Some(one_of) = schemas("oneOf") {
+            match one_of
+                .iter()
+                .filter(|one_of| self.is_valid(one_of, value, location))
+                .count()
+            {
+                0 => self.error(location, "value does not match any of the `oneOf` schemas"),
+                1 => (),
+                count => self.error(
+                    location,
+                    format!(
+                        "value matches {count} of the `oneOf` schemas but must match exactly one"
+                    ),
+                ),
+            }
+        }
+        if let Some(not) = schema.get("not") {
+            if self.is_valid(not, value, location) {
+                self.error(location, "value must not match the `not` schema");
+            }
+        }
+        if let Some(condition) = schema.get("if") {
+            let branch = if self.is_valid(condition, value, location) {
+                "then"
+            } else {
+                "else"
+            };
+            if let Some(branch) = schema.get(branch) {
+                self.validate(branch, value, location);
+            }
+        }
+        if let (Some(Value::Object(dependent_schemas)), Value::Object(object)) =
+            (schema.get("dependentSchemas"), value)
+        {
+            for (_, dependent_schema) in dependent_schemas
+                .iter()
+                .filter(|(name, _)| object.contains_key(*name))
+            {
+                self.validate(dependent_schema, value, location);
+            }
+        }
+    }
+}
+
+/// Walks the document validating the examples against their schemas.
+struct Examples<'a> {
+    validator: Validator<'a>,
+}
+
+impl<'a> Examples<'a> {
+    /// Resolve `$ref` of [`Example`][example] to the components of the document.
+    ///
+    /// [example]: super::example::Example
+    fn example(&self, example: &'a Value) -> &'a Value {
+        match example.get("$ref") {
+            Some(Value::String(reference)) => reference
+                .strip_prefix('#')
+                .and_then(|pointer| self.validator.document.pointer(pointer))
+                .unwrap_or(example),
+            _ => example,
+        }
+    }
+
+    /// Walk the document objects such as path items, operations and responses looking for
+    /// media types, parameters and headers which have a schema and examples.
+    fn walk(&mut self, node: &'a Value, location: &str) {
+        match node {
+            Value::Object(object) => {
+                if let Some(schema) = object.get("schema") {
+                    if let Some(example) = object.get("example") {
+                        self.validator
+                            .validate(schema, example, &format!("{location}/example"));
+                    }
+                    for (name, example) in object
+                        .get("examples")
+                        .and_then(Value::as_object)
+                        .into_iter()
+                        .flatten()
+                    {
+                        if let Some(value) = self.example(example).get("value") {
+                            let location = format!("{location}/examples/{}/value", escape(name));
+                            self.validator.validate(schema, value, &location);
+                        }
+                    }
+                    self.schema(schema, &format!("{location}/schema"));
+                }
+
+                for (key, value) in object {
+                    let key_location = format!("{location}/{}", escape(key));
+                    match key.as_str() {
+                        "schema" | "example" | "examples" => (),
+                        key if key.starts_with("x-") => (),
+                        "schemas" if location == "#/components" => {
+                            for (name, schema) in value.as_object().into_iter().flatten() {
+                                self.schema(schema, &format!("{key_location}/{}", escape(name)));
+                            }
+                        }
+                        _ => self.walk(value, &key_location),
+                    }
+                }
+            }
+            Value::Array(values) => {
+                for (index, value) in values.iter().enumerate() {
+                    self.walk(value, &format!("{location}/{index}"));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Validate the `example` and `examples` of a schema and its sub schemas.
+    fn schema(&mut self, schema: &'a Value, location: &str) {
+        let Value::Object(object) = schema else {
+            return;
+        };
+
+        if let Some(example) = object.get("example") {
+            self.validator
+                .validate(schema, example, &format!("{location}/example"));
+        }
+        for (index, example) in object
+            .get("examples")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .enumerate()
+        {
+            self.validator
+                .validate(schema, example, &format!("{location}/examples/{index}"));
+        }
+
+        for (keyword, value) in object {
+            let keyword_location = format!("{location}/{keyword}");
+            match keyword.as_str() {
+                "properties" | "patternProperties" | "dependentSchemas" | "$defs" => {
+                    for (name, schema) in value.as_object().into_iter().flatten() {
+                        self.schema(schema, &format!("{keyword_location}/{}", escape(name)));
+                    }
+                }
+                "allOf" | "anyOf" | "oneOf" | "prefixItems" => {
+                    for (index, schema) in value.as_array().into_iter().flatten().enumerate() {
+                        self.schema(schema, &format!("{keyword_location}/{index}"));
+                    }
+                }
+                "items"
+                | "additionalProperties"
+                | "unevaluatedProperties"
+                | "unevaluatedItems"
+                | "contains"
+                | "propertyNames"
+                | "not"
+                | "if"
+                | "then"
+                | "else" => self.schema(value, &keyword_location),
+                _ => (),
+            }
+        }
+    }
+}
+
+pub(super) fn validate_examples(openapi: &OpenApi) -> Result<(), Vec<SchemaValidationError>> {
+    let document = serde_json::to_value(openapi).unwrap_or_default();
+    let regexes = Regexes::default();
+    let mut examples = Examples {
+        validator: Validator::new(&document, &regexes),
+    };
+    examples.walk(&document, "#");
+
+    examples.validator.into_result()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::openapi::path::OperationBuilder;
+    use crate::openapi::{
+        schema::{AdditionalProperties, SchemaFormat, SchemaType},
+        ArrayBuilder, ComponentsBuilder, Content, HttpMethod, Info, KnownFormat, ObjectBuilder,
+        OneOfBuilder, OpenApiBuilder, PathItem, PathsBuilder, Ref, ResponseBuilder, Type,
+    };
+
+    use super::*;
+
+    fn locations(result: Result<(), Vec<SchemaValidationError>>) -> Vec<String> {
+        result
+            .unwrap_err()
+            .into_iter()
+            .map(|error| error.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn validate_value_against_schema() {
+        let components = ComponentsBuilder::new()
+            .schema(
+                "Tag",
+                ObjectBuilder::new()
+                    .schema_type(Type::String)
+                    .pattern(Some("^[a-z]+$"))
+                    .max_length(Some(5)),
+            )
+            .build();
+        let schema: RefOr<Schema> = ObjectBuilder::new()
+            .property(
+                "id",
+                ObjectBuilder::new()
+                    .schema_type(Type::Integer)
+                    .format(Some(SchemaFormat::KnownFormat(KnownFormat::Int32)))
+                    .minimum(Some(1)),
+            )
+            .required("id")
+            .property(
+                "name",
+                ObjectBuilder::new().schema_type(SchemaType::from_iter([Type::String, Type::Null])),
+            )
+            .required("name")
+            .property(
+                "born",
+                ObjectBuilder::new()
+                    .schema_type(Type::String)
+                    .format(Some(SchemaFormat::KnownFormat(KnownFormat::Date))),
+            )
+            .property(
+                "tags",
+                ArrayBuilder::new()
+                    .items(Ref::from_schema_name("Tag"))
+                    .unique_items(true),
+            )
+            .property(
+                "kind",
+                OneOfBuilder::new()
+                    .item(
+                        ObjectBuilder::new()
+                            .schema_type(Type::String)
+                            .enum_values(Some(["cat", "dog"])),
+                    )
+                    .item(ObjectBuilder::new().schema_type(Type::Integer)),
+            )
+            .additional_properties(Some(AdditionalProperties::FreeForm(false)))
+            .into();
+
+        let validator = SchemaValidator::new(Some(&components));
+        assert_eq!(
+            validator.validate(
+                &schema,
+                &json!({"id": 1, "name": null, "born": "2020-02-29", "tags": ["a"], "kind": "cat"})
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            locations(validator.validate(
+                &schema,
+                &json!({
+                    "id": 4294967296_u64,
+                    "born": "2020-13-01",
+                    "tags": ["abc", "Abc", "abcdef", "abc"],
+                    "kind": "bird",
+                    "extra": true
+                })
+            )),
+            [
+                "#: property `name` is required",
+                "#/born: value \"2020-13-01\" is not valid `date`",
+                "#/extra: additional property `extra` is not allowed",
+                "#/id: value 4294967296 is not valid `int32`",
+                "#/kind: value does not match any of the `oneOf` schemas",
+                "#/tags: array items are not unique",
+                "#/tags/1: value \"Abc\" does not match pattern `^[a-z]+$`",
+                "#/tags/2: length 6 is greater than maxLength 5",
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_conditional_and_dependent_keywords() {
+        let schema: RefOr<Schema> = ObjectBuilder::new()
+            .schema_type(Type::Object)
+            .property_names(Some(
+                ObjectBuilder::new()
+                    .schema_type(Type::String)
+                    .pattern(Some("^[a-z]+$")),
+            ))
+            .dependent_required("card", ["billing"])
+            .dependent_schema(
+                "billing",
+                ObjectBuilder::new()
+                    .property("billing", ObjectBuilder::new().schema_type(Type::String)),
+            )
+            .if_schema(Some(
+                ObjectBuilder::new().property(
+                    "kind",
+                    ObjectBuilder::new()
+                        .schema_type(Type::String)
+                        .const_value(Some("cat")),
+                ),
+            ))
+            .then_schema(Some(ObjectBuilder::new().required("lives")))
+            .else_schema(Some(
+                ObjectBuilder::new()
+                    .property("lives", ObjectBuilder::new().schema_type(Type::String)),
+            ))
+            .into();
+
+        let validator = SchemaValidator::new(None);
+        assert_eq!(
+            validator.validate(
+                &schema,
+                &json!({"kind": "cat", "lives": 9, "card": 1, "billing": "home"})
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            locations(validator.validate(&schema, &json!({"kind": "cat", "card": 1, "Bad": 1}))),
+            [
+                "#: property `billing` is required when `card` is present",
+                "#/Bad: property name `Bad` does not match `propertyNames` schema",
+                "#: property `lives` is required",
+            ]
+        );
+        assert_eq!(
+            locations(
+                validator.validate(&schema, &json!({"kind": "dog", "lives": 1, "billing": 2}))
+            ),
+            [
+                "#/lives: expected type `string` but found `integer`",
+                "#/billing: expected type `string` but found `integer`",
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_examples_of_openapi() {
+        let openapi = OpenApiBuilder::new()
+            .info(Info::new("api", "1.0.0"))
+            .paths(
+                PathsBuilder::new().path(
+                    "/pets",
+                    PathItem::new(
+                        HttpMethod::Get,
+                        OperationBuilder::new().response(
+                            "200",
+                            ResponseBuilder::new().content(
+                                "application/json",
+                                Content::builder()
+                                    .schema(Some(
+                                        ArrayBuilder::new().items(Ref::from_schema_name("Pet")),
+                                    ))
+                                    .example(Some(json!([{"name": "Tom"}, {"name": 1}])))
+                                    .build(),
+                            ),
+                        ),
+                    ),
+                ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Pet",
+                        ObjectBuilder::new()
+                            .property(
+                                "name",
+                                ObjectBuilder::new()
+                                    .schema_type(Type::String)
+                                    .examples(["Tom", "Jerry"]),
+                            )
+                            .property(
+                                "age",
+                                ObjectBuilder::new()
+                                    .schema_type(Type::Integer)
+                                    .examples([json!(-1), json!(1.5)])
+                                    .minimum(Some(0)),
+                            )
+                            .required("name")
+                            .examples([json!({"name": "Tom"}), json!({"age": 2})]),
+                    )
+                    .build(),
+            ))
+            .build();
+
+        assert_eq!(
+            locations(openapi.validate_examples()),
+            [
+                "#/components/schemas/Pet/examples/1: property `name` is required",
+                "#/components/schemas/Pet/properties/age/examples/0: value -1 is less than minimum 0",
+                "#/components/schemas/Pet/properties/age/examples/1: expected type `integer` but found `number`",
+                "#/paths/~1pets/get/responses/200/content/application~1json/example/1/name: expected type `string` but found `integer`",
+            ]
+        );
+    }
+}
@@ -133,6 +133,18 @@ impl SecurityRequirement {
 
         self
     }
+
+    /// Rename required [`SecurityScheme`] `from` to `to` if this [`SecurityRequirement`] has it.
+    pub(crate) fn rename(&mut self, from: &str, to: &str) {
+        if let Some(scopes) = self.value.remove(from) {
+            self.value.insert(to.to_string(), scopes);
+        }
+    }
+
+    /// Get iterator over names of the [`SecurityScheme`]s required by this [`SecurityRequirement`].
+    pub(crate) fn names(&self) -> impl Iterator<Item = &String> {
+        self.value.keys()
+    }
 }
 
 /// OpenAPI [security scheme][security] for path operations.
@@ -544,6 +556,13 @@ pub enum Flow {
     ///
     /// Soon to be deprecated by <https://datatracker.ietf.org/doc/html/draft-ietf-oauth-security-topics>.
     Implicit(Implicit),
+    // device authorization must be tried before password and client credentials flows when
+    // deserializing since they only require the `tokenUrl`
+    /// Define device authorization [`Flow`] type. See [`DeviceAuthorization::new`] for usage
+    /// details.
+    ///
+    /// Supported from OpenAPI 3.2 onwards.
+    DeviceAuthorization(DeviceAuthorization),
     /// Define password [`Flow`] type. See [`Password::new`] for usage details.
     Password(Password),
     /// Define client credentials [`Flow`] type. See [`ClientCredentials::new`] for usage details.
@@ -559,6 +578,7 @@ impl Flow {
             Self::Password(_) => "password",
             Self::ClientCredentials(_) => "clientCredentials",
             Self::AuthorizationCode(_) => "authorizationCode",
+            Self::DeviceAuthorization(_) => "deviceAuthorization",
         }
     }
 }
@@ -929,6 +949,99 @@ impl ClientCredentials {
     }
 }
 
+/// Device authorization [`Flow`] configuration for [`OAuth2`] as defined in [RFC 8628][rfc8628].
+///
+/// [rfc8628]: https://datatracker.ietf.org/doc/html/rfc8628
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct DeviceAuthorization {
+    /// Url used to obtain device and user codes for the flow.
+    pub device_authorization_url: String,
+    /// Token url for the flow.
+    pub token_url: String,
+
+    /// Optional refresh token url for the flow.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_url: Option<String>,
+
+    /// Scopes required by the flow.
+    #[serde(flatten)]
+    pub scopes: Scopes,
+
+    /// Optional extensions "x-something".
+    #[serde(skip_serializing_if = "Option::is_none", flatten)]
+    pub extensions: Option<Extensions>,
+}
+
+impl DeviceAuthorization {
+    /// Construct a new device authorization oauth flow.
+    ///
+    /// Accepts three arguments: one which is device authorization url, two a token url and
+    /// three a map of scopes for oauth flow.
+    ///
+    /// # Examples
+    ///
+    /// Create new device authorization flow with scopes.
+    /// ```rust
+    /// # use utoipa::openapi::security::{DeviceAuthorization, Scopes};
+    /// DeviceAuthorization::new(
+    ///     "https://localhost/device/code",
+    ///     "https://localhost/token",
+    ///     Scopes::from_iter([
+    ///         ("edit:items", "edit my items"),
+    ///         ("read:items", "read my items")
+    ///     ]),
+    /// );
+    /// ```
+    pub fn new<D: Into<String>, T: Into<String>>(
+        device_authorization_url: D,
+        token_url: T,
+        scopes: Scopes,
+    ) -> Self {
+        Self {
+            device_authorization_url: device_authorization_url.into(),
+            token_url: token_url.into(),
+            refresh_url: None,
+            scopes,
+            extensions: Default::default(),
+        }
+    }
+
+    /// Construct a new [`DeviceAuthorization`] OAuth2 flow with additional refresh token url.
+    ///
+    /// This is essentially same as [`DeviceAuthorization::new`] but allows defining extra
+    /// parameter `refresh_url` for fetching refresh token.
+    ///
+    /// # Examples
+    ///
+    /// Create [`DeviceAuthorization`] OAuth2 flow with refresh url.
+    /// ```rust
+    /// # use utoipa::openapi::security::{DeviceAuthorization, Scopes};
+    /// DeviceAuthorization::with_refresh_url(
+    ///     "https://localhost/device/code",
+    ///     "https://localhost/token",
+    ///     Scopes::new(),
+    ///     "https://localhost/refresh-token"
+    /// );
+    /// ```
+    pub fn with_refresh_url<S: Into<String>>(
+        device_authorization_url: S,
+        token_url: S,
+        scopes: Scopes,
+        refresh_url: S,
+    ) -> Self {
+        Self {
+            device_authorization_url: device_authorization_url.into(),
+            token_url: token_url.into(),
+            refresh_url: Some(refresh_url.into()),
+            scopes,
+            extensions: Default::default(),
+        }
+    }
+}
+
 /// [`OAuth2`] flow scopes object defines required permissions for oauth flow.
 ///
 /// Scopes must be given to oauth2 flow but depending on need one of few initialization methods
@@ -1307,6 +1420,31 @@ mod tests {
 }"###
     }
 
+    test_fn! {
+        security_scheme_correct_oauth2_device_authorization:
+        SecurityScheme::OAuth2(
+            OAuth2::new([Flow::DeviceAuthorization(
+                DeviceAuthorization::new(
+                    "https://localhost/device/code",
+                    "https://localhost/token/url",
+                    Scopes::one("read:items", "read my items"),
+                ),
+            )])
+        );
+        r###"{
+  "type": "oauth2",
+  "flows": {
+    "deviceAuthorization": {
+      "deviceAuthorizationUrl": "https://localhost/device/code",
+      "tokenUrl": "https://localhost/token/url",
+      "scopes": {
+        "read:items": "read my items"
+      }
+    }
+  }
+}"###
+    }
+
     test_fn! {
         security_scheme_correct_mutual_tls:
         SecurityScheme::MutualTls {
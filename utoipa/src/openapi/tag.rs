@@ -29,6 +29,18 @@ builder! {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub external_docs: Option<ExternalDocs>,
 
+        /// Name of the parent tag of this tag allowing tags to be nested into hierarchies. The
+        /// parent tag must be defined in the document. The _`parent`_ field is supported from
+        /// OpenAPI 3.2 onwards.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub parent: Option<String>,
+
+        /// Machine readable category of the tag e.g. `nav`, `badge` or `audience` telling tools
+        /// how the tag is meant to be used. The _`kind`_ field is supported from OpenAPI 3.2
+        /// onwards.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub kind: Option<String>,
+
         /// Optional extensions "x-something".
         #[serde(skip_serializing_if = "Option::is_none", flatten)]
         pub extensions: Option<Extensions>,
@@ -61,6 +73,16 @@ impl TagBuilder {
         set_value!(self external_docs external_docs)
     }
 
+    /// Add name of the parent tag of this tag.
+    pub fn parent<S: Into<String>>(mut self, parent: Option<S>) -> Self {
+        set_value!(self parent parent.map(|parent| parent.into()))
+    }
+
+    /// Add machine readable category of the tag e.g. `nav`, `badge` or `audience`.
+    pub fn kind<S: Into<String>>(mut self, kind: Option<S>) -> Self {
+        set_value!(self kind kind.map(|kind| kind.into()))
+    }
+
     /// Add openapi extensions (x-something) to the tag.
     pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
         set_value!(self extensions extensions)
@@ -0,0 +1,555 @@
+//! Implements spec level validation of the [`OpenApi`] document.
+//!
+//! Use [`OpenApi::validate`] to validate the document. The validation catches mistakes that are
+//! not visible from the Rust types such as references to components that do not exist or
+//! operations using security schemes which are not defined.
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+
+use serde_json::Value;
+
+use super::path::{HttpMethod, Operation, ParameterIn, PathItem};
+use super::security::{Flow, SecurityScheme};
+use super::{OpenApi, OpenApiVersion, RefOr};
+
+/// Error found in [`OpenApi`] document with [`OpenApi::validate`].
+///
+/// Each error has a `location` which is a [JSON Pointer][json_pointer] prefixed with `#` to the
+/// element of the document causing the error, e.g. `#/paths/~1pets~1{id}/get`.
+///
+/// [json_pointer]: https://datatracker.ietf.org/doc/html/rfc6901
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    /// `$ref` points to a component that is not defined in the [`Components`][components].
+    ///
+    /// [components]: super::Components
+    DanglingRef {
+        /// Location of the `$ref`.
+        location: String,
+        /// The reference that cannot be resolved.
+        reference: String,
+    },
+    /// Same `operationId` is used by more than one operation.
+    DuplicateOperationId {
+        /// Location of the duplicate operation.
+        location: String,
+        /// The duplicate `operationId`.
+        operation_id: String,
+        /// Location of the operation that first declared the `operationId`.
+        first_location: String,
+    },
+    /// Path template parameter e.g. `{id}` does not have matching [`ParameterIn::Path`] parameter.
+    MissingPathParameter {
+        /// Location of the operation missing the parameter.
+        location: String,
+        /// Name of the path template parameter.
+        parameter: String,
+    },
+    /// Security requirement names a security scheme that is not defined in
+    /// [`Components::security_schemes`][security_schemes].
+    ///
+    /// [security_schemes]: super::Components::security_schemes
+    UndefinedSecurityScheme {
+        /// Location of the security requirement.
+        location: String,
+        /// Name of the undefined security scheme.
+        name: String,
+    },
+    /// Operation or [`Tag::parent`][super::tag::Tag::parent] uses a tag that is not defined in
+    /// [`OpenApi::tags`].
+    UndefinedTag {
+        /// Location of the operation or the parent of the tag.
+        location: String,
+        /// Name of the undefined tag.
+        tag: String,
+    },
+    /// Field supported from OpenAPI 3.2 onwards is used in a document of
+    /// [`OpenApiVersion::Version31`]. Such as [`HttpMethod::Query`][query] operation or
+    /// [`Flow::DeviceAuthorization`][device_authorization].
+    ///
+    /// [query]: super::HttpMethod::Query
+    /// [device_authorization]: super::security::Flow::DeviceAuthorization
+    UnsupportedField {
+        /// Location of the field.
+        location: String,
+        /// Name of the field.
+        field: String,
+    },
+}
+
+impl ValidationError {
+    /// Get location of the error in the [`OpenApi`] document as [JSON Pointer][json_pointer]
+    /// prefixed with `#`.
+    ///
+    /// [json_pointer]: https://datatracker.ietf.org/doc/html/rfc6901
+    pub fn location(&self) -> &str {
+        match self {
+            Self::DanglingRef { location, .. }
+            | Self::DuplicateOperationId { location, .. }
+            | Self::MissingPathParameter { location, .. }
+            | Self::UndefinedSecurityScheme { location, .. }
+            | Self::UndefinedTag { location, .. }
+            | Self::UnsupportedField { location, .. } => location,
+        }
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DanglingRef {
+                location,
+                reference,
+            } => write!(f, "{location}: reference `{reference}` does not exist"),
+            Self::DuplicateOperationId {
+                location,
+                operation_id,
+                first_location,
+            } => write!(
+                f,
+                "{location}: operationId `{operation_id}` is already used in {first_location}"
+            ),
+            Self::MissingPathParameter {
+                location,
+                parameter,
+            } => write!(
+                f,
+                "{location}: path parameter `{parameter}` is not defined for the operation"
+            ),
+            Self::UndefinedSecurityScheme { location, name } => {
+                write!(f, "{location}: security scheme `{name}` is not defined")
+            }
+            Self::UndefinedTag { location, tag } => {
+                write!(f, "{location}: tag `{tag}` is not defined")
+            }
+            Self::UnsupportedField { location, field } => write!(
+                f,
+                "{location}: field `{field}` is not supported before OpenAPI 3.2"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Escape JSON Pointer reference token according to RFC 6901.
+pub(super) fn escape(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+pub(super) fn validate(openapi: &OpenApi) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+
+    if let Ok(document) = serde_json::to_value(openapi) {
+        validate_refs(&document, &document, &mut String::from("#"), &mut errors);
+    }
+
+    let security_schemes = openapi
+        .components
+        .as_ref()
+        .map(|components| &components.security_schemes);
+    let tags = openapi
+        .tags
+        .iter()
+        .flatten()
+        .map(|tag| tag.name.as_str())
+        .collect::<BTreeSet<_>>();
+
+    let version_31 = openapi.openapi == OpenApiVersion::Version31;
+
+    for (index, tag) in openapi.tags.iter().flatten().enumerate() {
+        if version_31 {
+            for (field, value) in [("parent", &tag.parent), ("kind", &tag.kind)] {
+                if value.is_some() {
+                    errors.push(ValidationError::UnsupportedField {
+                        location: format!("#/tags/{index}/{field}"),
+                        field: field.to_string(),
+                    });
+                }
+            }
+        }
+
+        if let Some(parent) = tag
+            .parent
+            .as_deref()
+            .filter(|parent| !tags.contains(parent))
+        {
+            errors.push(ValidationError::UndefinedTag {
+                location: format!("#/tags/{index}/parent"),
+                tag: parent.to_string(),
+            });
+        }
+    }
+
+    for (name, scheme) in security_schemes.into_iter().flatten() {
+        if let SecurityScheme::OAuth2(oauth2) = scheme {
+            for (flow_name, flow) in &oauth2.flows {
+                if version_31 && matches!(flow, Flow::DeviceAuthorization(_)) {
+                    errors.push(ValidationError::UnsupportedField {
+                        location: format!(
+                            "#/components/securitySchemes/{}/flows/{}",
+                            escape(name),
+                            escape(flow_name)
+                        ),
+                        field: flow_name.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    for (index, requirement) in openapi.security.iter().flatten().enumerate() {
+        validate_security_requirement(
+            requirement.names(),
+            security_schemes,
+            &format!("#/security/{index}"),
+            &mut errors,
+        );
+    }
+
+    let mut operation_ids = BTreeMap::<&str, String>::new();
+    let operations = openapi
+        .paths
+        .paths
+        .iter()
+        .map(|(path, item)| (Some(path), format!("#/paths/{}", escape(path)), item))
+        .chain(
+            openapi
+                .webhooks
+                .iter()
+                .filter_map(|(name, item)| match item {
+                    RefOr::T(item) => Some((None, format!("#/webhooks/{}", escape(name)), item)),
+                    RefOr::Ref(_) => None,
+                }),
+        );
+
+    for (path, item_location, item) in operations {
+        for (method, operation) in item.operations() {
+            let location = format!("{item_location}/{}", method.pointer());
+
+            let version_32_field = match method {
+                HttpMethod::Query => Some("query"),
+                HttpMethod::Custom(_) => Some("additionalOperations"),
+                _ => None,
+            };
+            if let Some(field) = version_32_field.filter(|_| version_31) {
+                errors.push(ValidationError::UnsupportedField {
+                    location: location.clone(),
+                    field: field.to_string(),
+                });
+            }
+
+            if let Some(operation_id) = operation.operation_id.as_deref() {
+                match operation_ids.get(operation_id) {
+                    Some(first_location) => errors.push(ValidationError::DuplicateOperationId {
+                        location: location.clone(),
+                        operation_id: operation_id.to_string(),
+                        first_location: first_location.clone(),
+                    }),
+                    None => {
+                        operation_ids.insert(operation_id, location.clone());
+                    }
+                }
+            }
+
+            if let Some(path) = path {
+                validate_path_parameters(path, item, operation, openapi, &location, &mut errors);
+            }
+
+            for (index, requirement) in operation.security.iter().flatten().enumerate() {
+                validate_security_requirement(
+                    requirement.names(),
+                    security_schemes,
+                    &format!("{location}/security/{index}"),
+                    &mut errors,
+                );
+            }
+
+            for tag in operation.tags.iter().flatten() {
+                if !tags.contains(tag.as_str()) {
+                    errors.push(ValidationError::UndefinedTag {
+                        location: location.clone(),
+                        tag: tag.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    errors
+}
+
+/// Recursively check that every local `$ref` pointing to `#/components/` resolves within the
+/// `document`.
+fn validate_refs(
+    document: &Value,
+    value: &Value,
+    location: &mut String,
+    errors: &mut Vec<ValidationError>,
+) {
+    let children: Box<dyn Iterator<Item = (String, &Value)>> = match value {
+        Value::Object(object) => {
+            if let Some(Value::String(reference)) = object.get("$ref") {
+                if reference.starts_with("#/components/")
+                    && document.pointer(&reference[1..]).is_none()
+                {
+                    errors.push(ValidationError::DanglingRef {
+                        location: format!("{location}/$ref"),
+                        reference: reference.clone(),
+                    });
+                }
+            }
+            Box::new(object.iter().map(|(key, value)| (escape(key), value)))
+        }
+        Value::Array(array) => Box::new(
+            array
+                .iter()
+                .enumerate()
+                .map(|(index, value)| (index.to_string(), value)),
+        ),
+        _ => return,
+    };
+
+    for (token, value) in children {
+        let len = location.len();
+        location.push('/');
+        location.push_str(&token);
+        validate_refs(document, value, location, errors);
+        location.truncate(len);
+    }
+}
+
+/// Check that each `{name}` template parameter of the `path` has a matching
+/// [`ParameterIn::Path`] parameter declared in [`PathItem`] or [`Operation`].
+fn validate_path_parameters(
+    path: &str,
+    item: &PathItem,
+    operation: &Operation,
+    openapi: &OpenApi,
+    location: &str,
+    errors: &mut Vec<ValidationError>,
+) {
+    let path_parameters = item
+        .parameters
+        .iter()
+        .flatten()
+        .chain(operation.parameters.iter().flatten())
+        .filter_map(|parameter| match parameter {
+            RefOr::T(parameter) => Some(parameter),
+            RefOr::Ref(reference) => reference
+                .ref_location
+                .strip_prefix("#/components/parameters/")
+                .and_then(|name| openapi.components.as_ref()?.parameters.get(name))
+                .and_then(|parameter| match parameter {
+                    RefOr::T(parameter) => Some(parameter),
+                    RefOr::Ref(_) => None,
+                }),
+        })
+        .filter(|parameter| parameter.parameter_in == ParameterIn::Path)
+        .map(|parameter| parameter.name.as_str())
+        .collect::<BTreeSet<_>>();
+
+    let template_parameters = path
+        .split('{')
+        .skip(1)
+        .filter_map(|segment| segment.split_once('}').map(|(name, _)| name));
+
+    for parameter in template_parameters {
+        if !path_parameters.contains(parameter) {
+            errors.push(ValidationError::MissingPathParameter {
+                location: location.to_string(),
+                parameter: parameter.to_string(),
+            });
+        }
+    }
+}
+
+fn validate_security_requirement<'a, S>(
+    names: impl Iterator<Item = &'a String>,
+    security_schemes: Option<&BTreeMap<String, S>>,
+    location: &str,
+    errors: &mut Vec<ValidationError>,
+) {
+    for name in names {
+        if !security_schemes.is_some_and(|schemes| schemes.contains_key(name)) {
+            errors.push(ValidationError::UndefinedSecurityScheme {
+                location: location.to_string(),
+                name: name.clone(),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::openapi::path::{OperationBuilder, ParameterBuilder};
+    use crate::openapi::security::{
+        ApiKey, ApiKeyValue, DeviceAuthorization, OAuth2, Scopes, SecurityRequirement,
+    };
+    use crate::openapi::tag::{Tag, TagBuilder};
+    use crate::openapi::{
+        ComponentsBuilder, Content, HttpMethod, Info, ObjectBuilder, OpenApiBuilder, PathItem,
+        PathsBuilder, Ref, ResponseBuilder,
+    };
+
+    use super::*;
+
+    #[test]
+    fn validate_valid_openapi() {
+        let openapi = OpenApiBuilder::new()
+            .info(Info::new("api", "1.0.0"))
+            .paths(
+                PathsBuilder::new().path(
+                    "/pets/{id}",
+                    PathItem::new(
+                        HttpMethod::Get,
+                        OperationBuilder::new()
+                            .operation_id(Some("get_pet"))
+                            .tag("pets")
+                            .parameter(Ref::new("#/components/parameters/PetId"))
+                            .security(SecurityRequirement::new("api_key", [] as [&str; 0]))
+                            .response(
+                                "200",
+                                ResponseBuilder::new().description("Pet").content(
+                                    "application/json",
+                                    Content::new(Some(Ref::from_schema_name("Pet"))),
+                                ),
+                            ),
+                    ),
+                ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema("Pet", ObjectBuilder::new())
+                    .parameter(
+                        "PetId",
+                        ParameterBuilder::new()
+                            .name("id")
+                            .parameter_in(ParameterIn::Path),
+                    )
+                    .security_scheme(
+                        "api_key",
+                        SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("api_key"))),
+                    )
+                    .build(),
+            ))
+            .tags(Some([Tag::new("pets")]))
+            .build();
+
+        assert_eq!(openapi.validate(), Ok(()));
+    }
+
+    #[test]
+    fn validate_invalid_openapi() {
+        let operation = OperationBuilder::new()
+            .operation_id(Some("get_pet"))
+            .tag("pets")
+            .security(SecurityRequirement::new("api_keyy", [] as [&str; 0]))
+            .response(
+                "200",
+                ResponseBuilder::new().description("Pet").content(
+                    "application/json",
+                    Content::new(Some(Ref::from_schema_name("Pet"))),
+                ),
+            )
+            .build();
+        let openapi = OpenApiBuilder::new()
+            .info(Info::new("api", "1.0.0"))
+            .paths(
+                PathsBuilder::new()
+                    .path("/pets", PathItem::new(HttpMethod::Post, operation.clone()))
+                    .path("/pets/{id}", PathItem::new(HttpMethod::Get, operation)),
+            )
+            .build();
+
+        // paths are in alphabetical order and errors are sorted to have same errors with
+        // `preserve_order`
+        let mut errors = openapi
+            .validate()
+            .expect_err("openapi should be invalid")
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        errors.sort();
+
+        assert_eq!(
+            errors,
+            [
+                "#/paths/~1pets/post/responses/200/content/application~1json/schema/$ref: reference `#/components/schemas/Pet` does not exist",
+                "#/paths/~1pets/post/security/0: security scheme `api_keyy` is not defined",
+                "#/paths/~1pets/post: tag `pets` is not defined",
+                "#/paths/~1pets~1{id}/get/responses/200/content/application~1json/schema/$ref: reference `#/components/schemas/Pet` does not exist",
+                "#/paths/~1pets~1{id}/get/security/0: security scheme `api_keyy` is not defined",
+                "#/paths/~1pets~1{id}/get: operationId `get_pet` is already used in #/paths/~1pets/post",
+                "#/paths/~1pets~1{id}/get: path parameter `id` is not defined for the operation",
+                "#/paths/~1pets~1{id}/get: tag `pets` is not defined",
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_openapi_32_fields() {
+        let operation = OperationBuilder::new().response("200", ResponseBuilder::new().build());
+        let openapi = OpenApiBuilder::new()
+            .info(Info::new("api", "1.0.0"))
+            .paths(PathsBuilder::new().path(
+                "/pets",
+                PathItem::from_http_methods(
+                    [
+                        HttpMethod::Get,
+                        HttpMethod::Query,
+                        HttpMethod::Custom("COPY".to_string()),
+                    ],
+                    operation,
+                ),
+            ))
+            .components(Some(
+                ComponentsBuilder::new()
+                    .security_scheme(
+                        "oauth2",
+                        SecurityScheme::OAuth2(OAuth2::new([Flow::DeviceAuthorization(
+                            DeviceAuthorization::new(
+                                "https://localhost/device",
+                                "https://localhost/token",
+                                Scopes::new(),
+                            ),
+                        )])),
+                    )
+                    .build(),
+            ))
+            .tags(Some([
+                Tag::new("pets"),
+                TagBuilder::new()
+                    .name("dogs")
+                    .parent(Some("pets"))
+                    .kind(Some("nav"))
+                    .build(),
+            ]))
+            .build();
+
+        let errors = openapi
+            .validate()
+            .expect_err("openapi 3.1 should not have 3.2 fields")
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            errors,
+            [
+                "#/tags/1/parent: field `parent` is not supported before OpenAPI 3.2",
+                "#/tags/1/kind: field `kind` is not supported before OpenAPI 3.2",
+                "#/components/securitySchemes/oauth2/flows/deviceAuthorization: field `deviceAuthorization` is not supported before OpenAPI 3.2",
+                "#/paths/~1pets/query: field `query` is not supported before OpenAPI 3.2",
+                "#/paths/~1pets/additionalOperations/COPY: field `additionalOperations` is not supported before OpenAPI 3.2",
+            ]
+        );
+
+        let openapi = OpenApi {
+            openapi: OpenApiVersion::Version32,
+            ..openapi
+        };
+        assert_eq!(openapi.validate(), Ok(()));
+    }
+}
@@ -0,0 +1,1133 @@
+//! Implements [`Visit`] and [`VisitMut`] traits to traverse all nodes of the [`OpenApi`] document.
+//!
+//! The traits have a hook method for every kind of node of the document such as
+//! [`Operation`], [`Response`], [`Content`] and [`Schema`]. The traversal reaches every node of
+//! the document including the schemas nested within [`Object::properties`], [`Array::items`] and
+//! the items of composite schemas. [`Ref`]s are visited with [`Visit::visit_ref`] and they are
+//! not resolved.
+//!
+//! # Examples
+//!
+//! _**Add `x-internal` extension to every object schema of the document.**_
+//! ```rust
+//! # use utoipa::openapi::{Object, OpenApi};
+//! # use utoipa::openapi::extensions::Extensions;
+//! use utoipa::openapi::visit::{self, VisitMut};
+//!
+//! struct InternalSchemas;
+//!
+//! impl VisitMut for InternalSchemas {
+//!     fn visit_object_mut(&mut self, object: &mut Object) {
+//!         object
+//!             .extensions
+//!             .get_or_insert_with(Extensions::default)
+//!             .insert("x-internal".to_string(), true.into());
+//!
+//!         // continue to the nested schemas
+//!         visit::walk_object_mut(self, object);
+//!     }
+//! }
+//!
+//! # fn modify(openapi: &mut OpenApi) {
+//! InternalSchemas.visit_openapi_mut(openapi);
+//! # }
+//! ```
+//!
+//! _**Collect all references used in the document.**_
+//! ```rust
+//! # use utoipa::openapi::{OpenApi, Ref};
+//! use utoipa::openapi::visit::Visit;
+//!
+//! #[derive(Default)]
+//! struct References<'a>(Vec<&'a str>);
+//!
+//! impl<'a> Visit<'a> for References<'a> {
+//!     fn visit_ref(&mut self, reference: &'a Ref) {
+//!         self.0.push(&reference.ref_location);
+//!     }
+//! }
+//!
+//! # fn references(openapi: &OpenApi) -> Vec<&str> {
+//! let mut references = References::default();
+//! references.visit_openapi(openapi);
+//! references.0
+//! # }
+//! ```
+use super::content::Content;
+use super::encoding::Encoding;
+use super::example::Example;
+use super::header::Header;
+use super::link::Link;
+use super::path::{Callback, Operation, Parameter, PathItem, Paths};
+use super::request_body::RequestBody;
+use super::response::{Response, Responses};
+use super::schema::{
+    AdditionalProperties, AllOf, AnyOf, Array, ArrayItems, Components, Not, Object, OneOf, Schema,
+};
+use super::security::{SecurityRequirement, SecurityScheme};
+use super::tag::Tag;
+use super::{ExternalDocs, Info, OpenApi, Ref, RefOr, Server};
+
+/// Visit either the [`Ref`] or the node of [`RefOr`] with given visitor methods.
+macro_rules! ref_or {
+    ($visitor:ident, $ref_or:expr, $visit_ref:ident, $visit:ident) => {
+        match $ref_or {
+            RefOr::Ref(reference) => $visitor.$visit_ref(reference),
+            RefOr::T(item) => $visitor.$visit(item),
+        }
+    };
+}
+
+/// Traverse the [`OpenApi`] document by shared reference.
+///
+/// Each method of the trait is a hook for a single kind of node of the document. By default
+/// the hooks call the matching `walk_*` function of this module which visits the nested nodes
+/// of the node. Override the hooks of interest and call the `walk_*` function within the
+/// override to continue the traversal to the nested nodes.
+///
+/// The lifetime `'a` allows the visitor to hold on to the visited nodes.
+///
+/// See [`VisitMut`] for traversing the document by mutable reference.
+pub trait Visit<'a> {
+    /// Visit [`OpenApi`] document.
+    fn visit_openapi(&mut self, openapi: &'a OpenApi) {
+        walk_openapi(self, openapi)
+    }
+
+    /// Visit [`Info`] of the document.
+    fn visit_info(&mut self, info: &'a Info) {
+        let _ = info;
+    }
+
+    /// Visit [`Server`].
+    fn visit_server(&mut self, server: &'a Server) {
+        let _ = server;
+    }
+
+    /// Visit [`Paths`] of the document.
+    fn visit_paths(&mut self, paths: &'a Paths) {
+        walk_paths(self, paths)
+    }
+
+    /// Visit [`PathItem`] of a path, webhook, callback or components.
+    fn visit_path_item(&mut self, path_item: &'a PathItem) {
+        walk_path_item(self, path_item)
+    }
+
+    /// Visit [`Operation`] of a [`PathItem`].
+    fn visit_operation(&mut self, operation: &'a Operation) {
+        walk_operation(self, operation)
+    }
+
+    /// Visit [`Parameter`] of a [`PathItem`], an [`Operation`] or components.
+    fn visit_parameter(&mut self, parameter: &'a Parameter) {
+        walk_parameter(self, parameter)
+    }
+
+    /// Visit [`RequestBody`] of an [`Operation`] or components.
+    fn visit_request_body(&mut self, request_body: &'a RequestBody) {
+        walk_request_body(self, request_body)
+    }
+
+    /// Visit [`Responses`] of an [`Operation`].
+    fn visit_responses(&mut self, responses: &'a Responses) {
+        walk_responses(self, responses)
+    }
+
+    /// Visit [`Response`] of an [`Operation`] or components.
+    fn visit_response(&mut self, response: &'a Response) {
+        walk_response(self, response)
+    }
+
+    /// Visit [`Header`] of a [`Response`], an [`Encoding`] or components.
+    fn visit_header(&mut self, header: &'a Header) {
+        walk_header(self, header)
+    }
+
+    /// Visit [`Content`] of a media type.
+    fn visit_content(&mut self, content: &'a Content) {
+        walk_content(self, content)
+    }
+
+    /// Visit [`Encoding`] of a [`Content`] property.
+    fn visit_encoding(&mut self, encoding: &'a Encoding) {
+        walk_encoding(self, encoding)
+    }
+
+    /// Visit [`Example`].
+    fn visit_example(&mut self, example: &'a Example) {
+        let _ = example;
+    }
+
+    /// Visit [`Link`] of a [`Response`] or components.
+    fn visit_link(&mut self, link: &'a Link) {
+        walk_link(self, link)
+    }
+
+    /// Visit [`Callback`] of an [`Operation`] or components.
+    fn visit_callback(&mut self, callback: &'a Callback) {
+        walk_callback(self, callback)
+    }
+
+    /// Visit [`Components`] of the document.
+    fn visit_components(&mut self, components: &'a Components) {
+        walk_components(self, components)
+    }
+
+    /// Visit [`SecurityScheme`] of components.
+    fn visit_security_scheme(&mut self, security_scheme: &'a SecurityScheme) {
+        let _ = security_scheme;
+    }
+
+    /// Visit [`SecurityRequirement`] of the document or an [`Operation`].
+    fn visit_security_requirement(&mut self, security_requirement: &'a SecurityRequirement) {
+        let _ = security_requirement;
+    }
+
+    /// Visit [`Tag`] of the document.
+    fn visit_tag(&mut self, tag: &'a Tag) {
+        walk_tag(self, tag)
+    }
+
+    /// Visit [`ExternalDocs`] of the document, a [`Tag`] or an [`Operation`].
+    fn visit_external_docs(&mut self, external_docs: &'a ExternalDocs) {
+        let _ = external_docs;
+    }
+
+    /// Visit [`Ref`] in place of any referenceable node.
+    fn visit_ref(&mut self, reference: &'a Ref) {
+        let _ = reference;
+    }
+
+    /// Visit any [`Schema`] including the schemas nested in other schemas.
+    fn visit_schema(&mut self, schema: &'a Schema) {
+        walk_schema(self, schema)
+    }
+
+    /// Visit [`Object`] schema.
+    fn visit_object(&mut self, object: &'a Object) {
+        walk_object(self, object)
+    }
+
+    /// Visit [`Array`] schema.
+    fn visit_array(&mut self, array: &'a Array) {
+        walk_array(self, array)
+    }
+
+    /// Visit [`OneOf`] schema.
+    fn visit_one_of(&mut self, one_of: &'a OneOf) {
+        walk_one_of(self, one_of)
+    }
+
+    /// Visit [`AllOf`] schema.
+    fn visit_all_of(&mut self, all_of: &'a AllOf) {
+        walk_all_of(self, all_of)
+    }
+
+    /// Visit [`AnyOf`] schema.
+    fn visit_any_of(&mut self, any_of: &'a AnyOf) {
+        walk_any_of(self, any_of)
+    }
+
+    /// Visit [`Not`] schema.
+    fn visit_not(&mut self, not: &'a Not) {
+        walk_not(self, not)
+    }
+}
+
+/// Visit the nested nodes of [`OpenApi`].
+pub fn walk_openapi<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, openapi: &'a OpenApi) {
+    visitor.visit_info(&openapi.info);
+    for server in openapi.servers.iter().flatten() {
+        visitor.visit_server(server);
+    }
+    visitor.visit_paths(&openapi.paths);
+    for webhook in openapi.webhooks.values() {
+        ref_or!(visitor, webhook, visit_ref, visit_path_item);
+    }
+    if let Some(components) = openapi.components.as_ref() {
+        visitor.visit_components(components);
+    }
+    for security_requirement in openapi.security.iter().flatten() {
+        visitor.visit_security_requirement(security_requirement);
+    }
+    for tag in openapi.tags.iter().flatten() {
+        visitor.visit_tag(tag);
+    }
+    if let Some(external_docs) = openapi.external_docs.as_ref() {
+        visitor.visit_external_docs(external_docs);
+    }
+}
+
+/// Visit the [`PathItem`]s of [`Paths`].
+pub fn walk_paths<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, paths: &'a Paths) {
+    for path_item in paths.paths.values() {
+        visitor.visit_path_item(path_item);
+    }
+}
+
+/// Visit the nested nodes of [`PathItem`].
+pub fn walk_path_item<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, path_item: &'a PathItem) {
+    for server in path_item.servers.iter().flatten() {
+        visitor.visit_server(server);
+    }
+    for parameter in path_item.parameters.iter().flatten() {
+        ref_or!(visitor, parameter, visit_ref, visit_parameter);
+    }
+    for (_, operation) in path_item.operations() {
+        visitor.visit_operation(operation);
+    }
+}
+
+/// Visit the nested nodes of [`Operation`].
+pub fn walk_operation<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, operation: &'a Operation) {
+    if let Some(external_docs) = operation.external_docs.as_ref() {
+        visitor.visit_external_docs(external_docs);
+    }
+    for parameter in operation.parameters.iter().flatten() {
+        ref_or!(visitor, parameter, visit_ref, visit_parameter);
+    }
+    if let Some(request_body) = operation.request_body.as_ref() {
+        ref_or!(visitor, request_body, visit_ref, visit_request_body);
+    }
+    visitor.visit_responses(&operation.responses);
+    for callback in operation
+        .callbacks
+        .iter()
+        .flat_map(|callbacks| callbacks.values())
+    {
+        ref_or!(visitor, callback, visit_ref, visit_callback);
+    }
+    for security_requirement in operation.security.iter().flatten() {
+        visitor.visit_security_requirement(security_requirement);
+    }
+    for server in operation.servers.iter().flatten() {
+        visitor.visit_server(server);
+    }
+}
+
+/// Visit the [`Schema`] of [`Parameter`].
+pub fn walk_parameter<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, parameter: &'a Parameter) {
+    if let Some(schema) = parameter.schema.as_ref() {
+        ref_or!(visitor, schema, visit_ref, visit_schema);
+    }
+}
+
+/// Visit the [`Content`]s of [`RequestBody`].
+pub fn walk_request_body<'a, V: Visit<'a> + ?Sized>(
+    visitor: &mut V,
+    request_body: &'a RequestBody,
+) {
+    for content in request_body.content.values() {
+        visitor.visit_content(content);
+    }
+}
+
+/// Visit the [`Response`]s of [`Responses`].
+pub fn walk_responses<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, responses: &'a Responses) {
+    for response in responses.responses.values() {
+        ref_or!(visitor, response, visit_ref, visit_response);
+    }
+}
+
+/// Visit the [`Header`]s, [`Content`]s and [`Link`]s of [`Response`].
+pub fn walk_response<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, response: &'a Response) {
+    for header in response.headers.values() {
+        ref_or!(visitor, header, visit_ref, visit_header);
+    }
+    for content in response.content.values() {
+        visitor.visit_content(content);
+    }
+    for link in response.links.values() {
+        ref_or!(visitor, link, visit_ref, visit_link);
+    }
+}
+
+/// Visit the [`Schema`], [`Example`]s and [`Content`]s of [`Header`].
+pub fn walk_header<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, header: &'a Header) {
+    if let Some(schema) = header.schema.as_ref() {
+        ref_or!(visitor, schema, visit_ref, visit_schema);
+    }
+    for example in header.examples.values() {
+        ref_or!(visitor, example, visit_ref, visit_example);
+    }
+    for content in header.content.values() {
+        visitor.visit_content(content);
+    }
+}
+
+/// Visit the [`Schema`], [`Example`]s and [`Encoding`]s of [`Content`].
+pub fn walk_content<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, content: &'a Content) {
+    if let Some(schema) = content.schema.as_ref() {
+        ref_or!(visitor, schema, visit_ref, visit_schema);
+    }
+    for example in content.examples.values() {
+        ref_or!(visitor, example, visit_ref, visit_example);
+    }
+    for encoding in content.encoding.values() {
+        visitor.visit_encoding(encoding);
+    }
+}
+
+/// Visit the [`Header`]s of [`Encoding`].
+pub fn walk_encoding<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, encoding: &'a Encoding) {
+    for header in encoding.headers.values() {
+        visitor.visit_header(header);
+    }
+}
+
+/// Visit the [`Server`] of [`Link`].
+pub fn walk_link<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, link: &'a Link) {
+    if let Some(server) = link.server.as_ref() {
+        visitor.visit_server(server);
+    }
+}
+
+/// Visit the [`PathItem`]s of [`Callback`].
+pub fn walk_callback<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, callback: &'a Callback) {
+    for path_item in callback.paths.values() {
+        visitor.visit_path_item(path_item);
+    }
+}
+
+/// Visit all nested nodes of [`Components`].
+pub fn walk_components<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, components: &'a Components) {
+    for schema in components.schemas.values() {
+        ref_or!(visitor, schema, visit_ref, visit_schema);
+    }
+    for response in components.responses.values() {
+        ref_or!(visitor, response, visit_ref, visit_response);
+    }
+    for parameter in components.parameters.values() {
+        ref_or!(visitor, parameter, visit_ref, visit_parameter);
+    }
+    for example in components.examples.values() {
+        ref_or!(visitor, example, visit_ref, visit_example);
+    }
+    for request_body in components.request_bodies.values() {
+        ref_or!(visitor, request_body, visit_ref, visit_request_body);
+    }
+    for header in components.headers.values() {
+        ref_or!(visitor, header, visit_ref, visit_header);
+    }
+    for security_scheme in components.security_schemes.values() {
+        visitor.visit_security_scheme(security_scheme);
+    }
+    for link in components.links.values() {
+        ref_or!(visitor, link, visit_ref, visit_link);
+    }
+    for callback in components.callbacks.values() {
+        ref_or!(visitor, callback, visit_ref, visit_callback);
+    }
+    for path_item in components.path_items.values() {
+        ref_or!(visitor, path_item, visit_ref, visit_path_item);
+    }
+}
+
+/// Visit the [`ExternalDocs`] of [`Tag`].
+pub fn walk_tag<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, tag: &'a Tag) {
+    if let Some(external_docs) = tag.external_docs.as_ref() {
+        visitor.visit_external_docs(external_docs);
+    }
+}
+
+/// Visit the variant of the [`Schema`].
+pub fn walk_schema<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, schema: &'a Schema) {
+    match schema {
+        Schema::Object(object) => visitor.visit_object(object),
+        Schema::Array(array) => visitor.visit_array(array),
+        Schema::OneOf(one_of) => visitor.visit_one_of(one_of),
+        Schema::AllOf(all_of) => visitor.visit_all_of(all_of),
+        Schema::AnyOf(any_of) => visitor.visit_any_of(any_of),
+        Schema::Not(not) => visitor.visit_not(not),
+        Schema::Bool(_) => (),
+    }
+}
+
+/// Visit the nested schemas of [`Object`].
+pub fn walk_object<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, object: &'a Object) {
+    for property in object.properties.values() {
+        ref_or!(visitor, property, visit_ref, visit_schema);
+    }
+    if let Some(AdditionalProperties::RefOr(schema)) = object.additional_properties.as_deref() {
+        ref_or!(visitor, schema, visit_ref, visit_schema);
+    }
+    if let Some(property_names) = object.property_names.as_deref() {
+        visitor.visit_schema(property_names);
+    }
+    for property in object.pattern_properties.values() {
+        ref_or!(visitor, property, visit_ref, visit_schema);
+    }
+    if let Some(AdditionalProperties::RefOr(schema)) = object.unevaluated_properties.as_deref() {
+        ref_or!(visitor, schema, visit_ref, visit_schema);
+    }
+    for schema in [
+        &object.if_schema,
+        &object.then_schema,
+        &object.else_schema,
+        &object.not_schema,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        ref_or!(visitor, schema.as_ref(), visit_ref, visit_schema);
+    }
+    for schema in object.dependent_schemas.values() {
+        ref_or!(visitor, schema, visit_ref, visit_schema);
+    }
+    for schema in object.defs.values() {
+        ref_or!(visitor, schema, visit_ref, visit_schema);
+    }
+}
+
+/// Visit the nested schemas of [`Array`].
+pub fn walk_array<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, array: &'a Array) {
+    if let ArrayItems::RefOrSchema(items) = &array.items {
+        ref_or!(visitor, items.as_ref(), visit_ref, visit_schema);
+    }
+    for schema in array.prefix_items.iter() {
+        visitor.visit_schema(schema);
+    }
+    if let Some(contains) = array.contains.as_deref() {
+        ref_or!(visitor, contains, visit_ref, visit_schema);
+    }
+    if let Some(ArrayItems::RefOrSchema(items)) = array.unevaluated_items.as_deref() {
+        ref_or!(visitor, items.as_ref(), visit_ref, visit_schema);
+    }
+    for schema in array.defs.values() {
+        ref_or!(visitor, schema, visit_ref, visit_schema);
+    }
+}
+
+/// Visit the nested schemas of [`OneOf`].
+pub fn walk_one_of<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, one_of: &'a OneOf) {
+    for item in one_of.items.iter() {
+        ref_or!(visitor, item, visit_ref, visit_schema);
+    }
+    for schema in one_of.defs.values() {
+        ref_or!(visitor, schema, visit_ref, visit_schema);
+    }
+}
+
+/// Visit the nested schemas of [`AllOf`].
+pub fn walk_all_of<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, all_of: &'a AllOf) {
+    for item in all_of.items.iter() {
+        ref_or!(visitor, item, visit_ref, visit_schema);
+    }
+    if let Some(AdditionalProperties::RefOr(schema)) = all_of.unevaluated_properties.as_deref() {
+        ref_or!(visitor, schema, visit_ref, visit_schema);
+    }
+    for schema in all_of.defs.values() {
+        ref_or!(visitor, schema, visit_ref, visit_schema);
+    }
+}
+
+/// Visit the nested schemas of [`AnyOf`].
+pub fn walk_any_of<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, any_of: &'a AnyOf) {
+    for item in any_of.items.iter() {
+        ref_or!(visitor, item, visit_ref, visit_schema);
+    }
+    for schema in any_of.defs.values() {
+        ref_or!(visitor, schema, visit_ref, visit_schema);
+    }
+}
+
+/// Visit the nested schemas of [`Not`].
+pub fn walk_not<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, not: &'a Not) {
+    ref_or!(visitor, not.item.as_ref(), visit_ref, visit_schema);
+    for schema in not.defs.values() {
+        ref_or!(visitor, schema, visit_ref, visit_schema);
+    }
+}
+
+/// Traverse the [`OpenApi`] document by mutable reference.
+///
+/// Works the same way as [`Visit`] but the hooks receive mutable references to the nodes
+/// allowing the visitor to modify the document in place. By default the hooks call the matching
+/// `walk_*_mut` function of this module which visits the nested nodes of the node.
+pub trait VisitMut {
+    /// Visit [`OpenApi`] document.
+    fn visit_openapi_mut(&mut self, openapi: &mut OpenApi) {
+        walk_openapi_mut(self, openapi)
+    }
+
+    /// Visit [`Info`] of the document.
+    fn visit_info_mut(&mut self, info: &mut Info) {
+        let _ = info;
+    }
+
+    /// Visit [`Server`].
+    fn visit_server_mut(&mut self, server: &mut Server) {
+        let _ = server;
+    }
+
+    /// Visit [`Paths`] of the document.
+    fn visit_paths_mut(&mut self, paths: &mut Paths) {
+        walk_paths_mut(self, paths)
+    }
+
+    /// Visit [`PathItem`] of a path, webhook, callback or components.
+    fn visit_path_item_mut(&mut self, path_item: &mut PathItem) {
+        walk_path_item_mut(self, path_item)
+    }
+
+    /// Visit [`Operation`] of a [`PathItem`].
+    fn visit_operation_mut(&mut self, operation: &mut Operation) {
+        walk_operation_mut(self, operation)
+    }
+
+    /// Visit [`Parameter`] of a [`PathItem`], an [`Operation`] or components.
+    fn visit_parameter_mut(&mut self, parameter: &mut Parameter) {
+        walk_parameter_mut(self, parameter)
+    }
+
+    /// Visit [`RequestBody`] of an [`Operation`] or components.
+    fn visit_request_body_mut(&mut self, request_body: &mut RequestBody) {
+        walk_request_body_mut(self, request_body)
+    }
+
+    /// Visit [`Responses`] of an [`Operation`].
+    fn visit_responses_mut(&mut self, responses: &mut Responses) {
+        walk_responses_mut(self, responses)
+    }
+
+    /// Visit [`Response`] of an [`Operation`] or components.
+    fn visit_response_mut(&mut self, response: &mut Response) {
+        walk_response_mut(self, response)
+    }
+
+    /// Visit [`Header`] of a [`Response`], an [`Encoding`] or components.
+    fn visit_header_mut(&mut self, header: &mut Header) {
+        walk_header_mut(self, header)
+    }
+
+    /// Visit [`Content`] of a media type.
+    fn visit_content_mut(&mut self, content: &mut Content) {
+        walk_content_mut(self, content)
+    }
+
+    /// Visit [`Encoding`] of a [`Content`] property.
+    fn visit_encoding_mut(&mut self, encoding: &mut Encoding) {
+        walk_encoding_mut(self, encoding)
+    }
+
+    /// Visit [`Example`].
+    fn visit_example_mut(&mut self, example: &mut Example) {
+        let _ = example;
+    }
+
+    /// Visit [`Link`] of a [`Response`] or components.
+    fn visit_link_mut(&mut self, link: &mut Link) {
+        walk_link_mut(self, link)
+    }
+
+    /// Visit [`Callback`] of an [`Operation`] or components.
+    fn visit_callback_mut(&mut self, callback: &mut Callback) {
+        walk_callback_mut(self, callback)
+    }
+
+    /// Visit [`Components`] of the document.
+    fn visit_components_mut(&mut self, components: &mut Components) {
+        walk_components_mut(self, components)
+    }
+
+    /// Visit [`SecurityScheme`] of components.
+    fn visit_security_scheme_mut(&mut self, security_scheme: &mut SecurityScheme) {
+        let _ = security_scheme;
+    }
+
+    /// Visit [`SecurityRequirement`] of the document or an [`Operation`].
+    fn visit_security_requirement_mut(&mut self, security_requirement: &mut SecurityRequirement) {
+        let _ = security_requirement;
+    }
+
+    /// Visit [`Tag`] of the document.
+    fn visit_tag_mut(&mut self, tag: &mut Tag) {
+        walk_tag_mut(self, tag)
+    }
+
+    /// Visit [`ExternalDocs`] of the document, a [`Tag`] or an [`Operation`].
+    fn visit_external_docs_mut(&mut self, external_docs: &mut ExternalDocs) {
+        let _ = external_docs;
+    }
+
+    /// Visit [`Ref`] in place of any referenceable node.
+    fn visit_ref_mut(&mut self, reference: &mut Ref) {
+        let _ = reference;
+    }
+
+    /// Visit any [`Schema`] including the schemas nested in other schemas.
+    fn visit_schema_mut(&mut self, schema: &mut Schema) {
+        walk_schema_mut(self, schema)
+    }
+
+    /// Visit [`Object`] schema.
+    fn visit_object_mut(&mut self, object: &mut Object) {
+        walk_object_mut(self, object)
+    }
+
+    /// Visit [`Array`] schema.
+    fn visit_array_mut(&mut self, array: &mut Array) {
+        walk_array_mut(self, array)
+    }
+
+    /// Visit [`OneOf`] schema.
+    fn visit_one_of_mut(&mut self, one_of: &mut OneOf) {
+        walk_one_of_mut(self, one_of)
+    }
+
+    /// Visit [`AllOf`] schema.
+    fn visit_all_of_mut(&mut self, all_of: &mut AllOf) {
+        walk_all_of_mut(self, all_of)
+    }
+
+    /// Visit [`AnyOf`] schema.
+    fn visit_any_of_mut(&mut self, any_of: &mut AnyOf) {
+        walk_any_of_mut(self, any_of)
+    }
+
+    /// Visit [`Not`] schema.
+    fn visit_not_mut(&mut self, not: &mut Not) {
+        walk_not_mut(self, not)
+    }
+}
+
+/// Visit the nested nodes of [`OpenApi`].
+pub fn walk_openapi_mut<V: VisitMut + ?Sized>(visitor: &mut V, openapi: &mut OpenApi) {
+    visitor.visit_info_mut(&mut openapi.info);
+    for server in openapi.servers.iter_mut().flatten() {
+        visitor.visit_server_mut(server);
+    }
+    visitor.visit_paths_mut(&mut openapi.paths);
+    for webhook in openapi.webhooks.values_mut() {
+        ref_or!(visitor, webhook, visit_ref_mut, visit_path_item_mut);
+    }
+    if let Some(components) = openapi.components.as_mut() {
+        visitor.visit_components_mut(components);
+    }
+    for security_requirement in openapi.security.iter_mut().flatten() {
+        visitor.visit_security_requirement_mut(security_requirement);
+    }
+    for tag in openapi.tags.iter_mut().flatten() {
+        visitor.visit_tag_mut(tag);
+    }
+    if let Some(external_docs) = openapi.external_docs.as_mut() {
+        visitor.visit_external_docs_mut(external_docs);
+    }
+}
+
+/// Visit the [`PathItem`]s of [`Paths`].
+pub fn walk_paths_mut<V: VisitMut + ?Sized>(visitor: &mut V, paths: &mut Paths) {
+    for path_item in paths.paths.values_mut() {
+        visitor.visit_path_item_mut(path_item);
+    }
+}
+
+/// Visit the nested nodes of [`PathItem`].
+pub fn walk_path_item_mut<V: VisitMut + ?Sized>(visitor: &mut V, path_item: &mut PathItem) {
+    for server in path_item.servers.iter_mut().flatten() {
+        visitor.visit_server_mut(server);
+    }
+    for parameter in path_item.parameters.iter_mut().flatten() {
+        ref_or!(visitor, parameter, visit_ref_mut, visit_parameter_mut);
+    }
+    for operation in path_item.operations_mut() {
+        visitor.visit_operation_mut(operation);
+    }
+}
+
+/// Visit the nested nodes of [`Operation`].
+pub fn walk_operation_mut<V: VisitMut + ?Sized>(visitor: &mut V, operation: &mut Operation) {
+    if let Some(external_docs) = operation.external_docs.as_mut() {
+        visitor.visit_external_docs_mut(external_docs);
+    }
+    for parameter in operation.parameters.iter_mut().flatten() {
+        ref_or!(visitor, parameter, visit_ref_mut, visit_parameter_mut);
+    }
+    if let Some(request_body) = operation.request_body.as_mut() {
+        ref_or!(visitor, request_body, visit_ref_mut, visit_request_body_mut);
+    }
+    visitor.visit_responses_mut(&mut operation.responses);
+    for callback in operation
+        .callbacks
+        .iter_mut()
+        .flat_map(|callbacks| callbacks.values_mut())
+    {
+        ref_or!(visitor, callback, visit_ref_mut, visit_callback_mut);
+    }
+    for security_requirement in operation.security.iter_mut().flatten() {
+        visitor.visit_security_requirement_mut(security_requirement);
+    }
+    for server in operation.servers.iter_mut().flatten() {
+        visitor.visit_server_mut(server);
+    }
+}
+
+/// Visit the [`Schema`] of [`Parameter`].
+pub fn walk_parameter_mut<V: VisitMut + ?Sized>(visitor: &mut V, parameter: &mut Parameter) {
+    if let Some(schema) = parameter.schema.as_mut() {
+        ref_or!(visitor, schema, visit_ref_mut, visit_schema_mut);
+    }
+}
+
+/// Visit the [`Content`]s of [`RequestBody`].
+pub fn walk_request_body_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    request_body: &mut RequestBody,
+) {
+    for content in request_body.content.values_mut() {
+        visitor.visit_content_mut(content);
+    }
+}
+
+/// Visit the [`Response`]s of [`Responses`].
+pub fn walk_responses_mut<V: VisitMut + ?Sized>(visitor: &mut V, responses: &mut Responses) {
+    for response in responses.responses.values_mut() {
+        ref_or!(visitor, response, visit_ref_mut, visit_response_mut);
+    }
+}
+
+/// Visit the [`Header`]s, [`Content`]s and [`Link`]s of [`Response`].
+pub fn walk_response_mut<V: VisitMut + ?Sized>(visitor: &mut V, response: &mut Response) {
+    for header in response.headers.values_mut() {
+        ref_or!(visitor, header, visit_ref_mut, visit_header_mut);
+    }
+    for content in response.content.values_mut() {
+        visitor.visit_content_mut(content);
+    }
+    for link in response.links.values_mut() {
+        ref_or!(visitor, link, visit_ref_mut, visit_link_mut);
+    }
+}
+
+/// Visit the [`Schema`], [`Example`]s and [`Content`]s of [`Header`].
+pub fn walk_header_mut<V: VisitMut + ?Sized>(visitor: &mut V, header: &mut Header) {
+    if let Some(schema) = header.schema.as_mut() {
+        ref_or!(visitor, schema, visit_ref_mut, visit_schema_mut);
+    }
+    for example in header.examples.values_mut() {
+        ref_or!(visitor, example, visit_ref_mut, visit_example_mut);
+    }
+    for content in header.content.values_mut() {
+        visitor.visit_content_mut(content);
+    }
+}
+
+/// Visit the [`Schema`], [`Example`]s and [`Encoding`]s of [`Content`].
+pub fn walk_content_mut<V: VisitMut + ?Sized>(visitor: &mut V, content: &mut Content) {
+    if let Some(schema) = content.schema.as_mut() {
+        ref_or!(visitor, schema, visit_ref_mut, visit_schema_mut);
+    }
+    for example in content.examples.values_mut() {
+        ref_or!(visitor, example, visit_ref_mut, visit_example_mut);
+    }
+    for encoding in content.encoding.values_mut() {
+        visitor.visit_encoding_mut(encoding);
+    }
+}
+
+/// Visit the [`Header`]s of [`Encoding`].
+pub fn walk_encoding_mut<V: VisitMut + ?Sized>(visitor: &mut V, encoding: &mut Encoding) {
+    for header in encoding.headers.values_mut() {
+        visitor.visit_header_mut(header);
+    }
+}
+
+/// Visit the [`Server`] of [`Link`].
+pub fn walk_link_mut<V: VisitMut + ?Sized>(visitor: &mut V, link: &mut Link) {
+    if let Some(server) = link.server.as_mut() {
+        visitor.visit_server_mut(server);
+    }
+}
+
+/// Visit the [`PathItem`]s of [`Callback`].
+pub fn walk_callback_mut<V: VisitMut + ?Sized>(visitor: &mut V, callback: &mut Callback) {
+    for path_item in callback.paths.values_mut() {
+        visitor.visit_path_item_mut(path_item);
+    }
+}
+
+/// Visit all nested nodes of [`Components`].
+pub fn walk_components_mut<V: VisitMut + ?Sized>(visitor: &mut V, components: &mut Components) {
+    for schema in components.schemas.values_mut() {
+        ref_or!(visitor, schema, visit_ref_mut, visit_schema_mut);
+    }
+    for response in components.responses.values_mut() {
+        ref_or!(visitor, response, visit_ref_mut, visit_response_mut);
+    }
+    for parameter in components.parameters.values_mut() {
+        ref_or!(visitor, parameter, visit_ref_mut, visit_parameter_mut);
+    }
+    for example in components.examples.values_mut() {
+        ref_or!(visitor, example, visit_ref_mut, visit_example_mut);
+    }
+    for request_body in components.request_bodies.values_mut() {
+        ref_or!(visitor, request_body, visit_ref_mut, visit_request_body_mut);
+    }
+    for header in components.headers.values_mut() {
+        ref_or!(visitor, header, visit_ref_mut, visit_header_mut);
+    }
+    for security_scheme in components.security_schemes.values_mut() {
+        visitor.visit_security_scheme_mut(security_scheme);
+    }
+    for link in components.links.values_mut() {
+        ref_or!(visitor, link, visit_ref_mut, visit_link_mut);
+    }
+    for callback in components.callbacks.values_mut() {
+        ref_or!(visitor, callback, visit_ref_mut, visit_callback_mut);
+    }
+    for path_item in components.path_items.values_mut() {
+        ref_or!(visitor, path_item, visit_ref_mut, visit_path_item_mut);
+    }
+}
+
+/// Visit the [`ExternalDocs`] of [`Tag`].
+pub fn walk_tag_mut<V: VisitMut + ?Sized>(visitor: &mut V, tag: &mut Tag) {
+    if let Some(external_docs) = tag.external_docs.as_mut() {
+        visitor.visit_external_docs_mut(external_docs);
+    }
+}
+
+/// Visit the variant of the [`Schema`].
+pub fn walk_schema_mut<V: VisitMut + ?Sized>(visitor: &mut V, schema: &mut Schema) {
+    match schema {
+        Schema::Object(object) => visitor.visit_object_mut(object),
+        Schema::Array(array) => visitor.visit_array_mut(array),
+        Schema::OneOf(one_of) => visitor.visit_one_of_mut(one_of),
+        Schema::AllOf(all_of) => visitor.visit_all_of_mut(all_of),
+        Schema::AnyOf(any_of) => visitor.visit_any_of_mut(any_of),
+        Schema::Not(not) => visitor.visit_not_mut(not),
+        Schema::Bool(_) => (),
+    }
+}
+
+/// Visit the nested schemas of [`Object`].
+pub fn walk_object_mut<V: VisitMut + ?Sized>(visitor: &mut V, object: &mut Object) {
+    for property in object.properties.values_mut() {
+        ref_or!(visitor, property, visit_ref_mut, visit_schema_mut);
+    }
+    if let Some(AdditionalProperties::RefOr(schema)) = object.additional_properties.as_deref_mut() {
+        ref_or!(visitor, schema, visit_ref_mut, visit_schema_mut);
+    }
+    if let Some(property_names) = object.property_names.as_deref_mut() {
+        visitor.visit_schema_mut(property_names);
+    }
+    for property in object.pattern_properties.values_mut() {
+        ref_or!(visitor, property, visit_ref_mut, visit_schema_mut);
+    }
+    if let Some(AdditionalProperties::RefOr(schema)) = object.unevaluated_properties.as_deref_mut()
+    {
+        ref_or!(visitor, schema, visit_ref_mut, visit_schema_mut);
+    }
+    for schema in [
+        &mut object.if_schema,
+        &mut object.then_schema,
+        &mut object.else_schema,
+        &mut object.not_schema,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        ref_or!(visitor, schema.as_mut(), visit_ref_mut, visit_schema_mut);
+    }
+    for schema in object.dependent_schemas.values_mut() {
+        ref_or!(visitor, schema, visit_ref_mut, visit_schema_mut);
+    }
+    for schema in object.defs.values_mut() {
+        ref_or!(visitor, schema, visit_ref_mut, visit_schema_mut);
+    }
+}
+
+/// Visit the nested schemas of [`Array`].
+pub fn walk_array_mut<V: VisitMut + ?Sized>(visitor: &mut V, array: &mut Array) {
+    if let ArrayItems::RefOrSchema(items) = &mut array.items {
+        ref_or!(visitor, items.as_mut(), visit_ref_mut, visit_schema_mut);
+    }
+    for schema in array.prefix_items.iter_mut() {
+        visitor.visit_schema_mut(schema);
+    }
+    if let Some(contains) = array.contains.as_deref_mut() {
+        ref_or!(visitor, contains, visit_ref_mut, visit_schema_mut);
+    }
+    if let Some(ArrayItems::RefOrSchema(items)) = array.unevaluated_items.as_deref_mut() {
+        ref_or!(visitor, items.as_mut(), visit_ref_mut, visit_schema_mut);
+    }
+    for schema in array.defs.values_mut() {
+        ref_or!(visitor, schema, visit_ref_mut, visit_schema_mut);
+    }
+}
+
+/// Visit the nested schemas of [`OneOf`].
+pub fn walk_one_of_mut<V: VisitMut + ?Sized>(visitor: &mut V, one_of: &mut OneOf) {
+    for item in one_of.items.iter_mut() {
+        ref_or!(visitor, item, visit_ref_mut, visit_schema_mut);
+    }
+    for schema in one_of.defs.values_mut() {
+        ref_or!(visitor, schema, visit_ref_mut, visit_schema_mut);
+    }
+}
+
+/// Visit the nested schemas of [`AllOf`].
+pub fn walk_all_of_mut<V: VisitMut + ?Sized>(visitor: &mut V, all_of: &mut AllOf) {
+    for item in all_of.items.iter_mut() {
+        ref_or!(visitor, item, visit_ref_mut, visit_schema_mut);
+    }
+    if let Some(AdditionalProperties::RefOr(schema)) = all_of.unevaluated_properties.as_deref_mut()
+    {
+        ref_or!(visitor, schema, visit_ref_mut, visit_schema_mut);
+    }
+    for schema in all_of.defs.values_mut() {
+        ref_or!(visitor, schema, visit_ref_mut, visit_schema_mut);
+    }
+}
+
+/// Visit the nested schemas of [`AnyOf`].
+pub fn walk_any_of_mut<V: VisitMut + ?Sized>(visitor: &mut V, any_of: &mut AnyOf) {
+    for item in any_of.items.iter_mut() {
+        ref_or!(visitor, item, visit_ref_mut, visit_schema_mut);
+    }
+    for schema in any_of.defs.values_mut() {
+        ref_or!(visitor, schema, visit_ref_mut, visit_schema_mut);
+    }
+}
+
+/// Visit the nested schemas of [`Not`].
+pub fn walk_not_mut<V: VisitMut + ?Sized>(visitor: &mut V, not: &mut Not) {
+    ref_or!(visitor, not.item.as_mut(), visit_ref_mut, visit_schema_mut);
+    for schema in not.defs.values_mut() {
+        ref_or!(visitor, schema, visit_ref_mut, visit_schema_mut);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_json_snapshot;
+
+    use crate::openapi::path::OperationBuilder;
+    use crate::openapi::{
+        ArrayBuilder, ComponentsBuilder, ContentBuilder, HttpMethod, ObjectBuilder, OneOfBuilder,
+        OpenApiBuilder, PathsBuilder, ResponseBuilder,
+    };
+
+    use super::*;
+
+    fn openapi() -> OpenApi {
+        OpenApiBuilder::new()
+            .paths(
+                PathsBuilder::new().path(
+                    "/pets",
+                    PathItem::new(
+                        HttpMethod::Get,
+                        OperationBuilder::new().response(
+                            "200",
+                            ResponseBuilder::new().description("Pets").content(
+                                "application/json",
+                                ContentBuilder::new()
+                                    .schema(Some(
+                                        ArrayBuilder::new().items(Ref::from_schema_name("Pet")),
+                                    ))
+                                    .build(),
+                            ),
+                        ),
+                    ),
+                ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Pet",
+                        ObjectBuilder::new()
+                            .property("name", ObjectBuilder::new())
+                            .property(
+                                "owner",
+                                OneOfBuilder::new()
+                                    .item(Ref::from_schema_name("Owner"))
+                                    .item(
+                                        ObjectBuilder::new().property("id", ObjectBuilder::new()),
+                                    ),
+                            ),
+                    )
+                    .build(),
+            ))
+            .build()
+    }
+
+    #[test]
+    fn visit_all_schemas_and_references() {
+        #[derive(Default)]
+        struct Collect<'a> {
+            references: Vec<&'a str>,
+            objects: usize,
+        }
+
+        impl<'a> Visit<'a> for Collect<'a> {
+            fn visit_ref(&mut self, reference: &'a Ref) {
+                self.references.push(&reference.ref_location);
+            }
+
+            fn visit_object(&mut self, object: &'a Object) {
+                self.objects += 1;
+                walk_object(self, object);
+            }
+        }
+
+        let openapi = openapi();
+        let mut collect = Collect::default();
+        collect.visit_openapi(&openapi);
+
+        assert_eq!(
+            collect.references,
+            ["#/components/schemas/Pet", "#/components/schemas/Owner"]
+        );
+        assert_eq!(collect.objects, 4);
+    }
+
+    #[test]
+    fn visit_mut_rewrites_nested_schemas() {
+        struct Describe;
+
+        impl VisitMut for Describe {
+            fn visit_object_mut(&mut self, object: &mut Object) {
+                object.description = Some(String::from("described"));
+                walk_object_mut(self, object);
+            }
+        }
+
+        let mut openapi = openapi();
+        Describe.visit_openapi_mut(&mut openapi);
+
+        assert_json_snapshot!(openapi.components, @r###"
+        {
+          "schemas": {
+            "Pet": {
+              "type": "object",
+              "description": "described",
+              "properties": {
+                "name": {
+                  "type": "object",
+                  "description": "described"
+                },
+                "owner": {
+                  "oneOf": [
+                    {
+                      "$ref": "#/components/schemas/Owner"
+                    },
+                    {
+                      "type": "object",
+                      "description": "described",
+                      "properties": {
+                        "id": {
+                          "type": "object",
+                          "description": "described"
+                        }
+                      }
+                    }
+                  ]
+                }
+              }
+            }
+          }
+        }
+        "###);
+    }
+}